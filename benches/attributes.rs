@@ -0,0 +1,64 @@
+//! SMART 属性解析基准测试
+//!
+//! 比较收集为 `Vec` 的 `parse_attributes` 和惰性迭代的 `attributes`,
+//! 后者在整个解析过程中不分配堆内存。
+//!
+//! 运行: cargo bench --bench attributes
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use libatasmart::{Disk, SmartInfo};
+
+/// 构造 30 个槽位全部占用的 SMART 数据和阈值
+fn full_smart() -> SmartInfo {
+    let mut data = [0u8; 512];
+    let mut thresholds = [0u8; 512];
+    let ids = [
+        1u8, 3, 4, 5, 7, 9, 10, 12, 183, 184, 187, 188, 189, 190, 191, 192, 193, 194, 195, 196,
+        197, 198, 199, 200, 240, 241, 242, 251, 252, 253,
+    ];
+    for (slot, &id) in ids.iter().enumerate() {
+        let offset = 2 + slot * 12;
+        data[offset] = id;
+        data[offset + 1] = 0x33;
+        data[offset + 3] = 100;
+        data[offset + 4] = 100;
+        data[offset + 5] = slot as u8;
+        thresholds[offset] = id;
+        thresholds[offset + 1] = 10;
+    }
+    for sector in [&mut data, &mut thresholds] {
+        let sum = sector.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        sector[511] = sum.wrapping_neg();
+    }
+
+    Disk::from_raw_buffers(None, Some(data), Some(thresholds), Some(true))
+        .and_then(|disk| disk.smart_info())
+        .expect("构造的 SMART 数据有效")
+}
+
+fn bench_attributes(c: &mut Criterion) {
+    let smart = full_smart();
+
+    c.bench_function("parse_attributes (Vec)", |b| {
+        b.iter(|| {
+            black_box(&smart)
+                .parse_attributes()
+                .unwrap()
+                .iter()
+                .map(|attr| attr.pretty_value)
+                .sum::<u64>()
+        })
+    });
+
+    c.bench_function("attributes (iterator)", |b| {
+        b.iter(|| {
+            black_box(&smart)
+                .attributes()
+                .map(|attr| attr.pretty_value)
+                .sum::<u64>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_attributes);
+criterion_main!(benches);
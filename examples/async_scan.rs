@@ -0,0 +1,63 @@
+//! async_scan - 并发采集所有磁盘的快照
+//!
+//! 用法: async_scan [最大并发数]
+//!
+//! 每块磁盘的命令在阻塞线程池中执行,通过信号量限制同时访问的设备数量。
+
+use libatasmart::{list_disks, AsyncDisk, DiskSnapshot, Result};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+#[tokio::main]
+async fn main() {
+    let limit = match env::args().nth(1).map(|arg| arg.parse::<usize>()) {
+        None => 4,
+        Some(Ok(limit)) if limit > 0 => limit,
+        Some(_) => {
+            eprintln!("用法: async_scan [最大并发数]");
+            process::exit(1);
+        }
+    };
+
+    let candidates = match list_disks() {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            eprintln!("错误: 枚举磁盘失败: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let semaphore = Arc::new(Semaphore::new(limit));
+    let mut tasks = JoinSet::new();
+    for candidate in candidates {
+        let semaphore = Arc::clone(&semaphore);
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("信号量不会被关闭");
+            let result = snapshot(candidate.path.clone()).await;
+            (candidate.path, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (path, result) = joined.expect("采集任务 panic");
+        match result {
+            Ok(snapshot) => {
+                let model = snapshot
+                    .identify
+                    .as_ref()
+                    .map(|identify| identify.model.as_str())
+                    .unwrap_or("?");
+                println!("{}: {} {:?}", path.display(), model, snapshot.overall);
+            }
+            Err(e) => eprintln!("{}: {}", path.display(), e),
+        }
+    }
+}
+
+async fn snapshot(path: PathBuf) -> Result<DiskSnapshot> {
+    AsyncDisk::open(path).await?.snapshot().await
+}
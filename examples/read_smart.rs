@@ -33,21 +33,17 @@ fn run(device_path: &str) -> Result<(), Error> {
     let disk = Disk::open(device_path)?;
 
     println!("\n=== 基本信息 ===");
-    println!("设备类型: {:?}", disk.disk_type());
-    println!(
-        "设备大小: {} 字节 ({:.2} GB)",
-        disk.size(),
-        disk.size() as f64 / 1_000_000_000.0
-    );
+    println!("设备类型: {:?}", disk.resolve_disk_type()?);
+    println!("设备大小: {} 字节 ({})", disk.size(), disk.capacity());
 
     // 检查睡眠状态
     println!("\n=== 电源状态 ===");
-    match disk.check_sleep_mode() {
-        Ok(awake) => {
-            println!("设备状态: {}", if awake { "活动/空闲" } else { "睡眠" });
+    match disk.smart_get_power_mode() {
+        Ok(mode) => {
+            println!("设备状态: {}", mode.as_str());
         }
         Err(e) => {
-            println!("无法检查睡眠状态: {}", e);
+            println!("无法检查电源模式: {}", e);
         }
     }
 
@@ -166,8 +162,7 @@ fn run(device_path: &str) -> Result<(), Error> {
 
         // 平均每次开机时间
         if let Some(duration) = stats.power_on_duration {
-            if cycles > 0 {
-                let avg_hours = duration.as_hours() / cycles;
+            if let Some(avg_hours) = duration.as_hours().checked_div(cycles) {
                 println!("平均每次开机时间: {} 小时", avg_hours);
             }
         }
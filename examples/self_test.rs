@@ -17,21 +17,7 @@ fn main() {
     let device_path = &args[1];
     let test_type_str = &args[2];
 
-    // 解析测试类型
-    let test_type = match test_type_str.to_lowercase().as_str() {
-        "short" => SmartSelfTest::Short,
-        "extended" => SmartSelfTest::Extended,
-        "conveyance" => SmartSelfTest::Conveyance,
-        "abort" => SmartSelfTest::Abort,
-        _ => {
-            eprintln!("错误: 未知的测试类型 '{}'", test_type_str);
-            eprintln!();
-            print_usage(&args[0]);
-            process::exit(1);
-        }
-    };
-
-    match run(device_path, test_type) {
+    match run(device_path, test_type_str) {
         Ok(()) => {}
         Err(e) => {
             eprintln!("错误: {}", e);
@@ -56,12 +42,15 @@ fn print_usage(program: &str) {
     eprintln!("注意: 需要root权限才能访问设备");
 }
 
-fn run(device_path: &str, test_type: SmartSelfTest) -> Result<(), Error> {
+fn run(device_path: &str, test_type_str: &str) -> Result<(), Error> {
+    // 解析测试类型
+    let test_type = test_type_str.parse::<SmartSelfTest>()?;
+
     println!("正在打开设备: {}", device_path);
-    let disk = Disk::open(device_path)?;
+    let disk = Disk::open_read_write(device_path)?;
 
     println!("\n=== 基本信息 ===");
-    println!("设备类型: {:?}", disk.disk_type());
+    println!("设备类型: {:?}", disk.resolve_disk_type()?);
 
     // 读取并解析IDENTIFY数据
     println!("\n=== 读取设备信息 ===");
@@ -105,7 +94,7 @@ fn run(device_path: &str, test_type: SmartSelfTest) -> Result<(), Error> {
                     if smart.self_test_available(test_type) {
                         let minutes = smart.self_test_polling_minutes(test_type);
                         if minutes > 0 {
-                            println!("\n{} 自检预计时间: {} 分钟", test_type.as_str(), minutes);
+                            println!("\n{} 自检预计时间: {} 分钟", test_type, minutes);
                         }
                     }
                 }
@@ -121,11 +110,11 @@ fn run(device_path: &str, test_type: SmartSelfTest) -> Result<(), Error> {
 
     // 启动自检
     println!("\n=== 启动自检 ===");
-    println!("正在启动 {} 自检...", test_type.as_str());
+    println!("正在启动 {} 自检...", test_type);
 
     disk.start_self_test(test_type)?;
 
-    println!("✓ {} 自检已成功启动!", test_type.as_str());
+    println!("✓ {} 自检已成功启动!", test_type);
 
     // 提示信息
     match test_type {
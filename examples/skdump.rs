@@ -0,0 +1,194 @@
+//! skdump - 显示完整的 SMART 信息
+//!
+//! 用法: skdump [--json] <设备路径>
+//!
+//! 使用 `--json` 时输出单个 JSON 对象,结构如下 (字段保持稳定):
+//!
+//! ```text
+//! {
+//!   "device": "/dev/sda",              // 设备路径
+//!   "disk_type": "AtaPassthrough16",   // DiskType
+//!   "size": 500107862016,              // 设备大小 (字节)
+//!   "identify": {                      // IdentifyParsedData, 读取失败时为 null
+//!     "serial": "...", "firmware": "...", "model": "...",
+//!     "queue_depth": 32,               //   NCQ 队列深度, 不支持 NCQ 时为 null
+//!     "form_factor": "Inch3_5",        //   外形尺寸, 未报告时为 null
+//!     "additional_product_id": "..."   //   附加产品标识, 未报告时为 null
+//!   },
+//!   "smart": { ... },                  // SmartParsedData, 读取失败时为 null
+//!   "attributes": [ { ... } ],         // SmartAttributeParsedData 数组
+//!   "statistics": {                    // DiskStatistics, 读取失败时为 null
+//!     "bad_sectors": 0,
+//!     "power_on_duration": { "milliseconds": 0 },
+//!     "power_cycle_count": 0,
+//!     "temperature": { "celsius": 35.0 }
+//!   },
+//!   "healthy": true                    // SMART 健康状态, 读取失败时为 null
+//! }
+//! ```
+
+use libatasmart::{
+    Capacity, Disk, DiskStatistics, DiskType, Error, IdentifyParsedData, SmartAttributeParsedData,
+    SmartParsedData,
+};
+use serde::Serialize;
+use std::env;
+use std::process;
+
+/// JSON 输出结构
+#[derive(Serialize)]
+struct Report {
+    device: String,
+    disk_type: DiskType,
+    size: u64,
+    identify: Option<IdentifyParsedData>,
+    smart: Option<SmartParsedData>,
+    attributes: Vec<SmartAttributeParsedData>,
+    statistics: Option<DiskStatistics>,
+    healthy: Option<bool>,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let json = args.iter().skip(1).any(|arg| arg == "--json");
+    let paths: Vec<&String> = args.iter().skip(1).filter(|arg| *arg != "--json").collect();
+
+    if paths.len() != 1 {
+        eprintln!("用法: {} [--json] <设备路径>", args[0]);
+        eprintln!("示例: {} /dev/sda", args[0]);
+        eprintln!();
+        eprintln!("注意: 需要root权限才能访问设备");
+        process::exit(1);
+    }
+
+    match collect(paths[0]) {
+        Ok(report) => {
+            if json {
+                match serde_json::to_string_pretty(&report) {
+                    Ok(output) => println!("{}", output),
+                    Err(e) => {
+                        eprintln!("错误: {}", e);
+                        process::exit(1);
+                    }
+                }
+            } else {
+                print_report(&report);
+            }
+        }
+        Err(e) => {
+            eprintln!("错误: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// 从设备读取所有信息
+fn collect(device_path: &str) -> Result<Report, Error> {
+    let disk = Disk::open(device_path)?;
+    let disk_type = disk.resolve_disk_type()?;
+
+    let identify = disk
+        .read_identify()
+        .and_then(|identify| identify.parse())
+        .ok();
+
+    let smart = disk.read_smart().ok();
+    let smart_parsed = smart.as_ref().and_then(|smart| smart.data.parse().ok());
+    let attributes = smart
+        .as_ref()
+        .and_then(|smart| smart.parse_attributes().ok())
+        .unwrap_or_default();
+    let statistics = smart.as_ref().map(|smart| smart.statistics());
+
+    Ok(Report {
+        device: device_path.to_string(),
+        disk_type,
+        size: disk.size(),
+        identify,
+        smart: smart_parsed,
+        attributes,
+        statistics,
+        healthy: disk.is_healthy().ok(),
+    })
+}
+
+/// 输出人类可读的报告
+fn print_report(report: &Report) {
+    println!("设备: {}", report.device);
+    println!("设备类型: {}", report.disk_type.to_human_string());
+    println!(
+        "设备大小: {} 字节 ({})",
+        report.size,
+        Capacity::from_bytes(report.size)
+    );
+
+    if let Some(identify) = &report.identify {
+        println!("型号: {}", identify.model);
+        println!("序列号: {}", identify.serial);
+        println!("固件版本: {}", identify.firmware);
+        if let Some(id) = &identify.additional_product_id {
+            println!("附加产品标识: {}", id);
+        }
+        println!("传输类型: {}", identify.transport.as_str());
+        if let Some(form_factor) = identify.form_factor {
+            println!("外形尺寸: {}", form_factor.as_str());
+        }
+        if let Some(depth) = identify.queue_depth {
+            println!("NCQ 队列深度: {}", depth);
+        }
+    }
+
+    match report.healthy {
+        Some(true) => println!("SMART状态: 良好"),
+        Some(false) => println!("SMART状态: 异常 - 磁盘可能即将故障!"),
+        None => println!("SMART状态: 不可用"),
+    }
+
+    if let Some(smart) = &report.smart {
+        println!(
+            "离线数据收集状态: {}",
+            smart.offline_data_collection_status.as_str()
+        );
+        println!(
+            "自检执行状态: {} (剩余 {}%)",
+            smart.self_test_execution_status.as_str(),
+            smart.self_test_execution_percent_remaining
+        );
+    }
+
+    if let Some(stats) = &report.statistics {
+        if let Some(sectors) = stats.bad_sectors {
+            println!("坏扇区: {} 扇区", sectors);
+        }
+        if let Some(duration) = stats.power_on_duration {
+            println!("累计开机时间: {}", duration);
+        }
+        if let Some(cycles) = stats.power_cycle_count {
+            println!("电源循环次数: {}", cycles);
+        }
+        if let Some(temp) = stats.temperature {
+            println!("当前温度: {}", temp);
+        }
+    }
+
+    if !report.attributes.is_empty() {
+        println!();
+        println!(
+            "{:<4} {:<32} {:<6} {:<6} {:<6} {:<16} {:<5}",
+            "ID", "名称", "当前值", "最差值", "阈值", "格式化值", "警告"
+        );
+        for attr in &report.attributes {
+            println!(
+                "{:<4} {:<32} {:<6} {:<6} {:<6} {:<16} {:<5}",
+                attr.id,
+                attr.name,
+                attr.current_value,
+                attr.worst_value,
+                attr.threshold,
+                attr.value().to_string(),
+                if attr.warn { "是" } else { "否" }
+            );
+        }
+    }
+}
@@ -77,6 +77,18 @@ fn run(blob_path: &str) -> Result<(), Error> {
                 "  自检剩余百分比: {}%",
                 smart_data.self_test_execution_percent_remaining
             );
+            println!(
+                "  离线数据收集能力: {:?}",
+                smart_data.offline_data_collection_capability
+            );
+            println!(
+                "  自动保存: {}",
+                if smart_data.auto_save_enabled {
+                    "已启用"
+                } else {
+                    "未启用"
+                }
+            );
 
             println!("\n自检功能:");
             println!(
@@ -131,8 +143,7 @@ fn run(blob_path: &str) -> Result<(), Error> {
         println!("电源循环次数: {}", cycles);
 
         if let Some(duration) = stats.power_on_duration {
-            if cycles > 0 {
-                let avg_hours = duration.as_hours() / cycles;
+            if let Some(avg_hours) = duration.as_hours().checked_div(cycles) {
                 println!("平均每次开机时间: {} 小时", avg_hours);
             }
         }
@@ -162,7 +173,7 @@ fn run(blob_path: &str) -> Result<(), Error> {
                     attr.current_value,
                     attr.worst_value,
                     attr.threshold,
-                    attr.pretty_value,
+                    attr.value().to_string(),
                     warn_marker
                 );
             }
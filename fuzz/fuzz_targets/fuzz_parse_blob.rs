@@ -0,0 +1,18 @@
+//! blob 解析模糊测试: 任意输入只能返回错误,不能 panic
+
+#![no_main]
+
+use libatasmart::fuzzing::{parse_attributes, parse_blob, parse_smart_data};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(blob) = parse_blob(data) else {
+        return;
+    };
+
+    // 解析成功的 blob 继续走完后续的解析流程
+    if let Some(smart_data) = blob.smart_data.as_ref() {
+        let _ = parse_smart_data(smart_data);
+        let _ = parse_attributes(smart_data, blob.smart_thresholds.as_ref(), 0);
+    }
+});
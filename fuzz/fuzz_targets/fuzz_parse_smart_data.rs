@@ -0,0 +1,23 @@
+//! SMART 数据解析模糊测试: 覆盖全部 30 个属性槽位
+
+#![no_main]
+
+use libatasmart::fuzzing::{parse_attributes, parse_smart_data};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // 前 512 字节为 SMART 数据,随后 512 字节 (可选) 为阈值,最后 8 字节 (可选) 为设备大小
+    let Some(smart_data) = data.get(..512).and_then(|s| <&[u8; 512]>::try_from(s).ok()) else {
+        return;
+    };
+    let thresholds = data
+        .get(512..1024)
+        .and_then(|s| <&[u8; 512]>::try_from(s).ok());
+    let disk_size = data
+        .get(1024..1032)
+        .and_then(|s| <[u8; 8]>::try_from(s).ok())
+        .map_or(0, u64::from_le_bytes);
+
+    let _ = parse_smart_data(smart_data);
+    let _ = parse_attributes(smart_data, thresholds, disk_size);
+});
@@ -0,0 +1,101 @@
+/*
+ * libatasmart-rs C 接口
+ *
+ * 与 libatasmart 的 atasmart.h 兼容的子集。
+ * 构建: cargo rustc --lib --release --features capi --crate-type cdylib
+ *
+ * 成功返回 0,失败返回负的 errno 并设置 errno。
+ */
+
+#ifndef LIBATASMART_RS_ATASMART_H
+#define LIBATASMART_RS_ATASMART_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef int SkBool;
+
+typedef struct SkDisk SkDisk;
+
+typedef struct SkIdentifyParsedData {
+        char serial[21];
+        char firmware[9];
+        char model[41];
+} SkIdentifyParsedData;
+
+typedef enum SkSmartAttributeUnit {
+        SK_SMART_ATTRIBUTE_UNIT_UNKNOWN,
+        SK_SMART_ATTRIBUTE_UNIT_NONE,
+        SK_SMART_ATTRIBUTE_UNIT_MSECONDS,
+        SK_SMART_ATTRIBUTE_UNIT_SECTORS,
+        SK_SMART_ATTRIBUTE_UNIT_MKELVIN,
+        SK_SMART_ATTRIBUTE_UNIT_SMALL_PERCENT,
+        SK_SMART_ATTRIBUTE_UNIT_PERCENT,
+        SK_SMART_ATTRIBUTE_UNIT_MB,
+        _SK_SMART_ATTRIBUTE_UNIT_MAX
+} SkSmartAttributeUnit;
+
+typedef struct SkSmartAttributeParsedData {
+        uint8_t id;
+        const char *name;
+        SkSmartAttributeUnit pretty_unit;
+        uint16_t flags;
+        uint8_t threshold;
+        SkBool threshold_valid:1;
+        SkBool online:1;
+        SkBool prefailure:1;
+        SkBool good_now:1, good_now_valid:1;
+        SkBool good_in_the_past:1, good_in_the_past_valid:1;
+        SkBool current_value_valid:1, worst_value_valid:1;
+        SkBool warn:1;
+        uint8_t current_value, worst_value;
+        uint64_t pretty_value;
+        uint8_t raw[6];
+} SkSmartAttributeParsedData;
+
+typedef enum SkSmartOverall {
+        SK_SMART_OVERALL_GOOD,
+        SK_SMART_OVERALL_BAD_ATTRIBUTE_IN_THE_PAST,
+        SK_SMART_OVERALL_BAD_SECTOR,
+        SK_SMART_OVERALL_BAD_ATTRIBUTE_NOW,
+        SK_SMART_OVERALL_BAD_SECTOR_MANY,
+        SK_SMART_OVERALL_BAD_STATUS,
+        _SK_SMART_OVERALL_MAX
+} SkSmartOverall;
+
+typedef void (*SkSmartAttributeParseCallback)(SkDisk *d, const SkSmartAttributeParsedData *a, void *userdata);
+
+/* name 为 NULL 时创建不关联设备的磁盘,通过 sk_disk_set_blob 载入数据 */
+int sk_disk_open(const char *name, SkDisk **d);
+void sk_disk_free(SkDisk *d);
+
+int sk_disk_get_size(SkDisk *d, uint64_t *bytes);
+
+/* 返回的数据归 SkDisk 所有,下一次调用或 sk_disk_free 之前有效 */
+int sk_disk_identify_parse(SkDisk *d, const SkIdentifyParsedData **data);
+
+int sk_disk_smart_is_available(SkDisk *d, SkBool *available);
+int sk_disk_smart_status(SkDisk *d, SkBool *good);
+int sk_disk_smart_read_data(SkDisk *d);
+
+/* 回调收到的指针只在回调执行期间有效 */
+int sk_disk_smart_parse_attributes(SkDisk *d, SkSmartAttributeParseCallback cb, void *userdata);
+
+int sk_disk_smart_get_overall(SkDisk *d, SkSmartOverall *overall);
+int sk_disk_smart_get_temperature(SkDisk *d, uint64_t *mkelvin);
+int sk_disk_smart_get_power_on(SkDisk *d, uint64_t *mseconds);
+int sk_disk_smart_get_bad(SkDisk *d, uint64_t *sectors);
+
+/* 返回的数据归 SkDisk 所有,下一次调用或 sk_disk_free 之前有效 */
+int sk_disk_get_blob(SkDisk *d, const void **blob, size_t *size);
+int sk_disk_set_blob(SkDisk *d, const void *blob, size_t size);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,151 @@
+//! 异步接口
+//!
+//! SG_IO 等 ioctl 会阻塞到命令完成或超时,直接在异步任务中调用会卡住运行时的工作线程。
+//! [`AsyncDisk`] 把这些调用放到 `tokio::task::spawn_blocking` 的线程池中执行。
+
+use crate::disk::{Disk, IdentifyData, SmartData};
+use crate::error::{Error, Result};
+use crate::snapshot::DiskSnapshot;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// 异步磁盘句柄
+///
+/// 内部持有 `Arc<Mutex<Disk>>`,每个方法都在阻塞线程池中锁住磁盘后执行,
+/// 因此同一句柄上的命令按顺序发送,不同句柄之间可以并发。句柄可以廉价克隆,
+/// 克隆后共享同一个设备和缓存。
+///
+/// # 取消
+///
+/// 底层 ioctl 无法取消。丢弃返回的 future 只会停止等待结果,
+/// 已经提交的阻塞任务会继续执行到命令完成或超时,期间一直持有磁盘的锁,
+/// 后续调用会等待它结束。文件描述符不会因此处于中间状态。
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::AsyncDisk;
+///
+/// # async fn run() -> libatasmart::Result<()> {
+/// let disk = AsyncDisk::open("/dev/sda").await?;
+/// let snapshot = disk.snapshot().await?;
+/// println!("{:?}", snapshot.overall);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncDisk {
+    disk: Arc<Mutex<Disk>>,
+}
+
+impl AsyncDisk {
+    /// 打开磁盘设备 (只读)
+    ///
+    /// 参见 [`Disk::open`]
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let disk = blocking(move || Disk::open(path)).await?;
+        Ok(Self::from(disk))
+    }
+
+    /// 读取 IDENTIFY 数据
+    ///
+    /// 参见 [`Disk::read_identify`]
+    pub async fn read_identify(&self) -> Result<IdentifyData> {
+        self.run(|disk| disk.read_identify()).await
+    }
+
+    /// 读取 SMART 数据
+    ///
+    /// 参见 [`Disk::read_smart_data`]
+    pub async fn read_smart_data(&self) -> Result<SmartData> {
+        self.run(|disk| disk.read_smart_data()).await
+    }
+
+    /// 查询 SMART 自评估结果
+    ///
+    /// 参见 [`Disk::is_healthy`]
+    pub async fn is_healthy(&self) -> Result<bool> {
+        self.run(|disk| disk.is_healthy()).await
+    }
+
+    /// 采集快照
+    ///
+    /// 参见 [`Disk::snapshot`]
+    pub async fn snapshot(&self) -> Result<DiskSnapshot> {
+        self.run(|disk| disk.snapshot()).await
+    }
+
+    /// 在阻塞线程池中对磁盘执行任意操作
+    ///
+    /// 用于没有异步版本的方法。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::AsyncDisk;
+    ///
+    /// # async fn run() -> libatasmart::Result<()> {
+    /// let disk = AsyncDisk::open("/dev/sda").await?;
+    /// let overall = disk.run(|disk| disk.smart_get_overall()).await?;
+    /// println!("整体状态: {:?}", overall);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Disk) -> Result<T> + Send + 'static,
+    {
+        let disk = Arc::clone(&self.disk);
+        blocking(move || {
+            // 其他任务在持锁时 panic 不影响设备本身,继续使用
+            let mut disk = disk.lock().unwrap_or_else(PoisonError::into_inner);
+            f(&mut disk)
+        })
+        .await
+    }
+}
+
+impl From<Disk> for AsyncDisk {
+    fn from(disk: Disk) -> Self {
+        Self {
+            disk: Arc::new(Mutex::new(disk)),
+        }
+    }
+}
+
+/// 在阻塞线程池中执行,任务中的 panic 原样传递给调用方
+async fn blocking<T, F>(f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(result) => result,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => Err(Error::Io(io::Error::other(e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_async_disk_open_missing() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let result = runtime.block_on(AsyncDisk::open("/nonexistent/device"));
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn test_async_disk_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AsyncDisk>();
+    }
+}
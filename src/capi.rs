@@ -0,0 +1,564 @@
+//! 与 libatasmart 兼容的 C 接口
+//!
+//! 导出原 C 库的 `sk_disk_*` 函数,已链接 libatasmart 的程序可以直接换用本库。
+//! 需要启用 `capi` 特性并构建为动态库:
+//!
+//! ```bash
+//! cargo rustc --lib --release --features capi --crate-type cdylib
+//! ```
+//!
+//! 头文件位于 `include/atasmart.h`。与原库相同的约定:
+//!
+//! - 成功返回 0,失败返回负的 errno,同时设置 `errno`
+//! - `sk_disk_identify_parse` 和 `sk_disk_get_blob` 返回的指针归 `SkDisk` 所有,
+//!   在下一次调用同一函数或 `sk_disk_free` 之前有效
+//! - 属性回调收到的指针 (包括属性名称) 只在回调执行期间有效
+//! - `sk_disk_open` 的名称为 `NULL` 时创建不关联设备的磁盘,通过 `sk_disk_set_blob` 载入数据
+
+use crate::disk::Disk;
+use crate::error::Error;
+use crate::smart::blob::parse_blob;
+use crate::types::{AttributeUnit, DiskType, SmartAttributeParsedData, SmartOverall};
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::ops::ControlFlow;
+
+/// 磁盘句柄
+pub struct SkDisk {
+    disk: Disk,
+    identify: Option<Box<SkIdentifyParsedData>>,
+    blob: Vec<u8>,
+}
+
+/// C 语言的布尔值
+pub type SkBool = c_int;
+
+/// IDENTIFY 解析结果
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SkIdentifyParsedData {
+    /// 序列号
+    pub serial: [c_char; 21],
+    /// 固件版本
+    pub firmware: [c_char; 9],
+    /// 型号
+    pub model: [c_char; 41],
+}
+
+/// SMART 属性解析结果
+///
+/// 原结构中的 `SkBool` 位域在 x86-64 和 AArch64 的 SysV ABI 下
+/// 占用偏移 23 的字节 (`threshold_valid` 到 `current_value_valid`)
+/// 和偏移 24 字节的低两位 (`worst_value_valid`、`warn`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SkSmartAttributeParsedData {
+    /// 属性 ID
+    pub id: u8,
+    /// 属性名称
+    pub name: *const c_char,
+    /// 格式化值的单位 (`SkSmartAttributeUnit`)
+    pub pretty_unit: c_int,
+    /// 标志位
+    pub flags: u16,
+    /// 阈值
+    pub threshold: u8,
+    /// 位域: threshold_valid、online、prefailure、good_now、good_now_valid、
+    /// good_in_the_past、good_in_the_past_valid、current_value_valid
+    pub bits0: u8,
+    /// 位域: worst_value_valid、warn
+    pub bits1: u8,
+    /// 当前值
+    pub current_value: u8,
+    /// 最差值
+    pub worst_value: u8,
+    /// 格式化的值
+    pub pretty_value: u64,
+    /// 原始值
+    pub raw: [u8; 6],
+}
+
+/// 属性回调
+pub type SkSmartAttributeParseCallback =
+    Option<unsafe extern "C" fn(*mut SkDisk, *const SkSmartAttributeParsedData, *mut c_void)>;
+
+/// 将错误转换为 errno
+fn errno_of(error: &Error) -> c_int {
+    match error {
+        Error::Io(_) | Error::Device { .. } => error.raw_os_error().unwrap_or(libc::EIO),
+        Error::NotSupported(_) | Error::SmartNotAvailable => libc::ENOTSUP,
+        Error::InvalidData(_) => libc::EINVAL,
+        Error::SecurityLocked(_) => libc::EACCES,
+        Error::DeviceSleeping => libc::EAGAIN,
+        Error::NoData => libc::ENOENT,
+    }
+}
+
+/// 设置 errno 并返回负的 errno
+fn fail(errno: c_int) -> c_int {
+    unsafe { *libc::__errno_location() = errno };
+    -errno
+}
+
+/// 将结果转换为返回值
+fn ret(result: crate::Result<()>) -> c_int {
+    match result {
+        Ok(()) => 0,
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 复制字符串到定长数组,保留结尾的 NUL
+fn copy_c_string<const N: usize>(s: &str) -> [c_char; N] {
+    let mut out = [0 as c_char; N];
+    for (dst, src) in out.iter_mut().zip(s.bytes().take(N - 1)) {
+        *dst = src as c_char;
+    }
+    out
+}
+
+/// 属性单位对应的 `SkSmartAttributeUnit` 值和换算后的值
+///
+/// 原库没有 GB 单位,按十进制换算为 MB,与 [`crate::PrettyValue`] 一致
+fn unit_of(attr: &SmartAttributeParsedData) -> (c_int, u64) {
+    match attr.pretty_unit {
+        AttributeUnit::Unknown => (0, attr.pretty_value),
+        AttributeUnit::None => (1, attr.pretty_value),
+        AttributeUnit::Milliseconds => (2, attr.pretty_value),
+        AttributeUnit::Sectors => (3, attr.pretty_value),
+        AttributeUnit::MilliKelvin => (4, attr.pretty_value),
+        AttributeUnit::SmallPercent => (5, attr.pretty_value),
+        AttributeUnit::Percent => (6, attr.pretty_value),
+        AttributeUnit::Megabytes => (7, attr.pretty_value),
+        AttributeUnit::GigaBytesWritten => (7, attr.pretty_value.saturating_mul(1000)),
+    }
+}
+
+/// 转换为 C 结构,`name` 由调用方保证在使用期间有效
+fn to_c_attribute(attr: &SmartAttributeParsedData, name: &CStr) -> SkSmartAttributeParsedData {
+    let bit = |value: bool, shift: u8| (value as u8) << shift;
+    let (pretty_unit, pretty_value) = unit_of(attr);
+
+    SkSmartAttributeParsedData {
+        id: attr.id,
+        name: name.as_ptr(),
+        pretty_unit,
+        flags: attr.flags,
+        threshold: attr.threshold,
+        bits0: bit(attr.threshold_valid, 0)
+            | bit(attr.online, 1)
+            | bit(attr.prefailure, 2)
+            | bit(attr.good_now, 3)
+            | bit(attr.good_now_valid, 4)
+            | bit(attr.good_in_the_past, 5)
+            | bit(attr.good_in_the_past_valid, 6)
+            | bit(attr.current_value_valid, 7),
+        bits1: bit(attr.worst_value_valid, 0) | bit(attr.warn, 1),
+        current_value: attr.current_value,
+        worst_value: attr.worst_value,
+        pretty_value,
+        raw: attr.raw,
+    }
+}
+
+/// `SkSmartOverall` 的值
+fn overall_value(overall: SmartOverall) -> c_int {
+    match overall {
+        SmartOverall::Good => 0,
+        SmartOverall::BadAttributeInThePast => 1,
+        SmartOverall::BadSector => 2,
+        SmartOverall::BadAttributeNow => 3,
+        SmartOverall::BadSectorMany => 4,
+        SmartOverall::BadStatus => 5,
+    }
+}
+
+/// 打开磁盘
+///
+/// # Safety
+///
+/// `name` 为 `NULL` 或有效的 C 字符串,`d` 指向可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_open(name: *const c_char, d: *mut *mut SkDisk) -> c_int {
+    if d.is_null() {
+        return fail(libc::EINVAL);
+    }
+
+    let disk = if name.is_null() {
+        Disk::from_blob()
+    } else {
+        match CStr::from_ptr(name).to_str() {
+            Ok(name) => Disk::open(name),
+            Err(_) => return fail(libc::EINVAL),
+        }
+    };
+
+    match disk {
+        Ok(disk) => {
+            *d = Box::into_raw(Box::new(SkDisk {
+                disk,
+                identify: None,
+                blob: Vec::new(),
+            }));
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 释放磁盘句柄
+///
+/// # Safety
+///
+/// `d` 为 `NULL` 或 `sk_disk_open` 返回且尚未释放的句柄
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_free(d: *mut SkDisk) {
+    if !d.is_null() {
+        drop(Box::from_raw(d));
+    }
+}
+
+/// 获取设备大小 (字节)
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`bytes` 指向可写的 `uint64_t`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_get_size(d: *mut SkDisk, bytes: *mut u64) -> c_int {
+    let (Some(d), false) = (d.as_mut(), bytes.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    let size = d.disk.size();
+    if size == 0 {
+        return fail(libc::ENODATA);
+    }
+    *bytes = size;
+    0
+}
+
+/// 解析 IDENTIFY 数据
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`data` 指向可写的指针。返回的数据归句柄所有
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_identify_parse(
+    d: *mut SkDisk,
+    data: *mut *const SkIdentifyParsedData,
+) -> c_int {
+    let (Some(d), false) = (d.as_mut(), data.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    let parsed = match d
+        .disk
+        .read_identify_cached()
+        .and_then(|identify| identify.parse())
+    {
+        Ok(parsed) => parsed,
+        Err(e) => return fail(errno_of(&e)),
+    };
+
+    let identify = d.identify.insert(Box::new(SkIdentifyParsedData {
+        serial: copy_c_string(&parsed.serial),
+        firmware: copy_c_string(&parsed.firmware),
+        model: copy_c_string(&parsed.model),
+    }));
+    *data = &**identify;
+    0
+}
+
+/// 设备是否支持 SMART
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`available` 指向可写的 `SkBool`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_is_available(
+    d: *mut SkDisk,
+    available: *mut SkBool,
+) -> c_int {
+    let (Some(d), false) = (d.as_mut(), available.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.read_identify_cached() {
+        Ok(identify) => {
+            *available = identify.features().smart.is_supported() as SkBool;
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取 SMART 自评估结果
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`good` 指向可写的 `SkBool`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_status(d: *mut SkDisk, good: *mut SkBool) -> c_int {
+    let (Some(d), false) = (d.as_mut(), good.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.is_healthy() {
+        Ok(status) => {
+            *good = status as SkBool;
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 读取 SMART 数据和阈值
+///
+/// # Safety
+///
+/// `d` 为有效句柄
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_read_data(d: *mut SkDisk) -> c_int {
+    let Some(d) = d.as_mut() else {
+        return fail(libc::EINVAL);
+    };
+
+    ret(d.disk.read_smart().map(|_| ()))
+}
+
+/// 对每个 SMART 属性调用回调
+///
+/// 需要先调用 `sk_disk_smart_read_data`
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`cb` 为有效的函数指针
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_parse_attributes(
+    d: *mut SkDisk,
+    cb: SkSmartAttributeParseCallback,
+    userdata: *mut c_void,
+) -> c_int {
+    let (Some(disk), Some(cb)) = (d.as_ref(), cb) else {
+        return fail(libc::EINVAL);
+    };
+
+    let Some(smart) = disk.disk.cached_smart() else {
+        return fail(libc::ENOENT);
+    };
+    let result = smart.for_each_attribute(|attr| {
+        let name = CString::new(attr.name).unwrap_or_default();
+        let c_attr = to_c_attribute(attr, &name);
+        cb(d, &c_attr, userdata);
+        ControlFlow::Continue(())
+    });
+    match result {
+        Ok(()) => 0,
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取整体健康状态
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`overall` 指向可写的 `SkSmartOverall`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_get_overall(d: *mut SkDisk, overall: *mut c_int) -> c_int {
+    let (Some(d), false) = (d.as_mut(), overall.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.smart_get_overall() {
+        Ok(value) => {
+            *overall = overall_value(value);
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取当前温度 (毫开尔文)
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`mkelvin` 指向可写的 `uint64_t`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_get_temperature(d: *mut SkDisk, mkelvin: *mut u64) -> c_int {
+    let (Some(d), false) = (d.as_mut(), mkelvin.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.smart_get_temperature() {
+        Ok(temperature) => {
+            *mkelvin = temperature.millikelvin();
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取累计开机时间 (毫秒)
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`mseconds` 指向可写的 `uint64_t`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_get_power_on(d: *mut SkDisk, mseconds: *mut u64) -> c_int {
+    let (Some(d), false) = (d.as_mut(), mseconds.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.smart_get_power_on() {
+        Ok(duration) => {
+            *mseconds = duration.as_millis();
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取坏扇区数
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`sectors` 指向可写的 `uint64_t`
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_smart_get_bad(d: *mut SkDisk, sectors: *mut u64) -> c_int {
+    let (Some(d), false) = (d.as_mut(), sectors.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    match d.disk.smart_info().map(|smart| smart.bad_sectors()) {
+        Ok(Some(bad)) => {
+            *sectors = bad;
+            0
+        }
+        Ok(None) => fail(libc::ENOENT),
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+/// 获取 blob 数据
+///
+/// 包含已读取的 IDENTIFY、SMART 数据和阈值,以及 SMART 自评估结果。
+/// 与 [`crate::Disk::save_blob`] 相同,读取过 SMART 数据时会与自评估结果一起重新读取,
+/// 两者来自同一时刻
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`blob` 和 `size` 指向可写的位置。返回的数据归句柄所有
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_get_blob(
+    d: *mut SkDisk,
+    blob: *mut *const c_void,
+    size: *mut usize,
+) -> c_int {
+    let (Some(d), false, false) = (d.as_mut(), blob.is_null(), size.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+
+    let blob_data = d.disk.export_blob();
+    if blob_data.identify.is_none() {
+        return fail(libc::ENODATA);
+    }
+
+    d.blob = blob_data.to_bytes();
+    *blob = d.blob.as_ptr() as *const c_void;
+    *size = d.blob.len();
+    0
+}
+
+/// 载入 blob 数据
+///
+/// 只支持以 `NULL` 名称打开的磁盘
+///
+/// # Safety
+///
+/// `d` 为有效句柄,`blob` 指向至少 `size` 字节的可读内存
+#[no_mangle]
+pub unsafe extern "C" fn sk_disk_set_blob(
+    d: *mut SkDisk,
+    blob: *const c_void,
+    size: usize,
+) -> c_int {
+    let (Some(d), false) = (d.as_mut(), blob.is_null()) else {
+        return fail(libc::EINVAL);
+    };
+    if d.disk.disk_type() != DiskType::Blob {
+        return fail(libc::ENOTSUP);
+    }
+
+    let bytes = std::slice::from_raw_parts(blob as *const u8, size);
+    let disk = parse_blob(bytes).and_then(|blob_data| {
+        Disk::from_raw_buffers(
+            blob_data.identify,
+            blob_data.smart_data,
+            blob_data.smart_thresholds,
+            blob_data.smart_status,
+        )
+    });
+
+    match disk {
+        Ok(disk) => {
+            d.disk = disk;
+            d.identify = None;
+            0
+        }
+        Err(e) => fail(errno_of(&e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_layout() {
+        assert_eq!(std::mem::size_of::<SkSmartAttributeParsedData>(), 48);
+        assert_eq!(std::mem::offset_of!(SkSmartAttributeParsedData, bits0), 23);
+        assert_eq!(std::mem::offset_of!(SkSmartAttributeParsedData, bits1), 24);
+        assert_eq!(
+            std::mem::offset_of!(SkSmartAttributeParsedData, pretty_value),
+            32
+        );
+        assert_eq!(std::mem::size_of::<SkIdentifyParsedData>(), 71);
+    }
+
+    #[test]
+    fn test_unit_of_gigabytes() {
+        // Intel S3710 以 GB 报告的属性 241,原始值 1234
+        let mut raw = [0u8; 12];
+        raw[0] = 241;
+        raw[5..7].copy_from_slice(&1234u16.to_le_bytes());
+        let context = crate::AttributeContext {
+            model: Some("INTEL SSDSC2BA400G4".to_string()),
+            ..Default::default()
+        };
+        let attr = crate::smart::parse_attribute(&raw, None, &context).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::GigaBytesWritten);
+        assert_eq!(unit_of(&attr), (7, 1_234_000));
+    }
+
+    #[test]
+    fn test_copy_c_string() {
+        let s: [c_char; 4] = copy_c_string("ABCDEF");
+        assert_eq!(s, [b'A' as c_char, b'B' as c_char, b'C' as c_char, 0]);
+    }
+
+    #[test]
+    fn test_errno_of() {
+        assert_eq!(errno_of(&Error::NoData), libc::ENOENT);
+        assert_eq!(
+            errno_of(&Error::Io(std::io::Error::from_raw_os_error(libc::EACCES))),
+            libc::EACCES
+        );
+        assert_eq!(
+            errno_of(&Error::Device {
+                path: "/dev/sda".into(),
+                source: std::io::Error::from_raw_os_error(libc::ENOTTY),
+            }),
+            libc::ENOTTY
+        );
+        assert_eq!(fail(libc::EINVAL), -libc::EINVAL);
+    }
+}
@@ -2,14 +2,19 @@
 
 use crate::error::Result;
 use crate::ffi::ata::{AtaCommand, Direction};
-use crate::ffi::commands::{send_ata_command, AtaRegisters};
+use crate::ffi::commands::{send_ata_command, AtaRegisters, TIMEOUT_MS};
 use crate::types::DiskType;
+use std::fs;
 use std::os::unix::io::RawFd;
+use std::path::Path;
+
+/// ASMedia 的 USB 厂商 ID
+const ASMEDIA_VENDOR_ID: u16 = 0x174c;
 
 /// 尝试发送 IDENTIFY DEVICE 命令
 ///
 /// 如果成功读取到有效数据,返回识别数据
-fn try_identify_device(fd: RawFd, disk_type: DiskType) -> Result<[u8; 512]> {
+fn try_identify_device(fd: RawFd, disk_type: DiskType, timeout_ms: u32) -> Result<[u8; 512]> {
     let mut registers = AtaRegisters::new();
     let mut identify_data = [0u8; 512];
 
@@ -25,6 +30,7 @@ fn try_identify_device(fd: RawFd, disk_type: DiskType) -> Result<[u8; 512]> {
         Direction::In,
         &mut registers,
         Some(&mut identify_data),
+        timeout_ms,
     )?;
 
     // 验证数据不全为 0
@@ -37,6 +43,69 @@ fn try_identify_device(fd: RawFd, disk_type: DiskType) -> Result<[u8; 512]> {
     Ok(identify_data)
 }
 
+/// 尝试发送 CHECK POWER MODE 命令
+///
+/// 与 IDENTIFY DEVICE 不同,该命令不会唤醒处于待机状态的设备
+fn try_check_power_mode(fd: RawFd, disk_type: DiskType, timeout_ms: u32) -> Result<()> {
+    let mut registers = AtaRegisters::new();
+
+    send_ata_command(
+        fd,
+        disk_type,
+        AtaCommand::CheckPowerMode,
+        Direction::None,
+        &mut registers,
+        None,
+        timeout_ms,
+    )?;
+
+    // ERROR 寄存器应该为 0, STATUS 的 ERR 位应该为 0
+    if registers.data[0] != 0 || (registers.data[5] & 1) != 0 {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "无效的电源模式响应").into(),
+        );
+    }
+
+    Ok(())
+}
+
+/// 要测试的磁盘类型 (按优先级排序)
+///
+/// Initio 和 ASMedia 发送的是相同的 12 字节 SAT 命令,只能按 USB 厂商 ID 区分:
+/// ASMedia 桥接 (0x174c) 测试 ASMedia,其他设备测试 Initio
+fn types_to_test(usb_vendor: Option<u16>) -> [DiskType; 3] {
+    let bridge = if usb_vendor == Some(ASMEDIA_VENDOR_ID) {
+        DiskType::UsbAsmedia
+    } else {
+        DiskType::Initio
+    };
+
+    [
+        DiskType::AtaPassthrough16,
+        DiskType::AtaPassthrough12,
+        bridge,
+    ]
+}
+
+/// 获取块设备所在 USB 设备的厂商 ID
+///
+/// 沿 `/sys/class/block/<name>` 指向的 sysfs 目录向上查找 `idVendor`,
+/// 不是 USB 设备或无法读取时返回 `None`
+pub(crate) fn usb_vendor_id(path: &Path) -> Option<u16> {
+    usb_vendor_id_in(Path::new("/sys/class/block"), path)
+}
+
+/// 在指定的 sysfs 目录中查找 USB 厂商 ID
+fn usb_vendor_id_in(sys_class_block: &Path, path: &Path) -> Option<u16> {
+    let resolved = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let entry = fs::canonicalize(sys_class_block.join(resolved.file_name()?)).ok()?;
+
+    entry.ancestors().find_map(|dir| {
+        let vendor = fs::read_to_string(dir.join("idVendor")).ok()?;
+        u16::from_str_radix(vendor.trim(), 16).ok()
+    })
+}
+
 /// 自动检测磁盘类型
 ///
 /// 依次尝试不同的命令接口,找到第一个能成功执行 IDENTIFY DEVICE 的类型
@@ -44,24 +113,43 @@ fn try_identify_device(fd: RawFd, disk_type: DiskType) -> Result<[u8; 512]> {
 /// # 检测顺序
 /// 1. ATA Passthrough 16 (最常用,现代 SATA 硬盘)
 /// 2. ATA Passthrough 12 (USB 外置硬盘)
+/// 3. `usb_vendor` 为 ASMedia (0x174c) 时为 ASMedia
+///    (以固定格式返回 sense 数据的 ASM225/ASM1153 USB 硬盘盒),
+///    否则为 Initio (INIC-1610P USB 硬盘盒)
 ///
 /// # 返回值
-/// - 成功: 返回检测到的磁盘类型
+/// - 成功: 返回检测到的磁盘类型,以及检测过程中读取到的 IDENTIFY 数据
 /// - 失败: 如果所有类型都失败,返回 `DiskType::None`
-pub(crate) fn detect_disk_type(fd: RawFd) -> Result<DiskType> {
-    // 要测试的磁盘类型列表 (按优先级排序)
-    let types_to_test = [DiskType::AtaPassthrough16, DiskType::AtaPassthrough12];
-
-    for disk_type in types_to_test {
+pub(crate) fn detect_disk_type(
+    fd: RawFd,
+    usb_vendor: Option<u16>,
+    timeout_ms: u32,
+) -> Result<(DiskType, Option<[u8; 512]>)> {
+    for disk_type in types_to_test(usb_vendor) {
         // 尝试发送 IDENTIFY DEVICE 命令
-        if try_identify_device(fd, disk_type).is_ok() {
-            return Ok(disk_type);
+        if let Ok(identify) = try_identify_device(fd, disk_type, timeout_ms) {
+            return Ok((disk_type, Some(identify)));
         }
         // 如果失败,继续尝试下一个类型
     }
 
     // 所有类型都失败,返回 None
-    Ok(DiskType::None)
+    Ok((DiskType::None, None))
+}
+
+/// 不唤醒设备的磁盘类型检测
+///
+/// 与 [`detect_disk_type`] 的顺序相同,但用 CHECK POWER MODE 代替 IDENTIFY DEVICE,
+/// 用于 [`crate::WakeupPolicy::NeverWake`]。所有类型都失败时返回 `DiskType::None`
+pub(crate) fn detect_disk_type_passive(
+    fd: RawFd,
+    usb_vendor: Option<u16>,
+    timeout_ms: u32,
+) -> DiskType {
+    types_to_test(usb_vendor)
+        .into_iter()
+        .find(|&disk_type| try_check_power_mode(fd, disk_type, timeout_ms).is_ok())
+        .unwrap_or(DiskType::None)
 }
 
 /// 发送 IDENTIFY DEVICE 命令并返回识别数据
@@ -78,7 +166,7 @@ pub(crate) fn identify_device(fd: RawFd, disk_type: DiskType) -> Result<[u8; 512
         return Ok([0u8; 512]);
     }
 
-    try_identify_device(fd, disk_type)
+    try_identify_device(fd, disk_type, TIMEOUT_MS)
 }
 
 #[cfg(test)]
@@ -93,4 +181,44 @@ mod tests {
         let data = result.unwrap();
         assert_eq!(data.len(), 512);
     }
+
+    #[test]
+    fn test_types_to_test_by_usb_vendor() {
+        let asmedia = types_to_test(Some(ASMEDIA_VENDOR_ID));
+        assert!(asmedia.contains(&DiskType::UsbAsmedia));
+        assert!(!asmedia.contains(&DiskType::Initio));
+
+        for vendor in [None, Some(0x13fd)] {
+            let types = types_to_test(vendor);
+            assert!(types.contains(&DiskType::Initio));
+            assert!(!types.contains(&DiskType::UsbAsmedia));
+        }
+    }
+
+    #[test]
+    fn test_usb_vendor_id_in() {
+        use std::os::unix::fs::symlink;
+
+        let root = std::env::temp_dir().join(format!("libatasmart-usb-{}", std::process::id()));
+        let usb = root.join("devices/usb2/2-1");
+        let usb_block = usb.join("2-1:1.0/host6/target6:0:0/6:0:0:0/block/sdb");
+        let ata_block = root.join("devices/pci0000:00/ata1/host0/block/sda");
+        let class_block = root.join("class/block");
+        fs::create_dir_all(&usb_block).unwrap();
+        fs::create_dir_all(&ata_block).unwrap();
+        fs::create_dir_all(&class_block).unwrap();
+        fs::write(usb.join("idVendor"), "174c\n").unwrap();
+        symlink(&usb_block, class_block.join("sdb")).unwrap();
+        symlink(&ata_block, class_block.join("sda")).unwrap();
+
+        let sdb = usb_vendor_id_in(&class_block, Path::new("/dev/sdb"));
+        let sda = usb_vendor_id_in(&class_block, Path::new("/dev/sda"));
+        let missing = usb_vendor_id_in(&class_block, Path::new("/dev/sdz"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(sdb, Some(ASMEDIA_VENDOR_ID));
+        assert_eq!(types_to_test(sdb)[2], DiskType::UsbAsmedia);
+        assert_eq!(sda, None);
+        assert_eq!(missing, None);
+    }
 }
@@ -1,23 +1,63 @@
 //! 磁盘设备操作
 
-use crate::disk::{IdentifyData, SmartData, SmartInfo, SmartThresholds};
+use crate::disk::{DiskOpenOptions, IdentifyData, SmartData, SmartInfo, SmartThresholds};
 use crate::error::{Error, Result};
 use crate::ffi;
+use crate::smart::BlobData;
 use crate::types::*;
+use crate::utils::verify_ata_checksum;
+use std::cell::{Cell, RefCell};
+use std::fmt;
 use std::fs::{File, OpenOptions};
+use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+use std::os::unix::fs::{FileTypeExt, OpenOptionsExt};
 use std::os::unix::io::{AsRawFd, RawFd};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// 磁盘设备句柄
+///
+/// 句柄遵循 RAII:离开作用域时自动关闭文件描述符。以可写方式打开的设备
+/// 在关闭前会执行 `fsync`,`Drop` 中的同步错误会被忽略,
+/// 需要检查同步结果时请使用 [`Disk::close`]。
+/// 通过本句柄启动过自检时不会同步,自检在设备后台继续运行。
 pub struct Disk {
     file: Option<File>,
-    disk_type: DiskType,
+    /// 设备路径 (规范化后)
+    path: Option<PathBuf>,
+    /// 被解析为整盘设备之前打开的分区路径
+    partition_path: Option<PathBuf>,
+    /// 磁盘类型,在首次发送命令前可能为 `DiskType::Auto`
+    disk_type: Cell<DiskType>,
     size: u64,
+    /// 命令超时时间 (毫秒)
+    timeout_ms: u32,
+    wakeup_policy: WakeupPolicy,
+    /// UDMA CRC 错误数超过该值时整体状态视为 `BadAttributeNow`
+    crc_error_threshold: Option<u64>,
+    /// 最近一次通过本句柄设置的待机计时器
+    standby_timer: Cell<Option<StandbyTimer>>,
+    /// 最近一次读取的 IDENTIFY 数据 (包括类型检测时读取的数据)
+    identify: RefCell<Option<IdentifyData>>,
+    /// 最近一次成功读取的 SMART 数据
+    smart_data: RefCell<Option<SmartData>>,
+    /// 最近一次成功读取的 SMART 阈值
+    smart_thresholds: RefCell<Option<SmartThresholds>>,
+    /// 外部提供的 SMART 自评估结果 (Blob 类型)
+    ///
+    /// 只在 Blob 类型中使用,与载入的 SMART 数据一起提供;
+    /// 其他类型的 `is_healthy` 每次都向设备发送命令,不缓存结果
+    smart_status: Cell<Option<bool>>,
+    /// 是否通过本句柄启动过自检
+    self_test_started: Cell<bool>,
+    /// 文件描述符是否以可写方式打开
+    writable: bool,
 }
 
 impl Disk {
     /// 打开磁盘设备
     ///
+    /// 使用默认选项,需要更多控制时请使用 [`DiskOpenOptions`]。
+    ///
     /// # 参数
     ///
     /// * `path` - 设备路径,例如 `/dev/sda`
@@ -31,40 +71,651 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        DiskOpenOptions::new().open(path)
+    }
+
+    /// 以读写方式打开磁盘设备
+    ///
+    /// 自检、离线数据收集、属性自动保存等会改变设备状态的命令
+    /// 要求以读写方式打开,使用 [`Disk::open`] 打开的磁盘会返回 `Error::NotSupported`。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, SmartSelfTest};
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.start_self_test(SmartSelfTest::Short)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn open_read_write<P: AsRef<Path>>(path: P) -> Result<Self> {
+        DiskOpenOptions::new().read_write(true).open(path)
+    }
+
+    /// 以指定的磁盘类型打开磁盘设备
+    ///
+    /// 传入 `DiskType::Auto` 时与 [`Disk::open`] 相同,磁盘类型会在第一次
+    /// 需要发送命令时自动检测;传入其他类型时跳过检测。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, DiskType};
+    ///
+    /// let disk = Disk::open_with_type("/dev/sdb", DiskType::AtaPassthrough12)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn open_with_type<P: AsRef<Path>>(path: P, disk_type: DiskType) -> Result<Self> {
+        DiskOpenOptions::new().disk_type(disk_type).open(path)
+    }
+
+    /// 按照打开选项打开磁盘设备
+    pub(crate) fn open_with_options(path: &Path, options: &DiskOpenOptions) -> Result<Self> {
+        let parent = options
+            .resolve_partition
+            .then(|| super::partition::parent_device(path))
+            .flatten();
+        let (path, partition_path) = match &parent {
+            Some(parent) => (parent.as_path(), Some(path.to_path_buf())),
+            None => (path, None),
+        };
+
         let file = OpenOptions::new()
             .read(true)
-            .write(false)
-            .open(path.as_ref())?;
+            .write(options.read_write)
+            .custom_flags(options.custom_flags())
+            .open(path)?;
 
+        let mut disk = Self::from_file(file, options.disk_type)?;
+        // 解析 /dev/disk/by-id/ 等符号链接,失败时保留原路径
+        disk.path = Some(std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()));
+        disk.partition_path = partition_path;
+        disk.timeout_ms = options.timeout_ms();
+        disk.wakeup_policy = options.wakeup_policy;
+        Ok(disk)
+    }
+
+    /// 从已打开的文件描述符创建磁盘句柄
+    ///
+    /// 获取文件描述符的所有权,`Disk` 被丢弃时关闭该描述符。
+    /// 与 [`Disk::open`] 一样读取设备大小,磁盘类型在第一次发送命令时检测。
+    /// 出错时文件描述符同样会被关闭。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    /// use std::fs::File;
+    /// use std::os::fd::OwnedFd;
+    ///
+    /// let fd: OwnedFd = File::open("/dev/sda")?.into();
+    /// let disk = Disk::from_fd(fd)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn from_fd(fd: OwnedFd) -> Result<Self> {
+        Self::from_file(File::from(fd), DiskType::Auto)
+    }
+
+    /// 从借用的文件描述符创建磁盘句柄
+    ///
+    /// 内部通过 `dup()` 复制描述符,调用方保留原描述符的所有权,
+    /// 两者可以独立关闭。
+    pub fn from_borrowed_fd(fd: BorrowedFd<'_>) -> Result<Self> {
+        Self::from_fd(fd.try_clone_to_owned()?)
+    }
+
+    /// 从已打开的文件创建磁盘句柄
+    ///
+    /// SCSI 通用设备 (`/dev/sgN`) 是字符设备,不支持 BLKGETSIZE64,
+    /// 此时设备大小记为 0 (未知),依赖设备大小的检查会被跳过。
+    fn from_file(file: File, disk_type: DiskType) -> Result<Self> {
         let fd = file.as_raw_fd();
 
         // 获取设备大小
-        let size = ffi::ioctl::get_block_size(fd)
-            .map_err(|_| Error::Io(std::io::Error::last_os_error()))?;
+        let size = match ffi::ioctl::get_block_size(fd) {
+            Ok(size) => size,
+            Err(e)
+                if e.raw_os_error() == Some(libc::ENOTTY)
+                    && file.metadata()?.file_type().is_char_device() =>
+            {
+                0
+            }
+            Err(e) => return Err(e.into()),
+        };
 
-        // 自动检测设备类型
-        let disk_type = super::detect::detect_disk_type(fd)?;
+        let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if flags < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
 
-        Ok(Self {
-            file: Some(file),
-            disk_type,
+        let mut disk = Self::new(Some(file), disk_type, size);
+        disk.writable = flags & libc::O_ACCMODE != libc::O_RDONLY;
+        Ok(disk)
+    }
+
+    /// 创建磁盘句柄
+    fn new(file: Option<File>, disk_type: DiskType, size: u64) -> Self {
+        Self {
+            file,
+            path: None,
+            partition_path: None,
+            disk_type: Cell::new(disk_type),
             size,
+            timeout_ms: ffi::commands::TIMEOUT_MS,
+            wakeup_policy: WakeupPolicy::default(),
+            crc_error_threshold: None,
+            standby_timer: Cell::new(None),
+            identify: RefCell::new(None),
+            smart_data: RefCell::new(None),
+            smart_thresholds: RefCell::new(None),
+            smart_status: Cell::new(None),
+            self_test_started: Cell::new(false),
+            writable: false,
+        }
+    }
+
+    /// 创建一个独立的磁盘句柄
+    ///
+    /// 通过 `dup()` 复制文件描述符,并复制磁盘类型、大小以及已缓存的数据。
+    /// Blob 类型的磁盘只复制内存中的数据。
+    ///
+    /// 克隆之后两个句柄不共享缓存,各自的读取只更新自己的缓存。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let other = disk.try_clone()?;
+    /// std::thread::spawn(move || other.read_identify());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn try_clone(&self) -> Result<Self> {
+        let file = self.file.as_ref().map(File::try_clone).transpose()?;
+
+        Ok(Self {
+            file,
+            path: self.path.clone(),
+            partition_path: self.partition_path.clone(),
+            disk_type: self.disk_type.clone(),
+            size: self.size,
+            timeout_ms: self.timeout_ms,
+            wakeup_policy: self.wakeup_policy,
+            crc_error_threshold: self.crc_error_threshold,
+            standby_timer: self.standby_timer.clone(),
+            identify: self.identify.clone(),
+            smart_data: self.smart_data.clone(),
+            smart_thresholds: self.smart_thresholds.clone(),
+            smart_status: self.smart_status.clone(),
+            self_test_started: Cell::new(false),
+            writable: self.writable,
         })
     }
 
     /// 获取文件描述符
-    pub(crate) fn fd(&self) -> RawFd {
-        self.file.as_ref().expect("Disk 没有文件句柄").as_raw_fd()
+    ///
+    /// Blob 类型的磁盘没有文件描述符,返回 `None`。
+    /// [`AsFd`] 和 [`AsRawFd`] 的实现在这种情况下会 panic。
+    pub fn borrowed_fd(&self) -> Option<BorrowedFd<'_>> {
+        self.file.as_ref().map(|file| file.as_fd())
+    }
+
+    /// 获取发送命令用的文件描述符,没有打开设备时返回 `Error::NotSupported`
+    fn device_fd(&self) -> Result<RawFd> {
+        self.borrowed_fd()
+            .map(|fd| fd.as_raw_fd())
+            .ok_or_else(|| Error::NotSupported("磁盘没有打开的设备".to_string()))
+    }
+
+    /// 获取设备路径
+    ///
+    /// 通过 [`Disk::open`] 打开的磁盘返回规范化后的路径
+    /// (例如 `/dev/disk/by-id/...` 会被解析为 `/dev/sda`)。
+    /// 通过文件描述符创建的磁盘以及 Blob 类型的磁盘返回 `None`。
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 获取最初传入的分区路径
+    ///
+    /// 打开分区 (例如 `/dev/sda1`) 时会自动改为打开整盘设备,
+    /// 此时 [`Disk::path`] 返回整盘设备路径,这里返回原分区路径。
+    /// 参见 [`DiskOpenOptions::resolve_partition`]。
+    pub fn partition_path(&self) -> Option<&Path> {
+        self.partition_path.as_deref()
+    }
+
+    /// 为错误信息添加设备路径
+    pub(crate) fn with_path_context(&self, err: Error) -> Error {
+        let Some(path) = &self.path else {
+            return err;
+        };
+
+        match err {
+            Error::Io(source) => Error::Device {
+                path: path.clone(),
+                source,
+            },
+            Error::InvalidData(msg) => Error::InvalidData(format!("{}: {}", path.display(), msg)),
+            Error::NotSupported(msg) => Error::NotSupported(format!("{}: {}", path.display(), msg)),
+            other => other,
+        }
     }
 
     /// 获取磁盘大小 (字节)
+    ///
+    /// 无法通过 ioctl 获取大小的设备 (例如 `/dev/sgN`) 使用已读取的 IDENTIFY 数据
+    /// 计算,与 [`Disk::capacity_from_identify`] 相同但不会向设备发送命令;
+    /// 两者都不可用时返回 0
     pub fn size(&self) -> u64 {
-        self.size
+        if self.size != 0 {
+            return self.size;
+        }
+
+        self.identify()
+            .and_then(|identify| identify.capacity())
+            .unwrap_or(0)
+    }
+
+    /// 获取磁盘容量
+    ///
+    /// 与 [`Disk::size`] 相同,返回带单位的 [`Capacity`]
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let capacity = disk.capacity();
+    /// println!("容量: {} ({:.1} GiB)", capacity, capacity.as_gib());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn capacity(&self) -> Capacity {
+        Capacity::from_bytes(self.size())
+    }
+
+    /// 读取第 `index` 个 IDENTIFY 字
+    ///
+    /// 用于本库尚未解析的字段,参见 [`IdentifyData::word`]。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::InvalidData)` - `index` 超过 255
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let word = disk.identify_word(222)?;
+    /// println!("传输类型: 0x{:04X}", word);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn identify_word(&self, index: usize) -> Result<u16> {
+        self.read_identify_cached()?
+            .word(index)
+            .ok_or_else(|| Error::InvalidData(format!("IDENTIFY 字序号超出范围: {}", index)))
+    }
+
+    /// 获取逻辑扇区大小 (字节,IDENTIFY 字 106、117-118)
+    ///
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。未报告时为 512。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let sectors = disk.size_in_sectors()?;
+    /// let sector_size = disk.logical_sector_size()?;
+    /// println!("{} 扇区 × {} 字节", sectors, sector_size);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn logical_sector_size(&self) -> Result<u32> {
+        Ok(self.read_identify_cached()?.parse()?.logical_sector_size)
+    }
+
+    /// 获取物理扇区大小 (字节,IDENTIFY 字 106)
+    ///
+    /// 字 106 bit 13 置位时为逻辑扇区大小乘以 2^(bit 3:0),否则等于逻辑扇区大小。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!(
+    ///     "逻辑 {} 字节, 物理 {} 字节, 对齐偏移 {} 字节",
+    ///     disk.logical_sector_size()?,
+    ///     disk.physical_sector_size()?,
+    ///     disk.sector_alignment_offset_bytes()?
+    /// );
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn physical_sector_size(&self) -> Result<u32> {
+        Ok(self.read_identify_cached()?.parse()?.physical_sector_size)
+    }
+
+    /// 获取逻辑扇区 0 在物理扇区内的偏移 (逻辑扇区数,IDENTIFY 字 209)
+    ///
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    pub fn sector_alignment_offset(&self) -> Result<u16> {
+        Ok(self
+            .read_identify_cached()?
+            .parse()?
+            .sector_alignment_offset)
+    }
+
+    /// 获取逻辑扇区 0 在物理扇区内的偏移 (字节)
+    ///
+    /// 即 [`Disk::sector_alignment_offset`] 乘以逻辑扇区大小。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    pub fn sector_alignment_offset_bytes(&self) -> Result<u32> {
+        let parsed = self.read_identify_cached()?.parse()?;
+        Ok(u32::from(parsed.sector_alignment_offset).saturating_mul(parsed.logical_sector_size))
+    }
+
+    /// 设备是否为 SSD (IDENTIFY 字 217 报告非旋转介质)
+    ///
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    /// 未报告旋转速率的设备返回 `false`。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// if disk.is_ssd()? {
+    ///     println!("固态硬盘");
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn is_ssd(&self) -> Result<bool> {
+        let rotation_rate = self.read_identify_cached()?.parse()?.rotation_rate;
+        Ok(rotation_rate.is_some_and(|rate| rate.is_ssd()))
+    }
+
+    /// 获取用户可寻址的逻辑扇区数 (IDENTIFY 字 100-103)
+    ///
+    /// 不支持 48 位地址的设备使用字 60-61。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告扇区数
+    pub fn size_in_sectors(&self) -> Result<u64> {
+        self.read_identify_cached()?
+            .sector_count()
+            .ok_or(Error::NoData)
+    }
+
+    /// 根据 IDENTIFY 数据计算设备容量 (字节)
+    ///
+    /// 使用 [`Disk::size_in_sectors`] 乘以逻辑扇区大小。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告容量
+    pub fn capacity_from_identify(&self) -> Result<u64> {
+        self.read_identify_cached()?.capacity().ok_or(Error::NoData)
     }
 
     /// 获取磁盘类型
+    ///
+    /// 磁盘类型在第一次发送命令时才会检测,在此之前返回 `DiskType::Auto`。
+    /// 需要立即得到检测结果时请使用 [`Disk::resolve_disk_type`]。
     pub fn disk_type(&self) -> DiskType {
-        self.disk_type
+        self.disk_type.get()
+    }
+
+    /// 获取磁盘类型,必要时立即执行自动检测
+    ///
+    /// 检测会发送 IDENTIFY DEVICE 命令,读取到的数据会被缓存,
+    /// 后续的 SMART 操作不会重复读取。
+    pub fn resolve_disk_type(&self) -> Result<DiskType> {
+        if self.disk_type.get() == DiskType::Auto {
+            let (disk_type, identify) = super::detect::detect_disk_type(
+                self.device_fd()?,
+                self.usb_vendor_id(),
+                self.timeout_ms,
+            )?;
+            self.disk_type.set(disk_type);
+            if let Some(identify) = identify {
+                *self.identify.borrow_mut() = Some(IdentifyData::new(identify));
+            }
+        }
+
+        Ok(self.disk_type.get())
+    }
+
+    /// 设备所在 USB 桥接的厂商 ID,用于选择检测时测试的桥接类型
+    fn usb_vendor_id(&self) -> Option<u16> {
+        self.path.as_deref().and_then(super::detect::usb_vendor_id)
+    }
+
+    /// 缓存的 IDENTIFY 数据是否报告设备已被安全功能锁定 (不发送命令)
+    fn is_security_locked(&self) -> bool {
+        self.identify
+            .borrow()
+            .as_ref()
+            .and_then(|identify| identify.parse().ok())
+            .and_then(|parsed| parsed.security)
+            .is_some_and(|security| security.locked)
+    }
+
+    /// 发送 NVMe GET LOG PAGE 管理命令
+    ///
+    /// 非 NVMe 设备的 ioctl 会失败 (通常为 ENOTTY),转换为 `Error::NotSupported`
+    pub(crate) fn nvme_get_log_page(&self, log_id: u8, data: &mut [u8]) -> Result<()> {
+        // Blob类型不支持
+        if self.disk_type.get() == DiskType::Blob {
+            return Err(Error::NotSupported("Blob类型不支持NVMe命令".to_string()));
+        }
+
+        ffi::nvme::get_log_page(
+            self.device_fd()?,
+            log_id,
+            ffi::nvme::NVME_NSID_ALL,
+            data,
+            self.timeout_ms,
+        )
+        .map_err(|e| match e.raw_os_error() {
+            Some(libc::ENOTTY) | Some(libc::EINVAL) => {
+                Error::NotSupported("设备不是NVMe设备".to_string())
+            }
+            _ => e.into(),
+        })
+        .map_err(|e| self.with_path_context(e))
+    }
+
+    /// 发送 ATA 命令 (必要时先检测磁盘类型)
+    pub(crate) fn send_command(
+        &self,
+        command: ffi::ata::AtaCommand,
+        direction: ffi::ata::Direction,
+        registers: &mut ffi::commands::AtaRegisters,
+        data: Option<&mut [u8]>,
+    ) -> Result<()> {
+        let disk_type = self.resolve_disk_type()?;
+        ffi::commands::send_ata_command(
+            self.device_fd()?,
+            disk_type,
+            command,
+            direction,
+            registers,
+            data,
+            self.timeout_ms,
+        )
+        .map_err(|e| self.command_error(command, e))
+    }
+
+    /// 为命令错误添加设备路径
+    ///
+    /// 被锁定的设备会中止 SMART 命令,此时返回 [`Error::SecurityLocked`] 给出更明确的原因,
+    /// 原始错误作为来源保留
+    fn command_error(&self, command: ffi::ata::AtaCommand, err: Error) -> Error {
+        let err = self.with_path_context(err);
+        if command == ffi::ata::AtaCommand::Smart && self.is_security_locked() {
+            return Error::SecurityLocked(Box::new(err));
+        }
+        err
+    }
+
+    /// 获取设备唤醒策略
+    pub fn wakeup_policy(&self) -> WakeupPolicy {
+        self.wakeup_policy
+    }
+
+    /// 设置设备唤醒策略
+    ///
+    /// 设置为 [`WakeupPolicy::NeverWake`] 后,读取 SMART 数据、阈值和健康状态前
+    /// 会先发送 CHECK POWER MODE,设备处于待机/睡眠状态时返回
+    /// [`Error::DeviceSleeping`],而不会唤醒设备。
+    /// 磁盘类型尚未检测时同样使用 CHECK POWER MODE 检测,而不是 IDENTIFY DEVICE。
+    /// 之前读取的数据仍可通过 [`Disk::cached_smart`] 获取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, Error, WakeupPolicy};
+    ///
+    /// let mut disk = Disk::open("/dev/sda")?;
+    /// disk.set_wakeup_policy(WakeupPolicy::NeverWake);
+    ///
+    /// match disk.read_smart() {
+    ///     Ok(smart) => println!("坏扇区: {:?}", smart.bad_sectors()),
+    ///     Err(Error::DeviceSleeping) => {
+    ///         // 设备正在睡眠,使用上一次读取的数据
+    ///         if let Some(smart) = disk.cached_smart() {
+    ///             println!("坏扇区 (缓存): {:?}", smart.bad_sectors());
+    ///         }
+    ///     }
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn set_wakeup_policy(&mut self, policy: WakeupPolicy) {
+        self.wakeup_policy = policy;
+    }
+
+    /// 获取 UDMA CRC 错误阈值
+    pub fn crc_error_threshold(&self) -> Option<u64> {
+        self.crc_error_threshold
+    }
+
+    /// 设置 UDMA CRC 错误阈值
+    ///
+    /// 属性 199 的错误数超过该值时,[`Disk::smart_get_overall`] 和
+    /// [`Disk::health_report`] 的整体状态至少为 [`SmartOverall::BadAttributeNow`]。
+    /// 默认为 `None`,不参与整体状态判定。
+    ///
+    /// [`SmartOverall::BadAttributeNow`]: crate::SmartOverall::BadAttributeNow
+    pub fn set_crc_error_threshold(&mut self, threshold: Option<u64>) {
+        self.crc_error_threshold = threshold;
+    }
+
+    /// 获取最近一次读取的 IDENTIFY 数据
+    ///
+    /// 不会向设备发送命令,从未读取过时返回 `None`。
+    /// 原始字、解析结果等都可以通过 [`IdentifyData`] 获取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// disk.read_identify()?;
+    /// if let Some(identify) = disk.identify() {
+    ///     println!("型号: {}", identify.parse()?.model);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn identify(&self) -> Option<IdentifyData> {
+        self.identify.borrow().clone()
+    }
+
+    /// 获取最近一次成功读取的 SMART 信息
+    ///
+    /// 如果从未成功读取过 SMART 数据,返回 `None`
+    pub fn cached_smart(&self) -> Option<SmartInfo> {
+        let data = self.smart_data.borrow().clone()?;
+        let thresholds = self.smart_thresholds.borrow().clone();
+        Some(SmartInfo::new(data, thresholds))
+    }
+
+    /// 获取 SMART 信息,优先使用缓存,没有缓存时从设备读取
+    ///
+    /// 返回的 [`SmartInfo`] 包含原始 SMART 数据和阈值,以及读取时的设备大小、
+    /// 扇区大小等解析上下文,可以序列化原始数据后在其他进程中再解析。
+    /// 需要最新数据时请使用 [`Disk::read_smart`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let smart = disk.smart_info()?;
+    /// let raw = *smart.data.raw();
+    /// println!("属性数: {}", smart.parse_attributes()?.len());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_info(&self) -> Result<SmartInfo> {
+        match self.cached_smart() {
+            Some(smart) => Ok(smart),
+            None => self.read_smart(),
+        }
+    }
+
+    /// 文件描述符是否以可写方式打开
+    ///
+    /// 通过 [`Disk::open_read_write`] 打开,或传入以可写方式打开的文件描述符时为 `true`。
+    /// Blob 类型的磁盘始终为 `false`。
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    /// 检查磁盘是否以可写方式打开,用于会改变设备状态的命令
+    ///
+    /// Blob 类型不检查,由各命令返回各自的不支持错误
+    pub(crate) fn ensure_writable(&self) -> Result<()> {
+        if !self.writable && self.disk_type.get() != DiskType::Blob {
+            return Err(Error::NotSupported("disk opened read-only".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 根据唤醒策略检查设备是否可以被访问
+    ///
+    /// 需要在读取 IDENTIFY 之前调用。`NeverWake` 策略下磁盘类型尚未检测时,
+    /// 用 CHECK POWER MODE 检测类型,避免检测时发送的 IDENTIFY DEVICE 唤醒设备
+    pub(crate) fn ensure_awake(&self) -> Result<()> {
+        if self.wakeup_policy != WakeupPolicy::NeverWake {
+            return Ok(());
+        }
+
+        if self.disk_type.get() == DiskType::Auto {
+            self.disk_type.set(super::detect::detect_disk_type_passive(
+                self.device_fd()?,
+                self.usb_vendor_id(),
+                self.timeout_ms,
+            ));
+        }
+
+        if !self.smart_get_power_mode()?.is_awake() {
+            return Err(Error::DeviceSleeping);
+        }
+        Ok(())
     }
 
     /// 检查设备是否处于睡眠模式
@@ -73,6 +724,14 @@ impl Disk {
     ///
     /// * `Ok(true)` - 设备处于活动或空闲状态
     /// * `Ok(false)` - 设备处于睡眠状态
+    #[deprecated(note = "请使用 `smart_get_power_mode`,它能区分活动/空闲/待机/睡眠状态")]
+    pub fn check_sleep_mode(&self) -> Result<bool> {
+        Ok(self.smart_get_power_mode()?.is_awake())
+    }
+
+    /// 获取设备当前的电源模式
+    ///
+    /// 发送 CHECK POWER MODE 命令,该命令不会唤醒处于待机状态的设备
     ///
     /// # 示例
     ///
@@ -80,25 +739,22 @@ impl Disk {
     /// use libatasmart::Disk;
     ///
     /// let disk = Disk::open("/dev/sda")?;
-    /// let awake = disk.check_sleep_mode()?;
-    /// println!("设备状态: {}", if awake { "活动" } else { "睡眠" });
+    /// let mode = disk.smart_get_power_mode()?;
+    /// println!("电源模式: {}", mode.as_str());
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
-    pub fn check_sleep_mode(&self) -> Result<bool> {
+    pub fn smart_get_power_mode(&self) -> Result<PowerMode> {
         // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
+        if self.disk_type.get() == DiskType::Blob {
             return Err(Error::NotSupported(
-                "Blob类型不支持睡眠模式检查".to_string(),
+                "Blob类型不支持电源模式检查".to_string(),
             ));
         }
 
-        let fd = self.fd();
         let mut registers = ffi::commands::AtaRegisters::new();
 
         // 发送 CHECK_POWER_MODE 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::CheckPowerMode,
             ffi::ata::Direction::None,
             &mut registers,
@@ -108,21 +764,158 @@ impl Disk {
         // 检查返回状态
         // cmd[0] 应该是 0, cmd[5] 的最低位应该是 0
         if registers.data[0] != 0 || (registers.data[5] & 1) != 0 {
-            return Err(
+            return Err(self.with_path_context(
                 std::io::Error::new(std::io::ErrorKind::InvalidData, "无效的电源模式响应").into(),
-            );
+            ));
+        }
+
+        // 状态值位于 SECTOR COUNT 寄存器
+        Ok(PowerMode::from_register(registers.data[3]))
+    }
+
+    /// 设置待机计时器
+    ///
+    /// 发送 IDLE 命令,设备将立即进入空闲状态,
+    /// 并在经过指定时间没有活动后进入待机状态。
+    /// 计时器代码的含义参见 [`StandbyTimer`]。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - Blob 类型或磁盘以只读方式打开
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, StandbyTimer};
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.set_standby_timer(StandbyTimer::Minutes(10))?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn set_standby_timer(&self, timer: StandbyTimer) -> Result<()> {
+        // Blob类型不支持
+        if self.disk_type.get() == DiskType::Blob {
+            return Err(Error::NotSupported(
+                "Blob类型不支持设置待机计时器".to_string(),
+            ));
         }
+        self.ensure_writable()?;
+
+        let code = timer
+            .to_code()
+            .ok_or_else(|| Error::InvalidData(format!("无法表示的待机计时器: {:?}", timer)))?;
 
-        // 获取状态值 (SECTOR COUNT 寄存器)
-        let status = registers.data[3];
+        let mut registers = ffi::commands::AtaRegisters::new();
+        // 计时器代码放在 SECTOR COUNT 寄存器
+        registers.set_sector_count(code);
 
-        // 0xFF = active/idle, 0x80 = idle
-        // 其他值表示睡眠或待机状态
-        Ok(status == 0xFF || status == 0x80)
+        // 发送 IDLE 命令
+        self.send_command(
+            ffi::ata::AtaCommand::Idle,
+            ffi::ata::Direction::None,
+            &mut registers,
+            None,
+        )?;
+
+        self.standby_timer.set(Some(timer));
+        Ok(())
+    }
+
+    /// 获取最近一次通过 [`Disk::set_standby_timer`] 设置的待机计时器
+    ///
+    /// ATA 没有提供读取待机计时器的命令,因此只能返回本句柄设置过的值。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 未通过本句柄设置过待机计时器
+    pub fn get_standby_timer(&self) -> Result<StandbyTimer> {
+        self.standby_timer.get().ok_or(Error::NoData)
+    }
+
+    /// 启用自动声学管理 (AAM) 并设置级别
+    ///
+    /// 设置成功后清除缓存的 IDENTIFY 数据,下次读取时可以从字 94 得到新的级别。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - Blob 类型或磁盘以只读方式打开
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{AcousticLevel, Disk};
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.set_acoustic_management(AcousticLevel::Quiet)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn set_acoustic_management(&self, level: AcousticLevel) -> Result<()> {
+        let value = level
+            .to_value()
+            .ok_or_else(|| Error::InvalidData(format!("无效的声学管理级别: {:?}", level)))?;
+
+        self.set_features(
+            ffi::ata::SetFeaturesCommand::EnableAcousticManagement,
+            value,
+        )
+    }
+
+    /// 禁用自动声学管理 (AAM)
+    ///
+    /// 需要以可写方式打开磁盘,参见 [`Disk::set_acoustic_management`]
+    pub fn disable_acoustic_management(&self) -> Result<()> {
+        self.set_features(ffi::ata::SetFeaturesCommand::DisableAcousticManagement, 0)
+    }
+
+    /// 获取自动声学管理 (AAM) 信息
+    ///
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持 AAM 或没有报告级别
+    pub fn acoustic_management(&self) -> Result<AamInfo> {
+        self.read_identify_cached()?
+            .parse()?
+            .aam
+            .ok_or_else(|| Error::NotSupported("设备不支持自动声学管理".to_string()))
+    }
+
+    /// 发送 SET FEATURES 命令,成功后清除缓存的 IDENTIFY 数据
+    fn set_features(
+        &self,
+        subcommand: ffi::ata::SetFeaturesCommand,
+        sector_count: u8,
+    ) -> Result<()> {
+        // Blob类型不支持
+        if self.disk_type.get() == DiskType::Blob {
+            return Err(Error::NotSupported(
+                "Blob类型不支持SET FEATURES".to_string(),
+            ));
+        }
+        self.ensure_writable()?;
+
+        let mut registers = ffi::commands::AtaRegisters::new();
+        registers.set_features(subcommand as u8);
+        registers.set_sector_count(sector_count);
+
+        self.send_command(
+            ffi::ata::AtaCommand::SetFeatures,
+            ffi::ata::Direction::None,
+            &mut registers,
+            None,
+        )?;
+
+        // 设备功能状态已改变,IDENTIFY 缓存失效
+        *self.identify.borrow_mut() = None;
+        Ok(())
     }
 
     /// 从设备读取 IDENTIFY 数据
     ///
+    /// 与 [`Disk::read_identify_force`] 相同,为兼容旧代码保留。
+    /// 不需要刷新数据时请使用 [`Disk::read_identify_cached`]。
+    ///
     /// # 示例
     ///
     /// ```no_run
@@ -135,22 +928,60 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn read_identify(&self) -> Result<IdentifyData> {
+        self.read_identify_force()
+    }
+
+    /// 获取 IDENTIFY 数据,优先使用缓存
+    ///
+    /// 已经读取过 IDENTIFY 数据时直接返回缓存,不会再向设备发送命令。
+    /// 读取 SMART 数据和阈值时会自动调用。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let model = disk.read_identify_cached()?.parse()?.model;
+    /// // 第二次调用不会再发送 IDENTIFY 命令
+    /// let serial = disk.read_identify_cached()?.parse()?.serial;
+    /// println!("{} ({})", model, serial);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn read_identify_cached(&self) -> Result<IdentifyData> {
+        self.resolve_disk_type()?;
+        if let Some(identify) = self.identify.borrow().as_ref() {
+            return Ok(identify.clone());
+        }
+
+        self.read_identify_force()
+    }
+
+    /// 从设备重新读取 IDENTIFY 数据并更新缓存
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let identify = disk.read_identify_force()?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn read_identify_force(&self) -> Result<IdentifyData> {
         // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
+        if self.disk_type.get() == DiskType::Blob {
             return Err(Error::NotSupported(
                 "Blob类型不支持读取IDENTIFY".to_string(),
             ));
         }
 
-        let fd = self.fd();
         let mut data = [0u8; 512];
         let mut registers = ffi::commands::AtaRegisters::new();
         registers.set_sector_count(1);
 
         // 发送 IDENTIFY DEVICE 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::IdentifyDevice,
             ffi::ata::Direction::In,
             &mut registers,
@@ -159,12 +990,15 @@ impl Disk {
 
         // 检查数据是否全为0 (无效)
         if data.iter().all(|&b| b == 0) {
-            return Err(
+            return Err(self.with_path_context(
                 std::io::Error::new(std::io::ErrorKind::InvalidData, "IDENTIFY数据全为0").into(),
-            );
+            ));
         }
 
-        Ok(IdentifyData::new(data))
+        let identify = IdentifyData::new(data);
+        *self.identify.borrow_mut() = Some(identify.clone());
+
+        Ok(identify)
     }
 
     /// 从设备读取 SMART 数据
@@ -180,20 +1014,22 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn read_smart_data(&self) -> Result<SmartData> {
+        // Blob类型只能返回已载入的数据
+        if self.disk_type.get() == DiskType::Blob {
+            return self.smart_data.borrow().clone().ok_or(Error::NotSupported(
+                "Blob类型不支持读取SMART数据".to_string(),
+            ));
+        }
+
+        // 避免唤醒睡眠中的设备
+        self.ensure_awake()?;
+
         // 检查SMART是否可用
-        let identify = self.read_identify()?;
+        let identify = self.read_identify_cached()?;
         if !Self::is_smart_available(&identify)? {
             return Err(Error::NotSupported("SMART功能不可用".to_string()));
         }
 
-        // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
-            return Err(Error::NotSupported(
-                "Blob类型不支持读取SMART数据".to_string(),
-            ));
-        }
-
-        let fd = self.fd();
         let mut data = [0u8; 512];
         let mut registers = ffi::commands::AtaRegisters::new();
 
@@ -205,16 +1041,17 @@ impl Disk {
         registers.set_lba_high(0xC2);
 
         // 发送 SMART 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::Smart,
             ffi::ata::Direction::In,
             &mut registers,
             Some(&mut data),
         )?;
 
-        Ok(SmartData::new(data, self.size))
+        let smart_data = SmartData::new(data, self.size()).with_identify(Some(&identify));
+        *self.smart_data.borrow_mut() = Some(smart_data.clone());
+
+        Ok(smart_data)
     }
 
     /// 从设备读取 SMART 阈值数据
@@ -229,20 +1066,26 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn read_smart_thresholds(&self) -> Result<SmartThresholds> {
+        // Blob类型只能返回已载入的数据
+        if self.disk_type.get() == DiskType::Blob {
+            return self
+                .smart_thresholds
+                .borrow()
+                .clone()
+                .ok_or(Error::NotSupported(
+                    "Blob类型不支持读取SMART阈值".to_string(),
+                ));
+        }
+
+        // 避免唤醒睡眠中的设备
+        self.ensure_awake()?;
+
         // 检查SMART是否可用
-        let identify = self.read_identify()?;
+        let identify = self.read_identify_cached()?;
         if !Self::is_smart_available(&identify)? {
             return Err(Error::NotSupported("SMART功能不可用".to_string()));
         }
 
-        // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
-            return Err(Error::NotSupported(
-                "Blob类型不支持读取SMART阈值".to_string(),
-            ));
-        }
-
-        let fd = self.fd();
         let mut data = [0u8; 512];
         let mut registers = ffi::commands::AtaRegisters::new();
 
@@ -254,16 +1097,17 @@ impl Disk {
         registers.set_lba_high(0xC2);
 
         // 发送 SMART 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::Smart,
             ffi::ata::Direction::In,
             &mut registers,
             Some(&mut data),
         )?;
 
-        Ok(SmartThresholds::new(data))
+        let thresholds = SmartThresholds::new(data);
+        *self.smart_thresholds.borrow_mut() = Some(thresholds.clone());
+
+        Ok(thresholds)
     }
 
     /// 读取完整的 SMART 信息 (数据 + 阈值)
@@ -278,10 +1122,32 @@ impl Disk {
     /// let stats = smart.statistics();
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
-    pub fn read_smart(&self) -> Result<SmartInfo> {
-        let data = self.read_smart_data()?;
-        let thresholds = self.read_smart_thresholds().ok();
-        Ok(SmartInfo::new(data, thresholds))
+    pub fn read_smart(&self) -> Result<SmartInfo> {
+        let data = self.read_smart_data()?;
+        let thresholds = self.read_smart_thresholds().ok();
+        Ok(SmartInfo::new(data, thresholds))
+    }
+
+    /// 同时读取 SMART 数据和自评估结果
+    ///
+    /// 整体状态、健康评分和健康报告都结合这两者,它们必须来自同一时刻,
+    /// 因此总是重新读取 SMART 数据,而不是使用可能很久以前缓存的数据。
+    /// Blob 类型返回载入的数据和状态。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let (smart, status) = disk.read_smart_with_status()?;
+    /// println!("{:?}", smart.overall(status));
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn read_smart_with_status(&self) -> Result<(SmartInfo, bool)> {
+        let smart = self.read_smart()?;
+        let status = self.is_healthy()?;
+        Ok((smart, status))
     }
 
     /// 获取 SMART 健康状态
@@ -302,20 +1168,22 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn is_healthy(&self) -> Result<bool> {
+        // Blob类型只能返回已载入的状态
+        if self.disk_type.get() == DiskType::Blob {
+            return self.smart_status.get().ok_or(Error::NotSupported(
+                "Blob类型不支持健康状态查询".to_string(),
+            ));
+        }
+
+        // 避免唤醒睡眠中的设备
+        self.ensure_awake()?;
+
         // 检查SMART是否可用
-        let identify = self.read_identify()?;
+        let identify = self.read_identify_cached()?;
         if !Self::is_smart_available(&identify)? {
             return Err(Error::NotSupported("SMART功能不可用".to_string()));
         }
 
-        // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
-            return Err(Error::NotSupported(
-                "Blob类型不支持健康状态查询".to_string(),
-            ));
-        }
-
-        let fd = self.fd();
         let mut registers = ffi::commands::AtaRegisters::new();
 
         // 设置SMART RETURN STATUS命令参数
@@ -325,38 +1193,24 @@ impl Disk {
         registers.set_lba_high(0xC2);
 
         // 发送 SMART 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::Smart,
             ffi::ata::Direction::None,
             &mut registers,
             None,
         )?;
 
-        // 检查返回的LBA寄存器值
-        // LBA MID = 0x4F, LBA HIGH = 0xC2 表示状态良好
-        // LBA MID = 0xF4, LBA HIGH = 0x2C 表示状态异常
-        let lba_mid = registers.data[8];
-        let lba_high = registers.data[7];
-
-        let good = if (self.disk_type == DiskType::AtaPassthrough12 || lba_high == 0xC2)
-            && lba_mid == 0x4F
-        {
-            true
-        } else if (self.disk_type == DiskType::AtaPassthrough12 || lba_high == 0x2C)
-            && lba_mid == 0xF4
-        {
-            false
-        } else {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "无效的SMART状态响应",
-            )
-            .into());
-        };
+        // 12 字节命令的桥接可能不返回完整的寄存器
+        let cdb12 = matches!(
+            self.disk_type.get(),
+            DiskType::AtaPassthrough12 | DiskType::UsbAsmedia
+        );
 
-        Ok(good)
+        check_return_status_ext(&registers, cdb12).map_err(|message| {
+            self.with_path_context(
+                std::io::Error::new(std::io::ErrorKind::InvalidData, message).into(),
+            )
+        })
     }
 
     /// 执行硬盘自检
@@ -368,14 +1222,14 @@ impl Disk {
     /// # 返回
     ///
     /// * `Ok(())` - 自检已成功启动
-    /// * `Err(Error::NotSupported)` - 自检功能不可用或不支持该类型的自检
+    /// * `Err(Error::NotSupported)` - 自检功能不可用、不支持该类型的自检或磁盘以只读方式打开
     ///
     /// # 示例
     ///
     /// ```no_run
     /// use libatasmart::{Disk, SmartSelfTest};
     ///
-    /// let disk = Disk::open("/dev/sda")?;
+    /// let disk = Disk::open_read_write("/dev/sda")?;
     ///
     /// // 启动短时自检
     /// disk.start_self_test(SmartSelfTest::Short)?;
@@ -383,14 +1237,16 @@ impl Disk {
     /// # Ok::<(), libatasmart::Error>(())
     /// ```
     pub fn start_self_test(&self, test: SmartSelfTest) -> Result<()> {
+        self.ensure_writable()?;
+
         // 检查SMART是否可用
-        let identify = self.read_identify()?;
+        let identify = self.read_identify_cached()?;
         if !Self::is_smart_available(&identify)? {
             return Err(Error::NotSupported("SMART功能不可用".to_string()));
         }
 
         // Blob类型不支持
-        if self.disk_type == DiskType::Blob {
+        if self.disk_type.get() == DiskType::Blob {
             return Err(Error::NotSupported("Blob类型不支持自检".to_string()));
         }
 
@@ -403,7 +1259,6 @@ impl Disk {
             return Err(Error::NotSupported(format!("{} 自检不可用", test.as_str())));
         }
 
-        let fd = self.fd();
         let mut registers = ffi::commands::AtaRegisters::new();
 
         // 设置SMART EXECUTE OFFLINE IMMEDIATE命令参数
@@ -415,32 +1270,325 @@ impl Disk {
         registers.data[9] = test as u8;
 
         // 发送 SMART 命令
-        ffi::commands::send_ata_command(
-            fd,
-            self.disk_type,
+        self.send_command(
             ffi::ata::AtaCommand::Smart,
             ffi::ata::Direction::None,
             &mut registers,
             None,
         )?;
 
+        if test != SmartSelfTest::Abort {
+            self.self_test_started.set(true);
+        }
+
+        Ok(())
+    }
+
+    /// 关闭磁盘设备
+    ///
+    /// 与 `Drop` 行为相同,但会返回同步时发生的错误。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let healthy = disk.is_healthy()?;
+    /// disk.close()?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn close(mut self) -> Result<()> {
+        let result = self.sync();
+        // 取出文件,避免 Drop 再次同步
+        self.file.take();
+        result.map_err(|e| self.with_path_context(e.into()))
+    }
+
+    /// 关闭前同步可写的设备
+    ///
+    /// Blob 类型、只读打开或启动过自检的句柄不做任何操作
+    fn sync(&self) -> std::io::Result<()> {
+        let Some(file) = self.file.as_ref() else {
+            return Ok(());
+        };
+        if self.self_test_started.get() || !self.writable {
+            return Ok(());
+        }
+
+        if unsafe { libc::fsync(file.as_raw_fd()) } < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
         Ok(())
     }
 
     /// 检查SMART是否可用
-    fn is_smart_available(identify: &IdentifyData) -> Result<bool> {
-        let raw = identify.raw();
+    pub(crate) fn is_smart_available(identify: &IdentifyData) -> Result<bool> {
         // IDENTIFY word 82 bit 0 表示SMART是否支持
-        Ok((raw[164] & 1) != 0)
+        Ok(identify.features().smart.is_supported())
     }
 
     /// 从 blob 数据创建 Disk 实例
     pub(crate) fn from_blob() -> Result<Self> {
-        Ok(Self {
-            file: None,
-            disk_type: DiskType::Blob,
-            size: 0,
-        })
+        Ok(Self::new(None, DiskType::Blob, 0))
+    }
+
+    /// 从已读取的原始扇区创建磁盘
+    ///
+    /// 适用于通过其他途径 (例如 RAID 控制器接口) 取得 IDENTIFY 和 SMART 扇区,
+    /// 只需要本库解析和健康判断功能的场景。创建的磁盘类型为 [`DiskType::Blob`],
+    /// 不会发送任何命令,读取方法返回这里提供的数据。
+    /// 设备大小取自 IDENTIFY 报告的容量。
+    ///
+    /// # 参数
+    ///
+    /// * `identify` - IDENTIFY DEVICE 数据
+    /// * `smart` - SMART READ DATA 数据
+    /// * `thresholds` - SMART READ THRESHOLDS 数据
+    /// * `status` - SMART RETURN STATUS 结果,`true` 表示状态良好
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::InvalidData)` - SMART 数据或阈值的校验和错误
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// # let (identify, smart) = ([0u8; 512], [0u8; 512]);
+    /// let disk = Disk::from_raw_buffers(Some(identify), Some(smart), None, Some(true))?;
+    /// println!("{:?}", disk.smart_get_overall()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn from_raw_buffers(
+        identify: Option<[u8; 512]>,
+        smart: Option<[u8; 512]>,
+        thresholds: Option<[u8; 512]>,
+        status: Option<bool>,
+    ) -> Result<Self> {
+        for sector in smart.iter().chain(thresholds.iter()) {
+            if !verify_ata_checksum(sector) {
+                return Err(Error::InvalidData("SMART checksum mismatch".to_string()));
+            }
+        }
+
+        let identify = identify.map(IdentifyData::new);
+        let size = identify
+            .as_ref()
+            .and_then(|identify| identify.capacity())
+            .unwrap_or(0);
+
+        let disk = Self::new(None, DiskType::Blob, size);
+        *disk.smart_data.borrow_mut() =
+            smart.map(|smart| SmartData::new(smart, size).with_identify(identify.as_ref()));
+        *disk.smart_thresholds.borrow_mut() = thresholds.map(SmartThresholds::new);
+        *disk.identify.borrow_mut() = identify;
+        disk.smart_status.set(status);
+
+        Ok(disk)
+    }
+
+    /// 将已缓存的数据复制为 blob 数据
+    ///
+    /// 不会向设备发送命令,没有读取过的块为 `None`。
+    /// 只有 Blob 类型的磁盘缓存 SMART 自评估结果,其他磁盘的 `smart_status` 为 `None`,
+    /// 需要包含自评估结果时使用 [`Disk::save_blob`]。
+    /// 结果可以用 [`BlobData::to_disk`] 重新创建磁盘。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// disk.read_identify()?;
+    /// disk.read_smart()?;
+    /// let blob = disk.capture_blob();
+    /// assert!(blob.identify.is_some());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn capture_blob(&self) -> BlobData {
+        BlobData {
+            identify: self
+                .identify
+                .borrow()
+                .as_ref()
+                .map(|identify| *identify.raw()),
+            smart_status: self.smart_status.get(),
+            smart_data: self.smart_data.borrow().as_ref().map(|data| *data.raw()),
+            smart_thresholds: self
+                .smart_thresholds
+                .borrow()
+                .as_ref()
+                .map(|thresholds| *thresholds.raw()),
+        }
+    }
+
+    /// 将已缓存的数据保存为 blob 文件
+    ///
+    /// 参见 [`Disk::capture_blob`],保存的文件可以用 [`crate::read_blob_from_file`] 读取。
+    /// 读取过 SMART 数据而没有缓存自评估结果时,会通过 [`Disk::read_smart_with_status`]
+    /// 重新读取 SMART 数据和自评估结果,使载入的磁盘可以回答 [`Disk::smart_get_overall`];
+    /// 读取失败 (例如设备正在睡眠) 时保存缓存的数据,不记录状态。
+    /// C 接口的 `sk_disk_get_blob` 采用相同的规则。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 尚未读取 IDENTIFY 数据,blob 文件必须包含 IDENTIFY 块
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// disk.read_identify()?;
+    /// disk.read_smart()?;
+    /// disk.save_blob("sda.blob")?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn save_blob<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let blob = self.export_blob();
+        if blob.identify.is_none() {
+            return Err(Error::NoData);
+        }
+        std::fs::write(path, blob.to_bytes())?;
+        Ok(())
+    }
+
+    /// 导出 blob 数据,读取过 SMART 数据时补充自评估结果,参见 [`Disk::save_blob`]
+    pub(crate) fn export_blob(&self) -> BlobData {
+        let blob = self.capture_blob();
+        if blob.smart_status.is_some() || blob.smart_data.is_none() {
+            return blob;
+        }
+
+        // 重新读取的数据已写入缓存,再次复制
+        match self.read_smart_with_status() {
+            Ok((_, status)) => BlobData {
+                smart_status: Some(status),
+                ..self.capture_blob()
+            },
+            Err(_) => blob,
+        }
+    }
+}
+
+/// 检查 SMART RETURN STATUS 返回的 LBA 寄存器
+///
+/// LBA MID = 0x4F, LBA HIGH = 0xC2 表示状态良好,
+/// LBA MID = 0xF4, LBA HIGH = 0x2C 表示状态异常。
+/// 两种情况都不检查 LBA LOW,部分桥接会在其中返回无关的值,只在错误信息中给出;
+/// 16 字节命令返回 48 位寄存器 (SAT ATA Status Return 描述符的 EXTEND 位) 时,
+/// 错误信息中同时给出 LBA 高位。
+/// `cdb12` 为 `true` 时不检查 LBA HIGH,12 字节命令的桥接可能不返回它。
+fn check_return_status_ext(
+    registers: &ffi::commands::AtaRegisters,
+    cdb12: bool,
+) -> std::result::Result<bool, String> {
+    let lba_low = registers.data[9];
+    let lba_mid = registers.data[8];
+    let lba_high = registers.data[7];
+
+    if (cdb12 || lba_high == 0xC2) && lba_mid == 0x4F {
+        return Ok(true);
+    }
+    if (cdb12 || lba_high == 0x2C) && lba_mid == 0xF4 {
+        return Ok(false);
+    }
+
+    let mut message = format!(
+        "无效的SMART状态响应: LBA LOW 0x{:02X}, LBA MID 0x{:02X}, LBA HIGH 0x{:02X}",
+        lba_low, lba_mid, lba_high
+    );
+    if registers.lba_ext != [0; 3] {
+        message.push_str(&format!(
+            " (高位 0x{:02X}/0x{:02X}/0x{:02X})",
+            registers.lba_ext[0], registers.lba_ext[1], registers.lba_ext[2]
+        ));
+    }
+    Err(message)
+}
+
+impl Default for Disk {
+    /// 创建占位用的空磁盘
+    ///
+    /// 类型为 [`DiskType::None`],大小为 0,没有文件描述符和任何缓存数据,
+    /// 所有需要访问设备的方法都返回 `Error::NotSupported`。
+    /// 只用于让包含 `Disk` 的结构体可以 `#[derive(Default)]`,
+    /// 使用前必须替换为通过 [`Disk::open`] 等方法得到的磁盘。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use libatasmart::{Disk, DiskType};
+    ///
+    /// #[derive(Default)]
+    /// struct Monitor {
+    ///     disk: Disk,
+    ///     polls: u64,
+    /// }
+    ///
+    /// let monitor = Monitor::default();
+    /// assert_eq!(monitor.disk.disk_type(), DiskType::None);
+    /// assert!(monitor.disk.read_identify().is_err());
+    /// ```
+    fn default() -> Self {
+        Self::new(None, DiskType::None, 0)
+    }
+}
+
+impl fmt::Debug for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 不输出 512 字节的原始数据,只显示是否已缓存
+        f.debug_struct("Disk")
+            .field("path", &self.path)
+            .field("partition_path", &self.partition_path)
+            .field("disk_type", &self.disk_type.get())
+            .field("size", &self.size())
+            .field("identify_cached", &self.identify.borrow().is_some())
+            .field("smart_data_cached", &self.smart_data.borrow().is_some())
+            .field(
+                "smart_thresholds_cached",
+                &self.smart_thresholds.borrow().is_some(),
+            )
+            .finish()
+    }
+}
+
+impl Drop for Disk {
+    fn drop(&mut self) {
+        // Drop 中无法传播错误,需要错误时请使用 Disk::close
+        let _ = self.sync();
+    }
+}
+
+impl AsFd for Disk {
+    /// # Panics
+    ///
+    /// Blob 类型的磁盘没有文件描述符,调用会 panic,
+    /// 请使用 [`Disk::borrowed_fd`] 进行检查。
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.borrowed_fd().expect("Blob 类型的 Disk 没有文件描述符")
+    }
+}
+
+impl AsRawFd for Disk {
+    /// # Panics
+    ///
+    /// Blob 类型的磁盘没有文件描述符,调用会 panic,
+    /// 请使用 [`Disk::borrowed_fd`] 进行检查。
+    fn as_raw_fd(&self) -> RawFd {
+        self.as_fd().as_raw_fd()
+    }
+}
+
+impl TryFrom<OwnedFd> for Disk {
+    type Error = Error;
+
+    fn try_from(fd: OwnedFd) -> Result<Self> {
+        Self::from_fd(fd)
     }
 }
 
@@ -453,4 +1601,448 @@ mod tests {
         // 需要真实设备才能测试
         // 这里只测试类型定义
     }
+
+    #[test]
+    fn test_from_fd_rejects_non_block_device() {
+        // 普通文件不支持 BLKGETSIZE64,描述符应随错误一起关闭
+        let file = File::open("Cargo.toml").unwrap();
+        assert!(Disk::from_fd(OwnedFd::from(file)).is_err());
+
+        let file = File::open("Cargo.toml").unwrap();
+        assert!(Disk::from_borrowed_fd(file.as_fd()).is_err());
+        // 调用方仍然持有原描述符
+        assert!(file.metadata().is_ok());
+    }
+
+    #[test]
+    fn test_from_fd_char_device_without_size() {
+        // 字符设备的 BLKGETSIZE64 返回 ENOTTY,与 /dev/sgN 相同
+        let file = File::open("/dev/null").unwrap();
+        let disk = Disk::from_fd(OwnedFd::from(file)).unwrap();
+        assert_eq!(disk.size(), 0);
+        assert_eq!(disk.disk_type(), DiskType::Auto);
+    }
+
+    #[test]
+    fn test_size_from_identify() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.size_in_sectors().is_err());
+        assert_eq!(disk.size(), 0);
+
+        let mut raw = [0u8; 512];
+        // 28 位地址: 2000 扇区
+        raw[120..122].copy_from_slice(&2000u16.to_le_bytes());
+        *disk.identify.borrow_mut() = Some(IdentifyData::new(raw));
+        assert_eq!(disk.size_in_sectors().unwrap(), 2000);
+        assert_eq!(disk.logical_sector_size().unwrap(), 512);
+        assert_eq!(disk.size(), 2000 * 512);
+        assert_eq!(disk.capacity_from_identify().unwrap(), disk.size());
+    }
+
+    #[test]
+    fn test_block_sizes_from_identify() {
+        /// 构造字 106、117 和 209
+        fn identify(word106: u16, logical_words: u16, word209: u16) -> IdentifyData {
+            let mut raw = [0u8; 512];
+            raw[212..214].copy_from_slice(&word106.to_le_bytes());
+            raw[234..236].copy_from_slice(&logical_words.to_le_bytes());
+            raw[418..420].copy_from_slice(&word209.to_le_bytes());
+            IdentifyData::new(raw)
+        }
+
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.identify().is_none());
+        assert!(disk.physical_sector_size().is_err());
+        assert!(disk.sector_alignment_offset_bytes().is_err());
+
+        // 512 字节扇区
+        *disk.identify.borrow_mut() = Some(identify(0x4000, 0, 0));
+        assert_eq!(disk.identify().unwrap().word(106), Some(0x4000));
+        assert_eq!(disk.logical_sector_size().unwrap(), 512);
+        assert_eq!(disk.physical_sector_size().unwrap(), 512);
+        assert_eq!(disk.sector_alignment_offset_bytes().unwrap(), 0);
+
+        // 512e: 每个 4K 物理扇区包含 8 个逻辑扇区,逻辑扇区 0 偏移 1 个扇区
+        *disk.identify.borrow_mut() = Some(identify(0x4000 | (1 << 13) | 3, 0, 0x4001));
+        assert_eq!(disk.logical_sector_size().unwrap(), 512);
+        assert_eq!(disk.physical_sector_size().unwrap(), 4096);
+        assert_eq!(disk.sector_alignment_offset().unwrap(), 1);
+        assert_eq!(disk.sector_alignment_offset_bytes().unwrap(), 512);
+
+        // 4K 原生: 逻辑扇区 2048 字
+        *disk.identify.borrow_mut() = Some(identify(0x4000 | (1 << 12), 2048, 0));
+        assert_eq!(disk.logical_sector_size().unwrap(), 4096);
+        assert_eq!(disk.physical_sector_size().unwrap(), 4096);
+        assert_eq!(disk.sector_alignment_offset_bytes().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_attribute_filters() {
+        let disk = Disk::from_blob().unwrap();
+
+        let mut data = [0u8; 512];
+        let mut thresholds = [0u8; 512];
+        // 属性 1: 预失败, 当前值低于阈值
+        data[2..7].copy_from_slice(&[1, 0x01, 0, 10, 10]);
+        thresholds[2..4].copy_from_slice(&[1, 50]);
+        // 属性 5: 预失败, 正常
+        data[14..19].copy_from_slice(&[5, 0x01, 0, 100, 100]);
+        thresholds[14..16].copy_from_slice(&[5, 10]);
+        // 属性 12: 非预失败
+        data[26..31].copy_from_slice(&[12, 0x00, 0, 100, 100]);
+        *disk.smart_data.borrow_mut() = Some(SmartData::new(data, 0));
+        *disk.smart_thresholds.borrow_mut() = Some(SmartThresholds::new(thresholds));
+
+        let warnings = disk.smart_attribute_warnings().unwrap();
+        assert_eq!(warnings.iter().map(|a| a.id).collect::<Vec<_>>(), [1]);
+
+        let prefailure = disk.smart_prefailure_attributes().unwrap();
+        assert_eq!(prefailure.iter().map(|a| a.id).collect::<Vec<_>>(), [1, 5]);
+
+        // 没有匹配的属性时返回空列表
+        *disk.smart_data.borrow_mut() = Some(SmartData::new([0u8; 512], 0));
+        assert!(disk.smart_attribute_warnings().unwrap().is_empty());
+        assert!(disk.smart_prefailure_attributes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_from_raw_buffers() {
+        let mut identify = [0u8; 512];
+        // 28 位地址: 2000 扇区
+        identify[120..122].copy_from_slice(&2000u16.to_le_bytes());
+        // 字 82/83: 支持 SMART
+        identify[164..166].copy_from_slice(&0x0001u16.to_le_bytes());
+        identify[166..168].copy_from_slice(&0x4000u16.to_le_bytes());
+
+        let mut smart = [0u8; 512];
+        // 属性 5: 重映射扇区数
+        smart[2..7].copy_from_slice(&[5, 0x01, 0, 100, 100]);
+        smart[511] = 0u8.wrapping_sub(5 + 0x01 + 100 + 100);
+
+        let disk = Disk::from_raw_buffers(Some(identify), Some(smart), None, Some(true)).unwrap();
+        assert_eq!(disk.disk_type(), DiskType::Blob);
+        assert_eq!(disk.size(), 2000 * 512);
+        assert!(disk.is_healthy().unwrap());
+        assert_eq!(disk.read_smart_data().unwrap().raw(), &smart);
+        assert!(matches!(
+            disk.read_smart_thresholds(),
+            Err(Error::NotSupported(_))
+        ));
+        assert_eq!(disk.smart_get_overall().unwrap(), SmartOverall::Good);
+
+        // 校验和错误
+        smart[2] ^= 0x01;
+        assert!(matches!(
+            Disk::from_raw_buffers(Some(identify), Some(smart), None, None),
+            Err(Error::InvalidData(_))
+        ));
+
+        // 没有提供的数据按不支持处理
+        let disk = Disk::from_raw_buffers(None, None, None, None).unwrap();
+        assert!(matches!(disk.is_healthy(), Err(Error::NotSupported(_))));
+        assert!(matches!(
+            disk.read_smart_data(),
+            Err(Error::NotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_smart_status_not_cached_for_devices() {
+        // 设备类型的磁盘不使用 smart_status,每次都要发送命令 (这里会失败)
+        let file = File::open("/dev/null").unwrap();
+        let disk = Disk::from_fd(OwnedFd::from(file)).unwrap();
+        disk.smart_status.set(Some(true));
+        *disk.smart_data.borrow_mut() = Some(SmartData::new([0u8; 512], 0));
+        assert!(disk.is_healthy().is_err());
+
+        // Blob 类型返回与数据一起载入的状态
+        let disk = Disk::from_raw_buffers(None, Some([0u8; 512]), None, Some(false)).unwrap();
+        assert!(!disk.is_healthy().unwrap());
+    }
+
+    #[test]
+    fn test_write_commands_require_read_write() {
+        let file = File::open("/dev/null").unwrap();
+        let disk = Disk::from_fd(OwnedFd::from(file)).unwrap();
+        assert!(!disk.is_writable());
+
+        let read_only = |result: Result<()>| matches!(result, Err(Error::NotSupported(msg)) if msg == "disk opened read-only");
+        assert!(read_only(disk.start_self_test(SmartSelfTest::Short)));
+        assert!(read_only(
+            disk.smart_execute_immediate_offline(OfflineTestType::Short)
+        ));
+        assert!(read_only(disk.smart_enable_attribute_auto_save(true)));
+        assert!(read_only(disk.set_standby_timer(StandbyTimer::Minutes(10))));
+        assert!(read_only(
+            disk.set_acoustic_management(AcousticLevel::Quiet)
+        ));
+        assert!(read_only(disk.disable_acoustic_management()));
+        assert_eq!(disk.get_standby_timer().ok(), None);
+
+        let file = OpenOptions::new().write(true).open("/dev/null").unwrap();
+        let disk = Disk::from_fd(OwnedFd::from(file)).unwrap();
+        assert!(disk.is_writable());
+        assert!(disk.try_clone().unwrap().is_writable());
+    }
+
+    #[test]
+    fn test_try_clone_blob() {
+        let mut disk = Disk::from_blob().unwrap();
+        disk.set_wakeup_policy(WakeupPolicy::NeverWake);
+        disk.set_crc_error_threshold(Some(10));
+        *disk.smart_data.borrow_mut() = Some(SmartData::new([1u8; 512], 0));
+
+        let clone = disk.try_clone().unwrap();
+        assert_eq!(clone.disk_type(), DiskType::Blob);
+        assert_eq!(clone.wakeup_policy(), WakeupPolicy::NeverWake);
+        assert_eq!(clone.crc_error_threshold(), Some(10));
+        assert!(clone.borrowed_fd().is_none());
+        assert_eq!(clone.cached_smart().unwrap().data.raw(), &[1u8; 512]);
+
+        // 克隆之后缓存互不影响
+        *disk.smart_data.borrow_mut() = None;
+        assert!(clone.cached_smart().is_some());
+    }
+
+    #[test]
+    fn test_try_clone_duplicates_fd() {
+        let file = File::open("Cargo.toml").unwrap();
+        let disk = Disk::new(Some(file), DiskType::None, 0);
+
+        let clone = disk.try_clone().unwrap();
+        assert_ne!(disk.as_raw_fd(), clone.as_raw_fd());
+
+        drop(disk);
+        let file = File::from(clone.borrowed_fd().unwrap().try_clone_to_owned().unwrap());
+        assert!(file.metadata().unwrap().is_file());
+    }
+
+    #[test]
+    fn test_path() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.path().is_none());
+
+        let mut disk = Disk::new(None, DiskType::None, 0);
+        disk.path = Some(PathBuf::from("/dev/sda"));
+        assert_eq!(disk.path(), Some(Path::new("/dev/sda")));
+
+        let err = disk.with_path_context(Error::InvalidData("测试".to_string()));
+        assert_eq!(err.to_string(), "数据无效或损坏: /dev/sda: 测试");
+        assert!(matches!(
+            disk.with_path_context(Error::DeviceSleeping),
+            Error::DeviceSleeping
+        ));
+
+        // 保留原始错误码
+        let err =
+            disk.with_path_context(Error::Io(std::io::Error::from_raw_os_error(libc::EACCES)));
+        assert!(matches!(err, Error::Device { .. }));
+        assert_eq!(err.raw_os_error(), Some(libc::EACCES));
+        assert!(err.to_string().starts_with("/dev/sda: I/O 错误: "));
+        let source = std::error::Error::source(&err).unwrap();
+        assert_eq!(
+            source
+                .downcast_ref::<std::io::Error>()
+                .unwrap()
+                .raw_os_error(),
+            Some(libc::EACCES)
+        );
+    }
+
+    #[test]
+    fn test_command_error_security_locked() {
+        let mut disk = Disk::new(None, DiskType::None, 0);
+        disk.path = Some(PathBuf::from("/dev/sda"));
+        let io_error = || Error::Io(std::io::Error::from_raw_os_error(libc::EIO));
+
+        let err = disk.command_error(ffi::ata::AtaCommand::Smart, io_error());
+        assert!(matches!(err, Error::Device { .. }));
+
+        // 字 128: 支持、启用、锁定
+        let mut identify = [0u8; 512];
+        identify[256] = 0x07;
+        *disk.identify.borrow_mut() = Some(IdentifyData::new(identify));
+
+        let err = disk.command_error(ffi::ata::AtaCommand::Smart, io_error());
+        assert!(matches!(err, Error::SecurityLocked(_)));
+        assert_eq!(err.raw_os_error(), Some(libc::EIO));
+        let source = std::error::Error::source(&err).unwrap();
+        assert!(source.to_string().starts_with("/dev/sda: "));
+
+        // 只影响 SMART 命令
+        let err = disk.command_error(ffi::ata::AtaCommand::CheckPowerMode, io_error());
+        assert!(matches!(err, Error::Device { .. }));
+    }
+
+    #[test]
+    fn test_check_return_status_ext() {
+        let status = |low: u8, mid: u8, high: u8| {
+            let mut registers = ffi::commands::AtaRegisters::new();
+            registers.set_lba_low(low);
+            registers.set_lba_mid(mid);
+            registers.set_lba_high(high);
+            registers
+        };
+
+        assert_eq!(
+            check_return_status_ext(&status(0x00, 0x4F, 0xC2), false),
+            Ok(true)
+        );
+        assert_eq!(
+            check_return_status_ext(&status(0x00, 0xF4, 0x2C), false),
+            Ok(false)
+        );
+
+        // 两种情况都忽略 LBA LOW
+        assert_eq!(
+            check_return_status_ext(&status(0x05, 0x4F, 0xC2), false),
+            Ok(true)
+        );
+        assert_eq!(
+            check_return_status_ext(&status(0x05, 0xF4, 0x2C), false),
+            Ok(false)
+        );
+        let err = check_return_status_ext(&status(0x05, 0x4F, 0x00), false).unwrap_err();
+        assert!(err.contains("LBA LOW 0x05"), "{}", err);
+        assert!(err.contains("LBA MID 0x4F"), "{}", err);
+
+        // 12 字节命令只检查 LBA MID
+        assert_eq!(
+            check_return_status_ext(&status(0x05, 0x4F, 0x00), true),
+            Ok(true)
+        );
+        assert_eq!(
+            check_return_status_ext(&status(0x00, 0xF4, 0x00), true),
+            Ok(false)
+        );
+
+        let mut registers = status(0x00, 0x00, 0x00);
+        registers.lba_ext = [0x01, 0x02, 0x03];
+        let err = check_return_status_ext(&registers, false).unwrap_err();
+        assert!(err.contains("0x01/0x02/0x03"), "{}", err);
+    }
+
+    #[test]
+    fn test_default_disk() {
+        let disk = Disk::default();
+        assert_eq!(disk.disk_type(), DiskType::None);
+        assert_eq!(disk.size(), 0);
+        assert!(disk.path().is_none());
+        assert!(disk.borrowed_fd().is_none());
+        assert!(disk.cached_smart().is_none());
+
+        assert!(matches!(disk.read_identify(), Err(Error::NotSupported(_))));
+        assert!(matches!(
+            disk.read_identify_cached(),
+            Err(Error::NotSupported(_))
+        ));
+        assert!(matches!(
+            disk.read_smart_data(),
+            Err(Error::NotSupported(_))
+        ));
+        assert!(matches!(disk.is_healthy(), Err(Error::NotSupported(_))));
+        assert!(matches!(
+            disk.smart_get_power_mode(),
+            Err(Error::NotSupported(_))
+        ));
+        assert!(matches!(
+            disk.start_self_test(SmartSelfTest::Short),
+            Err(Error::NotSupported(_))
+        ));
+        assert!(disk.try_clone().is_ok());
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let disk = Disk::from_blob().unwrap();
+        let output = format!("{:?}", disk);
+        assert!(output.contains("disk_type: Blob"));
+        assert!(output.contains("smart_data_cached: false"));
+    }
+
+    #[test]
+    fn test_blob_has_no_fd() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.borrowed_fd().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Blob 类型的 Disk 没有文件描述符")]
+    fn test_blob_as_raw_fd_panics() {
+        let disk = Disk::from_blob().unwrap();
+        let _ = disk.as_raw_fd();
+    }
+
+    #[test]
+    fn test_blob_type_needs_no_detection() {
+        let disk = Disk::from_blob().unwrap();
+        assert_eq!(disk.disk_type(), DiskType::Blob);
+        assert_eq!(disk.resolve_disk_type().unwrap(), DiskType::Blob);
+    }
+
+    #[test]
+    fn test_close_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.close().is_ok());
+    }
+
+    #[test]
+    fn test_close_syncs_writable_file() {
+        let path = std::env::temp_dir().join(format!("libatasmart-close-{}", std::process::id()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .unwrap();
+
+        let disk = Disk::new(Some(file), DiskType::None, 0);
+        assert!(disk.sync().is_ok());
+        assert!(disk.close().is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_close_read_only_file() {
+        let file = File::open("Cargo.toml").unwrap();
+        let disk = Disk::new(Some(file), DiskType::None, 0);
+        disk.self_test_started.set(true);
+        assert!(disk.close().is_ok());
+    }
+
+    #[test]
+    fn test_wakeup_policy() {
+        let mut disk = Disk::from_blob().unwrap();
+        assert_eq!(disk.wakeup_policy(), WakeupPolicy::AlwaysWake);
+
+        disk.set_wakeup_policy(WakeupPolicy::NeverWake);
+        assert_eq!(disk.wakeup_policy(), WakeupPolicy::NeverWake);
+
+        // 从未读取过数据,没有缓存
+        assert!(disk.cached_smart().is_none());
+    }
+
+    #[test]
+    fn test_never_wake_skips_identify_detection() {
+        let file = File::open("/dev/null").unwrap();
+        let mut disk = Disk::from_fd(OwnedFd::from(file)).unwrap();
+        disk.set_wakeup_policy(WakeupPolicy::NeverWake);
+
+        // 类型检测只发送 CHECK POWER MODE,不读取 IDENTIFY
+        assert!(disk.read_smart_data().is_err());
+        assert_eq!(disk.disk_type(), DiskType::None);
+        assert!(disk.identify.borrow().is_none());
+    }
+
+    #[test]
+    fn test_standby_timer_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(disk.get_standby_timer(), Err(Error::NoData)));
+        assert!(matches!(
+            disk.set_standby_timer(StandbyTimer::Minutes(10)),
+            Err(Error::NotSupported(_))
+        ));
+    }
 }
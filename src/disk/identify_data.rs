@@ -1,7 +1,7 @@
 //! IDENTIFY 数据封装
 
 use crate::error::Result;
-use crate::types::IdentifyParsedData;
+use crate::types::{FeatureFlags, IdentifyParsedData};
 
 /// IDENTIFY 数据
 #[derive(Debug, Clone)]
@@ -24,4 +24,132 @@ impl IdentifyData {
     pub fn parse(&self) -> Result<IdentifyParsedData> {
         crate::identify::parse::parse_identify_data(&self.raw)
     }
+
+    /// 读取第 `index` 个 IDENTIFY 字
+    ///
+    /// ATA 规定每个字按小端序存放,返回值与主机字节序无关。
+    /// `index` 超过 255 时返回 `None`。
+    ///
+    /// 型号、序列号等 ATA 字符串每个字内的两个字节是交换过的,
+    /// 直接使用字的值时需要自行处理,解析后的字符串参见 [`IdentifyData::parse`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let identify = disk.read_identify_cached()?;
+    /// // 字 217: 介质旋转速率
+    /// println!("字 217: 0x{:04X}", identify.word(217).unwrap());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn word(&self, index: usize) -> Option<u16> {
+        (index < 256).then(|| crate::utils::read_ata_word(&self.raw, index))
+    }
+
+    /// 命令集支持/启用标志 (字 82-87),不需要解析其他字段
+    pub fn features(&self) -> FeatureFlags {
+        crate::identify::parse::feature_flags(&self.raw)
+    }
+
+    /// 以字为单位获取全部 IDENTIFY 数据 (按小端序转换)
+    pub fn words(&self) -> [u16; 256] {
+        std::array::from_fn(|index| crate::utils::read_ata_word(&self.raw, index))
+    }
+
+    /// 逻辑扇区大小 (字节)
+    pub(crate) fn logical_sector_size(&self) -> u64 {
+        u64::from(crate::identify::parse::logical_sector_size(&self.raw))
+    }
+
+    /// 用户可寻址的逻辑扇区数
+    ///
+    /// 支持 48 位地址 (字 83 bit 10) 时使用字 100-103,否则使用字 60-61。
+    /// 没有报告时返回 `None`。
+    pub fn sector_count(&self) -> Option<u64> {
+        let words = self.words();
+        let sectors = if words[83] & (1 << 10) != 0 {
+            (100..=103)
+                .rev()
+                .fold(0u64, |acc, i| (acc << 16) | u64::from(words[i]))
+        } else {
+            u64::from(words[60]) | (u64::from(words[61]) << 16)
+        };
+
+        (sectors != 0).then_some(sectors)
+    }
+
+    /// 根据 IDENTIFY 数据计算设备容量 (字节)
+    ///
+    /// 即 [`IdentifyData::sector_count`] 乘以逻辑扇区大小。
+    /// 没有报告容量时返回 `None`。
+    pub fn capacity(&self) -> Option<u64> {
+        self.sector_count()?.checked_mul(self.logical_sector_size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 设置 IDENTIFY 字
+    fn set_word(raw: &mut [u8; 512], index: usize, value: u16) {
+        raw[index * 2..index * 2 + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn test_capacity_48bit() {
+        let mut raw = [0u8; 512];
+        set_word(&mut raw, 83, 1 << 10);
+        // 976773168 扇区 (500 GB)
+        set_word(&mut raw, 100, 0x6030);
+        set_word(&mut raw, 101, 0x3A38);
+
+        let identify = IdentifyData::new(raw);
+        assert_eq!(identify.sector_count(), Some(976773168));
+        assert_eq!(identify.capacity(), Some(976773168 * 512));
+    }
+
+    #[test]
+    fn test_capacity_28bit_and_4k_sectors() {
+        let mut raw = [0u8; 512];
+        set_word(&mut raw, 60, 1000);
+        // 逻辑扇区 4096 字节 = 2048 字
+        set_word(&mut raw, 106, 0x4000 | (1 << 12));
+        set_word(&mut raw, 117, 2048);
+
+        let identify = IdentifyData::new(raw);
+        assert_eq!(identify.capacity(), Some(1000 * 4096));
+    }
+
+    #[test]
+    fn test_word_byte_order() {
+        // 按字节构造,不依赖主机字节序
+        let mut raw = [0u8; 512];
+        raw[0] = 0x34;
+        raw[1] = 0x12;
+        raw[510] = 0xCD;
+        raw[511] = 0xAB;
+        // 型号 "AB" 在字 27 中为字节交换的形式
+        raw[54] = b'B';
+        raw[55] = b'A';
+
+        let identify = IdentifyData::new(raw);
+        assert_eq!(identify.word(0), Some(0x1234));
+        assert_eq!(identify.word(255), Some(0xABCD));
+        assert_eq!(identify.word(256), None);
+        assert_eq!(identify.word(27), Some(u16::from_be_bytes(*b"AB")));
+
+        let words = identify.words();
+        assert_eq!(words[0], 0x1234);
+        assert_eq!(words[255], 0xABCD);
+        assert!((0..256).all(|i| identify.word(i) == Some(words[i])));
+    }
+
+    #[test]
+    fn test_capacity_missing() {
+        assert_eq!(IdentifyData::new([0u8; 512]).sector_count(), None);
+        assert_eq!(IdentifyData::new([0u8; 512]).capacity(), None);
+    }
 }
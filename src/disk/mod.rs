@@ -3,9 +3,12 @@
 mod detect;
 mod device;
 mod identify_data;
+mod options;
+mod partition;
 mod smart_data;
 
 pub(crate) use detect::detect_disk_type;
 pub use device::Disk;
 pub use identify_data::IdentifyData;
-pub use smart_data::{SmartData, SmartInfo, SmartThresholds};
+pub use options::DiskOpenOptions;
+pub use smart_data::{SmartAttributeIter, SmartData, SmartInfo, SmartThresholds};
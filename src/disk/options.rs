@@ -0,0 +1,206 @@
+//! 磁盘打开选项
+
+use crate::disk::Disk;
+use crate::error::Result;
+use crate::types::{DiskType, WakeupPolicy};
+use std::path::Path;
+use std::time::Duration;
+
+/// 磁盘打开选项
+///
+/// 用于配置 [`Disk`] 的打开方式,[`Disk::open`] 等价于使用默认选项。
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::{DiskOpenOptions, WakeupPolicy};
+/// use std::time::Duration;
+///
+/// let disk = DiskOpenOptions::new()
+///     .exclusive(true)
+///     .non_blocking(true)
+///     .timeout(Duration::from_secs(5))
+///     .wakeup_policy(WakeupPolicy::NeverWake)
+///     .open("/dev/sda")?;
+/// # Ok::<(), libatasmart::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiskOpenOptions {
+    pub(crate) exclusive: bool,
+    pub(crate) non_blocking: bool,
+    pub(crate) disk_type: DiskType,
+    pub(crate) timeout: Duration,
+    pub(crate) wakeup_policy: WakeupPolicy,
+    pub(crate) resolve_partition: bool,
+    pub(crate) read_write: bool,
+}
+
+impl DiskOpenOptions {
+    /// 创建默认选项
+    pub fn new() -> Self {
+        Self {
+            exclusive: false,
+            non_blocking: false,
+            disk_type: DiskType::Auto,
+            timeout: Duration::from_millis(crate::ffi::commands::TIMEOUT_MS as u64),
+            wakeup_policy: WakeupPolicy::default(),
+            resolve_partition: true,
+            read_write: false,
+        }
+    }
+
+    /// 以独占方式打开 (O_EXCL)
+    ///
+    /// 设备已被挂载或被其他程序独占打开时,打开会失败 (EBUSY)
+    pub fn exclusive(&mut self, exclusive: bool) -> &mut Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// 以非阻塞方式打开 (O_NONBLOCK)
+    ///
+    /// 避免打开已被移除的可移动设备时长时间挂起
+    pub fn non_blocking(&mut self, non_blocking: bool) -> &mut Self {
+        self.non_blocking = non_blocking;
+        self
+    }
+
+    /// 指定磁盘类型
+    ///
+    /// 默认为 `DiskType::Auto`,即在第一次发送命令时自动检测
+    pub fn disk_type(&mut self, disk_type: DiskType) -> &mut Self {
+        self.disk_type = disk_type;
+        self
+    }
+
+    /// 设置命令超时时间
+    ///
+    /// 默认为 2 秒,超过 `u32::MAX` 毫秒的值会被截断
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置设备唤醒策略
+    pub fn wakeup_policy(&mut self, policy: WakeupPolicy) -> &mut Self {
+        self.wakeup_policy = policy;
+        self
+    }
+
+    /// 是否将分区解析为所属的整盘设备
+    ///
+    /// 默认开启,打开 `/dev/sda1` 时实际打开 `/dev/sda`。
+    /// 分区的大小会使扇区数检查失效,部分内核也会拒绝对分区发送 SG_IO。
+    pub fn resolve_partition(&mut self, resolve: bool) -> &mut Self {
+        self.resolve_partition = resolve;
+        self
+    }
+
+    /// 是否以读写方式打开
+    ///
+    /// 默认只读。自检、属性自动保存等会改变设备状态的命令需要读写方式打开,
+    /// 参见 [`Disk::open_read_write`]
+    pub fn read_write(&mut self, read_write: bool) -> &mut Self {
+        self.read_write = read_write;
+        self
+    }
+
+    /// 使用当前选项打开磁盘设备
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> Result<Disk> {
+        Disk::open_with_options(path.as_ref(), self)
+    }
+
+    /// 打开设备时附加的标志位
+    pub(crate) fn custom_flags(&self) -> i32 {
+        let mut flags = 0;
+        if self.exclusive {
+            flags |= libc::O_EXCL;
+        }
+        if self.non_blocking {
+            flags |= libc::O_NONBLOCK;
+        }
+        flags
+    }
+
+    /// 超时时间 (毫秒)
+    pub(crate) fn timeout_ms(&self) -> u32 {
+        self.timeout.as_millis().min(u32::MAX as u128) as u32
+    }
+}
+
+impl Default for DiskOpenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_default_options() {
+        let options = DiskOpenOptions::new();
+        assert_eq!(options.custom_flags(), 0);
+        assert_eq!(options.timeout_ms(), 2000);
+        assert_eq!(options.disk_type, DiskType::Auto);
+        assert_eq!(options.wakeup_policy, WakeupPolicy::AlwaysWake);
+        assert!(options.resolve_partition);
+        assert!(!options.read_write);
+    }
+
+    #[test]
+    fn test_custom_flags() {
+        let mut options = DiskOpenOptions::new();
+        options.exclusive(true);
+        assert_eq!(options.custom_flags(), libc::O_EXCL);
+
+        options.exclusive(false).non_blocking(true);
+        assert_eq!(options.custom_flags(), libc::O_NONBLOCK);
+    }
+
+    #[test]
+    fn test_timeout_saturates() {
+        let mut options = DiskOpenOptions::new();
+        options.timeout(Duration::from_secs(u64::MAX));
+        assert_eq!(options.timeout_ms(), u32::MAX);
+    }
+
+    #[test]
+    fn test_open_missing_device() {
+        let result = DiskOpenOptions::new()
+            .non_blocking(true)
+            .open("/dev/libatasmart-does-not-exist");
+        match result {
+            Err(Error::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+            other => panic!("意外的结果: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_open_character_device() {
+        // /dev/null 与 /dev/sgN 一样不支持 BLKGETSIZE64,大小记为 0
+        let disk = DiskOpenOptions::new()
+            .non_blocking(true)
+            .timeout(Duration::from_millis(100))
+            .open("/dev/null")
+            .unwrap();
+        assert_eq!(disk.size(), 0);
+        assert_eq!(disk.path(), Some(Path::new("/dev/null")));
+        assert!(!disk.is_writable());
+
+        let disk = DiskOpenOptions::new()
+            .read_write(true)
+            .open("/dev/null")
+            .unwrap();
+        assert!(disk.is_writable());
+    }
+
+    #[test]
+    fn test_open_regular_file() {
+        // 普通文件不是设备,仍然拒绝打开
+        let result = DiskOpenOptions::new().open("Cargo.toml");
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
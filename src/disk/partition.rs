@@ -0,0 +1,71 @@
+//! 分区到整盘设备的解析
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 如果路径指向分区,返回所属整盘设备的路径
+///
+/// 通过 `/sys/class/block/<name>/partition` 判断是否为分区,
+/// 其 sysfs 父目录即为整盘设备。
+pub(crate) fn parent_device(path: &Path) -> Option<PathBuf> {
+    parent_device_in(Path::new("/sys/class/block"), Path::new("/dev"), path)
+}
+
+/// 在指定的 sysfs 和 /dev 目录中解析分区
+pub(crate) fn parent_device_in(sys_class_block: &Path, dev: &Path, path: &Path) -> Option<PathBuf> {
+    // 解析 /dev/disk/by-id/ 等符号链接
+    let resolved = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let name = resolved.file_name()?;
+
+    let entry = sys_class_block.join(name);
+    if !entry.join("partition").exists() {
+        return None;
+    }
+
+    // /sys/class/block/sda1 -> ../../devices/.../block/sda/sda1
+    let parent = fs::canonicalize(&entry)
+        .ok()?
+        .parent()?
+        .file_name()?
+        .to_owned();
+    Some(dev.join(parent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    /// 构造 sysfs 布局: devices/<disk>/<part>/partition 以及 class/block 下的符号链接
+    fn fake_sysfs(root: &Path, disk: &str, part: &str) {
+        let devices = root.join("devices");
+        let class_block = root.join("class").join("block");
+        fs::create_dir_all(devices.join(disk).join(part)).unwrap();
+        fs::create_dir_all(&class_block).unwrap();
+        fs::write(devices.join(disk).join(part).join("partition"), "1\n").unwrap();
+
+        symlink(devices.join(disk), class_block.join(disk)).unwrap();
+        symlink(devices.join(disk).join(part), class_block.join(part)).unwrap();
+    }
+
+    #[test]
+    fn test_parent_device_in() {
+        let root = std::env::temp_dir().join(format!("libatasmart-part-{}", std::process::id()));
+        fake_sysfs(&root, "sda", "sda1");
+        fake_sysfs(&root, "nvme0n1", "nvme0n1p2");
+
+        let class_block = root.join("class").join("block");
+        let dev = Path::new("/dev");
+
+        let sda = parent_device_in(&class_block, dev, Path::new("/dev/sda1"));
+        let nvme = parent_device_in(&class_block, dev, Path::new("/dev/nvme0n1p2"));
+        let whole = parent_device_in(&class_block, dev, Path::new("/dev/sda"));
+        let unknown = parent_device_in(&class_block, dev, Path::new("/dev/sdz"));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(sda, Some(PathBuf::from("/dev/sda")));
+        assert_eq!(nvme, Some(PathBuf::from("/dev/nvme0n1")));
+        assert_eq!(whole, None);
+        assert_eq!(unknown, None);
+    }
+}
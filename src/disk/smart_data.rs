@@ -1,19 +1,37 @@
 //! SMART 数据封装
 
+use crate::disk::IdentifyData;
 use crate::error::Result;
 use crate::types::*;
+use std::ops::ControlFlow;
 
 /// SMART 数据
 #[derive(Debug, Clone)]
 pub struct SmartData {
     raw: [u8; 512],
-    disk_size: u64,
+    /// 读取时的设备信息
+    context: AttributeContext,
 }
 
 impl SmartData {
     /// 从原始数据创建
     pub(crate) fn new(raw: [u8; 512], disk_size: u64) -> Self {
-        Self { raw, disk_size }
+        Self {
+            raw,
+            context: AttributeContext::new(disk_size),
+        }
+    }
+
+    /// 从 IDENTIFY 数据中补充型号和逻辑扇区大小
+    pub(crate) fn with_identify(mut self, identify: Option<&IdentifyData>) -> Self {
+        if let Some(parsed) = identify.and_then(|identify| identify.parse().ok()) {
+            self.context.sector_size = parsed.logical_sector_size;
+            self.context.power_on_encoding =
+                VendorAttributeTable::for_model(&parsed.model).power_on_encoding;
+            self.context.model = Some(parsed.model);
+            self.context.rotation_rate = parsed.rotation_rate;
+        }
+        self
     }
 
     /// 获取原始数据
@@ -21,44 +39,222 @@ impl SmartData {
         &self.raw
     }
 
+    /// 获取属性解析上下文
+    pub fn context(&self) -> &AttributeContext {
+        &self.context
+    }
+
     /// 解析 SMART 数据
     pub fn parse(&self) -> Result<SmartParsedData> {
         crate::smart::parse::parse_smart_data(&self.raw)
     }
 
     /// 解析 SMART 属性 (需要阈值数据)
+    ///
+    /// 使用读取时记录的设备信息,参见 [`SmartData::context`]
     pub fn parse_attributes(
         &self,
         thresholds: Option<&SmartThresholds>,
     ) -> Result<Vec<SmartAttributeParsedData>> {
-        let thresholds_raw = thresholds.map(|t| t.raw());
+        self.parse_attributes_with_context(thresholds, &self.context)
+    }
+
+    /// 使用指定的上下文解析 SMART 属性
+    ///
+    /// 用于调用方掌握更准确的设备大小或扇区大小的情况
+    pub fn parse_attributes_with_context(
+        &self,
+        thresholds: Option<&SmartThresholds>,
+        context: &AttributeContext,
+    ) -> Result<Vec<SmartAttributeParsedData>> {
+        Ok(self.attributes_with_context(thresholds, context).collect())
+    }
+
+    /// 逐个解析 SMART 属性并交给回调处理
+    ///
+    /// 与 libatasmart 的 `sk_disk_smart_parse_attributes` 相同,
+    /// 每解析出一个属性就调用一次回调,不分配属性列表。
+    /// 回调返回 `ControlFlow::Break(())` 时停止解析剩余的槽位。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let smart = disk.read_smart()?;
+    /// smart.data.for_each_attribute(smart.thresholds.as_ref(), |attr| {
+    ///     if attr.warn {
+    ///         println!("{} 异常", attr.name);
+    ///         return ControlFlow::Break(());
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// })?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn for_each_attribute<F>(
+        &self,
+        thresholds: Option<&SmartThresholds>,
+        mut f: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&SmartAttributeParsedData) -> ControlFlow<()>,
+    {
+        for attr in self.attributes(thresholds) {
+            if f(&attr).is_break() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// 按 ID 解析单个属性
+    ///
+    /// 直接在原始数据中查找 ID 匹配的槽位,只解析该槽位及其阈值。
+    /// 同一 ID 出现在多个槽位时使用第一个,与 [`SmartData::attributes`] 的顺序一致。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let smart = disk.read_smart()?;
+    /// if let Some(attr) = smart.data.attribute(194, smart.thresholds.as_ref()) {
+    ///     println!("{}: {}", attr.name, attr.value());
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn attribute(
+        &self,
+        id: u8,
+        thresholds: Option<&SmartThresholds>,
+    ) -> Option<SmartAttributeParsedData> {
+        let attr_data = find_slot(&self.raw, id)?;
+        let threshold_data = thresholds.and_then(|t| find_slot(t.raw(), id));
+        crate::smart::attributes::parse_attribute(attr_data, threshold_data, &self.context)
+    }
+
+    /// 按槽位顺序惰性解析 SMART 属性
+    ///
+    /// 使用读取时记录的设备信息,参见 [`SmartData::context`]。
+    /// 迭代过程不分配堆内存,适合高频轮询。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let smart = disk.read_smart()?;
+    /// let warnings = smart
+    ///     .data
+    ///     .attributes(smart.thresholds.as_ref())
+    ///     .filter(|attr| attr.warn)
+    ///     .count();
+    /// println!("异常属性: {}", warnings);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn attributes<'a>(
+        &'a self,
+        thresholds: Option<&'a SmartThresholds>,
+    ) -> SmartAttributeIter<'a> {
+        self.attributes_with_context(thresholds, &self.context)
+    }
 
-        let mut attributes = Vec::new();
+    /// 使用指定的上下文惰性解析 SMART 属性
+    ///
+    /// 参见 [`SmartData::parse_attributes_with_context`]
+    pub fn attributes_with_context<'a>(
+        &'a self,
+        thresholds: Option<&'a SmartThresholds>,
+        context: &'a AttributeContext,
+    ) -> SmartAttributeIter<'a> {
+        SmartAttributeIter::new(&self.raw, thresholds.map(|t| t.raw()), context)
+    }
+}
+
+/// SMART 属性迭代器
+///
+/// 由 [`SmartData::attributes`] 创建,跳过 ID 为 0 的空槽位
+#[derive(Debug, Clone)]
+pub struct SmartAttributeIter<'a> {
+    raw: &'a [u8; 512],
+    thresholds: Option<&'a [u8; 512]>,
+    /// 属性 ID 到阈值槽位的索引,值为槽位号加 1,0 表示没有阈值
+    threshold_index: [u8; 256],
+    context: &'a AttributeContext,
+    slot: usize,
+}
+
+impl<'a> SmartAttributeIter<'a> {
+    fn new(
+        raw: &'a [u8; 512],
+        thresholds: Option<&'a [u8; 512]>,
+        context: &'a AttributeContext,
+    ) -> Self {
+        let mut threshold_index = [0u8; 256];
+        if let Some(t) = thresholds {
+            // 倒序填充,同一 ID 出现多次时使用第一个槽位
+            for slot in (0..ATTRIBUTE_SLOTS).rev() {
+                let id = t[2 + slot * 12];
+                if id != 0 {
+                    threshold_index[id as usize] = slot as u8 + 1;
+                }
+            }
+        }
+
+        Self {
+            raw,
+            thresholds,
+            threshold_index,
+            context,
+            slot: 0,
+        }
+    }
+}
+
+/// SMART 数据中的属性槽位数
+const ATTRIBUTE_SLOTS: usize = 30;
+
+/// 查找 ID 匹配的第一个槽位 (SMART 数据和阈值的槽位布局相同)
+fn find_slot(raw: &[u8; 512], id: u8) -> Option<&[u8]> {
+    if id == 0 {
+        return None;
+    }
+    raw[2..2 + ATTRIBUTE_SLOTS * 12]
+        .chunks_exact(12)
+        .find(|slot| slot[0] == id)
+}
+
+impl Iterator for SmartAttributeIter<'_> {
+    type Item = SmartAttributeParsedData;
 
+    fn next(&mut self) -> Option<Self::Item> {
         // SMART 数据从字节 2 开始,每个属性 12 字节,共 30 个槽位
-        for i in 0..30 {
-            let offset = 2 + i * 12;
+        while self.slot < ATTRIBUTE_SLOTS {
+            let offset = 2 + self.slot * 12;
+            self.slot += 1;
             let attr_data = &self.raw[offset..offset + 12];
 
-            // 查找对应的阈值数据
-            let threshold_data = thresholds_raw.and_then(|t| {
-                for j in 0..30 {
-                    let t_offset = 2 + j * 12;
-                    if t[t_offset] == attr_data[0] && attr_data[0] != 0 {
-                        return Some(&t[t_offset..t_offset + 12]);
-                    }
-                }
-                None
+            let threshold_data = self.thresholds.and_then(|t| {
+                let index = self.threshold_index[attr_data[0] as usize] as usize;
+                let t_offset = 2 + index.checked_sub(1)? * 12;
+                Some(&t[t_offset..t_offset + 12])
             });
 
             if let Some(attr) =
-                crate::smart::attributes::parse_attribute(attr_data, threshold_data, self.disk_size)
+                crate::smart::attributes::parse_attribute(attr_data, threshold_data, self.context)
             {
-                attributes.push(attr);
+                return Some(attr);
             }
         }
+        None
+    }
 
-        Ok(attributes)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(ATTRIBUTE_SLOTS - self.slot))
     }
 }
 
@@ -99,4 +295,21 @@ impl SmartInfo {
     pub fn parse_attributes(&self) -> Result<Vec<SmartAttributeParsedData>> {
         self.data.parse_attributes(self.thresholds.as_ref())
     }
+
+    /// 惰性解析 SMART 属性
+    ///
+    /// 参见 [`SmartData::attributes`]
+    pub fn attributes(&self) -> SmartAttributeIter<'_> {
+        self.data.attributes(self.thresholds.as_ref())
+    }
+
+    /// 逐个解析 SMART 属性并交给回调处理
+    ///
+    /// 参见 [`SmartData::for_each_attribute`]
+    pub fn for_each_attribute<F>(&self, f: F) -> Result<()>
+    where
+        F: FnMut(&SmartAttributeParsedData) -> ControlFlow<()>,
+    {
+        self.data.for_each_attribute(self.thresholds.as_ref(), f)
+    }
 }
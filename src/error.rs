@@ -1,6 +1,7 @@
 //! 错误类型定义
 
 use std::io;
+use std::path::PathBuf;
 
 /// libatasmart 错误类型
 #[derive(Debug, thiserror::Error)]
@@ -9,6 +10,16 @@ pub enum Error {
     #[error("I/O 错误: {0}")]
     Io(#[from] io::Error),
 
+    /// 打开的设备上发生的 I/O 错误,附带设备路径
+    #[error("{}: I/O 错误: {source}", .path.display())]
+    Device {
+        /// 设备路径
+        path: PathBuf,
+        /// 原始 I/O 错误
+        #[source]
+        source: io::Error,
+    },
+
     /// 设备不支持
     #[error("设备不支持此操作: {0}")]
     NotSupported(String),
@@ -21,6 +32,10 @@ pub enum Error {
     #[error("数据无效或损坏: {0}")]
     InvalidData(String),
 
+    /// 设备已被 ATA 安全功能锁定,SMART 命令被中止
+    #[error("设备已被 ATA 安全功能锁定,需要先解锁才能执行 SMART 命令")]
+    SecurityLocked(#[source] Box<Error>),
+
     /// 设备处于睡眠状态
     #[error("设备处于睡眠状态")]
     DeviceSleeping,
@@ -30,5 +45,24 @@ pub enum Error {
     NoData,
 }
 
+impl Error {
+    /// 获取底层 I/O 错误
+    ///
+    /// 对 [`Error::Io`] 和 [`Error::Device`] 返回原始错误,
+    /// [`Error::SecurityLocked`] 返回其来源中的 I/O 错误,其他变体返回 `None`
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            Self::Io(e) | Self::Device { source: e, .. } => Some(e),
+            Self::SecurityLocked(source) => source.io_error(),
+            _ => None,
+        }
+    }
+
+    /// 获取系统错误码 (errno),参见 [`io::Error::raw_os_error`]
+    pub fn raw_os_error(&self) -> Option<i32> {
+        self.io_error().and_then(io::Error::raw_os_error)
+    }
+}
+
 /// Result 类型别名
 pub type Result<T> = std::result::Result<T, Error>;
@@ -12,6 +12,21 @@ pub(crate) enum AtaCommand {
     Smart = 0xB0,
     /// CHECK POWER MODE 命令
     CheckPowerMode = 0xE5,
+    /// IDLE 命令 (设置待机计时器)
+    Idle = 0xE3,
+    /// READ LOG EXT 命令 (通用日志)
+    ReadLogExt = 0x2F,
+    /// WRITE LOG EXT 命令 (通用日志)
+    WriteLogExt = 0x3F,
+    /// SET FEATURES 命令
+    SetFeatures = 0xEF,
+}
+
+impl AtaCommand {
+    /// 是否为 48 位命令
+    pub fn is_48bit(&self) -> bool {
+        matches!(self, Self::ReadLogExt | Self::WriteLogExt)
+    }
 }
 
 /// SMART 子命令
@@ -22,6 +37,10 @@ pub(crate) enum SmartCommand {
     ReadData = 0xD0,
     /// 读取 SMART 阈值
     ReadThresholds = 0xD1,
+    /// 启用/禁用属性自动保存
+    AttributeAutosave = 0xD2,
+    /// 读取 SMART 日志
+    ReadLog = 0xD5,
     /// 立即执行离线测试
     ExecuteOfflineImmediate = 0xD4,
     /// 启用 SMART 操作
@@ -32,6 +51,16 @@ pub(crate) enum SmartCommand {
     ReturnStatus = 0xDA,
 }
 
+/// SET FEATURES 子命令 (FEATURES 寄存器)
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetFeaturesCommand {
+    /// 启用自动声学管理
+    EnableAcousticManagement = 0x42,
+    /// 禁用自动声学管理
+    DisableAcousticManagement = 0xC2,
+}
+
 /// ATA 命令方向
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Direction {
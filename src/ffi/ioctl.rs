@@ -2,6 +2,7 @@
 //!
 //! 使用 libc 提供的 ioctl 系统调用
 
+use super::nvme::NvmePassthruCmd;
 use super::scsi::SgIoHdr;
 use std::os::unix::io::RawFd;
 
@@ -17,6 +18,9 @@ type IoctlRequest = libc::c_int;
 #[cfg(not(target_env = "musl"))]
 type IoctlRequest = libc::c_ulong;
 
+// HDIO_* 和 SG_IO 是不编码参数大小的旧式请求码,在所有架构上取值相同,
+// BLKGETSIZE64 和 NVME_IOCTL_ADMIN_CMD 使用 _IOR/_IOWR 编码,需要按目标平台计算。
+
 /// HDIO_DRIVE_CMD - IDE 驱动器命令
 /// 请求码: 0x031f
 const HDIO_DRIVE_CMD: IoctlRequest = 0x031f;
@@ -29,13 +33,89 @@ const HDIO_DRIVE_TASK: IoctlRequest = 0x031e;
 /// 请求码: 0x2285
 const SG_IO: IoctlRequest = 0x2285;
 
-/// BLKGETSIZE64 - 获取块设备大小
-/// 请求码: 0x80081272
-#[cfg(target_env = "musl")]
-const BLKGETSIZE64: IoctlRequest = 0x80081272u32 as i32;
+/// _IOC 方向字段的位置和 "读"/"写" 方向的取值
+///
+/// powerpc、mips 和 sparc 的大小字段只有 13 位,方向字段从第 29 位开始
+#[cfg(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+const IOC_DIR_SHIFT: u32 = 29;
+
+#[cfg(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+const IOC_WRITE: u32 = 4;
+
+#[cfg(not(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+const IOC_DIR_SHIFT: u32 = 30;
+
+#[cfg(not(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+const IOC_WRITE: u32 = 1;
+
+const IOC_READ: u32 = 2;
+
+/// 与内核 `_IOR(type, nr, size)` 宏相同的请求码计算
+const fn ior(ty: u8, nr: u8, size: usize) -> u32 {
+    (IOC_READ << IOC_DIR_SHIFT) | ((size as u32) << 16) | ((ty as u32) << 8) | nr as u32
+}
 
-#[cfg(not(target_env = "musl"))]
-const BLKGETSIZE64: IoctlRequest = 0x80081272;
+/// 与内核 `_IOWR(type, nr, size)` 宏相同的请求码计算
+const fn iowr(ty: u8, nr: u8, size: usize) -> u32 {
+    ((IOC_READ | IOC_WRITE) << IOC_DIR_SHIFT)
+        | ((size as u32) << 16)
+        | ((ty as u32) << 8)
+        | nr as u32
+}
+
+/// BLKGETSIZE64 - 获取块设备大小
+///
+/// 内核定义为 `_IOR(0x12, 114, size_t)`,参数大小随 `size_t` 变化:
+/// 64 位平台为 0x80081272,32 位平台 (armv7、i686) 为 0x80041272
+const BLKGETSIZE64: IoctlRequest = ior(0x12, 114, std::mem::size_of::<usize>()) as IoctlRequest;
+
+#[cfg(target_arch = "x86_64")]
+const _: () = assert!(BLKGETSIZE64 as u32 == 0x80081272);
+#[cfg(target_arch = "aarch64")]
+const _: () = assert!(BLKGETSIZE64 as u32 == 0x80081272);
+#[cfg(target_arch = "x86")]
+const _: () = assert!(BLKGETSIZE64 as u32 == 0x80041272);
+#[cfg(target_arch = "arm")]
+const _: () = assert!(BLKGETSIZE64 as u32 == 0x80041272);
+#[cfg(target_arch = "powerpc64")]
+const _: () = assert!(BLKGETSIZE64 as u32 == 0x40081272);
+
+/// NVME_IOCTL_ADMIN_CMD - NVMe 管理命令
+///
+/// 内核定义为 `_IOWR('N', 0x41, struct nvme_admin_cmd)`,结构体大小固定为 72 字节
+const NVME_IOCTL_ADMIN_CMD: IoctlRequest =
+    iowr(b'N', 0x41, std::mem::size_of::<NvmePassthruCmd>()) as IoctlRequest;
+
+#[cfg(target_arch = "x86_64")]
+const _: () = assert!(NVME_IOCTL_ADMIN_CMD as u32 == 0xC0484E41);
 
 /// 底层 ioctl 调用封装
 unsafe fn raw_ioctl<T>(fd: RawFd, request: IoctlRequest, arg: *mut T) -> std::io::Result<()> {
@@ -63,6 +143,13 @@ pub(crate) fn sg_io_cmd(fd: RawFd, hdr: &mut SgIoHdr) -> std::io::Result<()> {
     unsafe { raw_ioctl(fd, SG_IO, hdr as *mut SgIoHdr) }
 }
 
+/// 安全的 NVME_IOCTL_ADMIN_CMD 封装
+///
+/// 调用方负责保证 `cmd.addr` 指向至少 `cmd.data_len` 字节的有效缓冲区
+pub(crate) fn nvme_admin_cmd(fd: RawFd, cmd: &mut NvmePassthruCmd) -> std::io::Result<()> {
+    unsafe { raw_ioctl(fd, NVME_IOCTL_ADMIN_CMD, cmd as *mut NvmePassthruCmd) }
+}
+
 /// 安全的 BLKGETSIZE64 封装
 pub(crate) fn get_block_size(fd: RawFd) -> std::io::Result<u64> {
     let mut size: u64 = 0;
@@ -82,6 +169,13 @@ mod tests {
         // 实际的功能测试需要真实的设备
     }
 
+    #[test]
+    fn test_blkgetsize64_encoding() {
+        let code = BLKGETSIZE64 as u32;
+        assert_eq!(code & 0xFFFF, 0x1272);
+        assert_eq!((code >> 16) & 0x1FFF, std::mem::size_of::<usize>() as u32);
+    }
+
     #[test]
     #[should_panic(expected = "数据缓冲区至少需要 4 字节")]
     fn test_drive_cmd_buffer_size() {
@@ -6,4 +6,5 @@
 pub(crate) mod ata;
 pub(crate) mod commands;
 pub(crate) mod ioctl;
+pub(crate) mod nvme;
 pub(crate) mod scsi;
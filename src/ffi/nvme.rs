@@ -0,0 +1,87 @@
+//! NVMe 管理命令和数据结构
+
+use super::ioctl::nvme_admin_cmd;
+use std::os::unix::io::RawFd;
+
+/// GET LOG PAGE 管理命令操作码
+pub(crate) const NVME_ADMIN_GET_LOG_PAGE: u8 = 0x02;
+
+/// 表示所有命名空间的 NSID
+pub(crate) const NVME_NSID_ALL: u32 = 0xFFFF_FFFF;
+
+/// NVMe 直通命令 (对应 Linux struct nvme_passthru_cmd)
+#[repr(C)]
+#[derive(Debug)]
+pub(crate) struct NvmePassthruCmd {
+    pub opcode: u8,
+    pub flags: u8,
+    pub rsvd1: u16,
+    pub nsid: u32,
+    pub cdw2: u32,
+    pub cdw3: u32,
+    pub metadata: u64,
+    pub addr: u64,
+    pub metadata_len: u32,
+    pub data_len: u32,
+    pub cdw10: u32,
+    pub cdw11: u32,
+    pub cdw12: u32,
+    pub cdw13: u32,
+    pub cdw14: u32,
+    pub cdw15: u32,
+    pub timeout_ms: u32,
+    pub result: u32,
+}
+
+impl NvmePassthruCmd {
+    /// 创建新的直通命令
+    pub(crate) fn new() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// 发送 GET LOG PAGE 命令,读取 `data.len()` 字节 (必须为 4 的倍数)
+pub(crate) fn get_log_page(
+    fd: RawFd,
+    log_id: u8,
+    nsid: u32,
+    data: &mut [u8],
+    timeout_ms: u32,
+) -> std::io::Result<()> {
+    assert!(
+        !data.is_empty() && data.len().is_multiple_of(4),
+        "日志长度必须为 4 字节的整数倍"
+    );
+
+    // NUMD 为双字数减 1,低 16 位放在 CDW10 31:16,高 16 位放在 CDW11 15:0
+    let numd = (data.len() / 4 - 1) as u32;
+
+    let mut cmd = NvmePassthruCmd::new();
+    cmd.opcode = NVME_ADMIN_GET_LOG_PAGE;
+    cmd.nsid = nsid;
+    cmd.addr = data.as_mut_ptr() as u64;
+    cmd.data_len = data.len() as u32;
+    cmd.cdw10 = ((numd & 0xFFFF) << 16) | u32::from(log_id);
+    cmd.cdw11 = numd >> 16;
+    cmd.timeout_ms = timeout_ms;
+
+    nvme_admin_cmd(fd, &mut cmd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passthru_cmd_size() {
+        // 与内核结构体大小一致
+        assert_eq!(std::mem::size_of::<NvmePassthruCmd>(), 72);
+    }
+
+    #[test]
+    #[should_panic(expected = "日志长度必须为 4 字节的整数倍")]
+    fn test_get_log_page_length() {
+        let mut data = [0u8; 6];
+        let _ = get_log_page(-1, 0x02, NVME_NSID_ALL, &mut data, 1000);
+    }
+}
@@ -0,0 +1,28 @@
+//! 模糊测试入口
+//!
+//! 仅在启用 `fuzzing` feature 时编译,供 `fuzz/` 目录中的目标使用,不属于稳定 API。
+
+use crate::disk::{SmartData, SmartThresholds};
+use crate::error::Result;
+use crate::smart::BlobData;
+use crate::types::{SmartAttributeParsedData, SmartParsedData};
+
+/// 解析 blob 数据
+pub fn parse_blob(data: &[u8]) -> Result<BlobData> {
+    crate::smart::blob::parse_blob(data)
+}
+
+/// 解析 SMART 数据
+pub fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
+    crate::smart::parse_smart_data(raw)
+}
+
+/// 解析全部 30 个属性槽位
+pub fn parse_attributes(
+    raw: &[u8; 512],
+    thresholds: Option<&[u8; 512]>,
+    disk_size: u64,
+) -> Result<Vec<SmartAttributeParsedData>> {
+    let thresholds = thresholds.map(|t| SmartThresholds::new(*t));
+    SmartData::new(*raw, disk_size).parse_attributes(thresholds.as_ref())
+}
@@ -1,13 +1,20 @@
 //! IDENTIFY 数据解析
 
 use crate::error::Result;
-use crate::types::IdentifyParsedData;
-use crate::utils::read_ata_string;
+use crate::types::{
+    AamInfo, AcousticLevel, ApmInfo, AtaVersion, FeatureFlags, FeatureState, FormFactor,
+    IdentifyParsedData, RotationRate, SanitizeSupport, SataInfo, SecurityState, SecurityStatus,
+    Transport, TrimSupport, ZonedCapability,
+};
+use crate::utils::{read_ata_string, read_ata_word};
 
 /// 解析 IDENTIFY 数据
 ///
 /// 从 512 字节的 IDENTIFY 数据中提取设备信息
 pub(crate) fn parse_identify_data(raw: &[u8; 512]) -> Result<IdentifyParsedData> {
+    // ATA 规范版本：字 80 (主版本位图) 和字 81 (次版本代码)
+    let ata_version = AtaVersion::from_words(read_ata_word(raw, 80), read_ata_word(raw, 81));
+
     // 序列号：字节 20-39 (20 字节)
     let serial = read_ata_string(&raw[20..40]);
 
@@ -17,13 +24,277 @@ pub(crate) fn parse_identify_data(raw: &[u8; 512]) -> Result<IdentifyParsedData>
     // 型号：字节 54-93 (40 字节)
     let model = read_ata_string(&raw[54..94]);
 
+    // 安全状态：字 128,擦除时间：字 89 (普通) 和字 90 (增强)
+    let security =
+        SecurityStatus::from_word(read_ata_word(raw, 128)).map(|security| SecurityStatus {
+            erase_time_minutes: erase_time_minutes(read_ata_word(raw, 89)),
+            enhanced_erase_time_minutes: erase_time_minutes(read_ata_word(raw, 90)),
+            ..security
+        });
+
+    // 扇区大小：字 106、117-118 和 209
+    let logical_sector_size = logical_sector_size(raw);
+    let physical_sector_size = physical_sector_size(raw, logical_sector_size);
+    let sector_alignment_offset = sector_alignment_offset(raw);
+
+    // 旋转速率：字 217
+    let rotation_rate = match RotationRate::from_word(read_ata_word(raw, 217)) {
+        RotationRate::Unknown => None,
+        rate => Some(rate),
+    };
+
+    // 全球唯一名称：字 108-111
+    let wwn = world_wide_name(raw);
+
+    // SATA 能力：字 76 (支持的速率、NCQ) 和字 77 (当前速率)
+    let sata = SataInfo::from_words(read_ata_word(raw, 76), read_ata_word(raw, 77));
+
+    // NCQ 队列深度：字 75,只有字 76 表示支持 NCQ 时才有意义
+    let queue_depth = queue_depth(raw, sata.as_ref());
+
+    // 高级电源管理：字 83/86 bit 3 表示支持/启用,字 91 bit 7:0 为当前级别
+    let apm = apm_info(raw);
+
+    // 自动声学管理：字 83/86 bit 9 表示支持/启用,字 94 为当前级别和推荐级别
+    let aam = aam_info(raw);
+
+    // TRIM：字 169、69 和 105
+    let trim = trim_support(raw);
+
+    // 写缓存和预读：字 82/85 bit 5、6
+    let write_cache = FeatureState::from_words(read_ata_word(raw, 82), read_ata_word(raw, 85), 5);
+    let read_lookahead =
+        FeatureState::from_words(read_ata_word(raw, 82), read_ata_word(raw, 85), 6);
+
+    // 命令集支持/启用标志：字 82-87
+    let features = feature_flags(raw);
+
+    // SANITIZE：字 59 bit 15:10
+    let sanitize = sanitize_support(raw);
+
+    // 分区能力：字 69 bit 1:0
+    let zoned = ZonedCapability::from_word(read_ata_word(raw, 69));
+
+    // 外形尺寸：字 168 bit 3:0
+    let form_factor = FormFactor::from_word(read_ata_word(raw, 168));
+
+    // 传输类型：字 222
+    let transport = Transport::from_word(read_ata_word(raw, 222));
+
+    // 附加产品标识：字节 340-347 (字 170-173)
+    let additional_product_id = Some(read_ata_string(&raw[340..348])).filter(|id| !id.is_empty());
+
     Ok(IdentifyParsedData {
         serial,
         firmware,
         model,
+        logical_sector_size,
+        physical_sector_size,
+        sector_alignment_offset,
+        rotation_rate,
+        wwn,
+        ata_version,
+        sata,
+        queue_depth,
+        trim,
+        security,
+        apm,
+        aam,
+        write_cache,
+        read_lookahead,
+        features,
+        sanitize,
+        zoned,
+        form_factor,
+        transport,
+        additional_product_id,
+    })
+}
+
+/// SANITIZE 功能支持情况
+///
+/// 字 59 bit 12 表示支持 SANITIZE,字为 0xFFFF 时视为未实现
+fn sanitize_support(raw: &[u8; 512]) -> Option<SanitizeSupport> {
+    let word59 = read_ata_word(raw, 59);
+    if word59 == 0xFFFF || word59 & (1 << 12) == 0 {
+        return None;
+    }
+
+    Some(SanitizeSupport {
+        crypto_scramble: word59 & (1 << 13) != 0,
+        overwrite: word59 & (1 << 14) != 0,
+        block_erase: word59 & (1 << 15) != 0,
+        antifreeze_lock: word59 & (1 << 10) != 0,
+        commands_allowed: word59 & (1 << 11) != 0,
+    })
+}
+
+/// 命令集支持/启用标志 (字 82-87)
+pub(crate) fn feature_flags(raw: &[u8; 512]) -> FeatureFlags {
+    let mut words = [0u16; 6];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = read_ata_word(raw, 82 + i);
+    }
+    FeatureFlags::from_words(words)
+}
+
+/// NCQ 队列深度
+///
+/// 字 75 bit 4:0 为最大队列深度减 1,PATA 或桥接设备上该字无效
+fn queue_depth(raw: &[u8; 512], sata: Option<&SataInfo>) -> Option<u8> {
+    if !sata.is_some_and(|sata| sata.ncq_supported) {
+        return None;
+    }
+
+    let word75 = read_ata_word(raw, 75);
+    if word75 == 0xFFFF {
+        return None;
+    }
+
+    Some((word75 & 0x1F) as u8 + 1)
+}
+
+/// 自动声学管理信息
+///
+/// 字 83 需要 bit 15:14 = 01b 才有效,字 94 为 0x0000 或 0xFFFF 时视为未报告
+fn aam_info(raw: &[u8; 512]) -> Option<AamInfo> {
+    let word83 = read_ata_word(raw, 83);
+    if word83 & 0xC000 != 0x4000 || word83 & (1 << 9) == 0 {
+        return None;
+    }
+
+    let word94 = read_ata_word(raw, 94);
+    if word94 == 0x0000 || word94 == 0xFFFF {
+        return None;
+    }
+
+    Some(AamInfo {
+        enabled: read_ata_word(raw, 86) & (1 << 9) != 0,
+        current: AcousticLevel::from_value(word94 as u8),
+        recommended: AcousticLevel::from_value((word94 >> 8) as u8),
+    })
+}
+
+/// 高级电源管理信息
+///
+/// 字 83 需要 bit 15:14 = 01b 才有效;级别 0x00 和 0xFF 为保留值
+fn apm_info(raw: &[u8; 512]) -> Option<ApmInfo> {
+    let word83 = read_ata_word(raw, 83);
+    if word83 & 0xC000 != 0x4000 || word83 & (1 << 3) == 0 {
+        return None;
+    }
+
+    let enabled = read_ata_word(raw, 86) & (1 << 3) != 0;
+    let level = read_ata_word(raw, 91) as u8;
+    let level = (enabled && level != 0x00 && level != 0xFF).then_some(level);
+
+    Some(ApmInfo { enabled, level })
+}
+
+/// TRIM 支持情况
+///
+/// 字 169 bit 0 表示支持 DATA SET MANAGEMENT 的 TRIM,
+/// 字 169 为 0xFFFF 时视为未实现
+fn trim_support(raw: &[u8; 512]) -> Option<TrimSupport> {
+    let word169 = read_ata_word(raw, 169);
+    if word169 == 0xFFFF || word169 & 0x0001 == 0 {
+        return None;
+    }
+
+    let word69 = read_ata_word(raw, 69);
+    let deterministic = word69 & (1 << 14) != 0;
+    // 只有确定性 TRIM 的读零位才有意义
+    let zeroes_after_trim = deterministic && word69 & (1 << 5) != 0;
+
+    let word105 = read_ata_word(raw, 105);
+    let max_dsm_blocks = (word105 != 0 && word105 != 0xFFFF).then_some(word105);
+
+    Some(TrimSupport {
+        deterministic,
+        zeroes_after_trim,
+        max_dsm_blocks,
     })
 }
 
+/// 全球唯一名称 (64 位)
+///
+/// 需要字 84 或字 87 bit 8 置位 (且该字 bit 15:14 = 01b),
+/// 字 108 的 NAA (bit 15:12) 只接受 5 (IEEE 注册格式)
+fn world_wide_name(raw: &[u8; 512]) -> Option<u64> {
+    let supported = [84, 87].into_iter().any(|index| {
+        let word = read_ata_word(raw, index);
+        word & 0xC000 == 0x4000 && word & (1 << 8) != 0
+    });
+    if !supported {
+        return None;
+    }
+
+    let wwn = (108..112).fold(0u64, |wwn, index| {
+        (wwn << 16) | u64::from(read_ata_word(raw, index))
+    });
+    (wwn >> 60 == 5).then_some(wwn)
+}
+
+/// 字 106 是否有效 (bit 15:14 = 01b)
+fn word106(raw: &[u8; 512]) -> Option<u16> {
+    let word = read_ata_word(raw, 106);
+    (word & 0xC000 == 0x4000).then_some(word)
+}
+
+/// 逻辑扇区大小 (字节)
+///
+/// 字 106 bit 12 置位时由字 117-118 给出 (以字为单位),否则为 512
+pub(crate) fn logical_sector_size(raw: &[u8; 512]) -> u32 {
+    if let Some(word) = word106(raw) {
+        if word & (1 << 12) != 0 {
+            let words =
+                u32::from(read_ata_word(raw, 117)) | (u32::from(read_ata_word(raw, 118)) << 16);
+            if words > 0 {
+                return words.saturating_mul(2);
+            }
+        }
+    }
+    512
+}
+
+/// 物理扇区大小 (字节)
+///
+/// 字 106 bit 13 置位时每个物理扇区包含 2^(bit 3:0) 个逻辑扇区
+fn physical_sector_size(raw: &[u8; 512], logical_sector_size: u32) -> u32 {
+    match word106(raw) {
+        Some(word) if word & (1 << 13) != 0 => {
+            logical_sector_size.saturating_mul(1 << (word & 0x000F))
+        }
+        _ => logical_sector_size,
+    }
+}
+
+/// 逻辑扇区对齐偏移
+///
+/// 字 209 bit 15:14 = 01b 时 bit 13:0 有效
+fn sector_alignment_offset(raw: &[u8; 512]) -> u16 {
+    let word = read_ata_word(raw, 209);
+    if word & 0xC000 == 0x4000 {
+        word & 0x3FFF
+    } else {
+        0
+    }
+}
+
+/// 解析安全擦除时间字
+///
+/// bit 15 置位时为扩展格式 (bit 14:0),否则为 bit 7:0,数值乘以 2 为分钟数,
+/// 0 表示未报告
+fn erase_time_minutes(word: u16) -> Option<u16> {
+    let value = if word & 0x8000 != 0 {
+        word & 0x7FFF
+    } else {
+        word & 0x00FF
+    };
+
+    (value != 0).then_some(value * 2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,4 +316,397 @@ mod tests {
         let parsed = result.unwrap();
         assert!(!parsed.serial.is_empty());
     }
+
+    #[test]
+    fn test_parse_security() {
+        let mut data = [0u8; 512];
+        // 字 128: 支持、启用、冻结
+        data[256] = 0x0B;
+        // 字 89: 60 (120 分钟)
+        data[178] = 60;
+        // 字 90: 扩展格式 300 (600 分钟)
+        data[180..182].copy_from_slice(&(0x8000u16 | 300).to_le_bytes());
+
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.security_state(), Some(SecurityState::EnabledFrozen));
+
+        let security = parsed.security.unwrap();
+        assert_eq!(security.erase_time_minutes, Some(120));
+        assert_eq!(security.state(), SecurityState::EnabledFrozen);
+        assert!(security.enabled && security.frozen && !security.locked);
+        assert!(!security.count_expired);
+        assert!(!security.enhanced_erase_supported);
+        assert_eq!(security.enhanced_erase_time_minutes, Some(600));
+
+        // 支持、启用、锁定、次数用尽、支持增强擦除
+        data[256] = 0x37;
+        let security = parse_identify_data(&data).unwrap().security.unwrap();
+        assert_eq!(security.state(), SecurityState::Locked);
+        assert!(security.count_expired);
+        assert!(security.enhanced_erase_supported);
+
+        let parsed = parse_identify_data(&[0u8; 512]).unwrap();
+        assert_eq!(parsed.security_state(), None);
+        assert_eq!(parsed.security, None);
+    }
+
+    #[test]
+    fn test_parse_sector_sizes() {
+        let parsed = parse_identify_data(&[0u8; 512]).unwrap();
+        assert_eq!(parsed.logical_sector_size, 512);
+        assert_eq!(parsed.physical_sector_size, 512);
+        assert_eq!(parsed.sector_alignment_offset, 0);
+
+        // 512e: 逻辑 512, 物理 4096 (2^3), 对齐偏移 1
+        let mut data = [0u8; 512];
+        data[212..214].copy_from_slice(&(0x4000u16 | (1 << 13) | 3).to_le_bytes());
+        data[418..420].copy_from_slice(&(0x4000u16 | 1).to_le_bytes());
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.logical_sector_size, 512);
+        assert_eq!(parsed.physical_sector_size, 4096);
+        assert_eq!(parsed.sector_alignment_offset, 1);
+
+        // 4Kn: 逻辑 4096 (2048 字)
+        let mut data = [0u8; 512];
+        data[212..214].copy_from_slice(&(0x4000u16 | (1 << 12)).to_le_bytes());
+        data[234..236].copy_from_slice(&2048u16.to_le_bytes());
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.logical_sector_size, 4096);
+        assert_eq!(parsed.physical_sector_size, 4096);
+
+        // 字 106 无效时忽略
+        let mut data = [0u8; 512];
+        data[212..214].copy_from_slice(&(0xC000u16 | (1 << 13) | 3).to_le_bytes());
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.physical_sector_size, 512);
+    }
+
+    #[test]
+    fn test_parse_wwn() {
+        let mut data = [0u8; 512];
+        for (i, word) in [0x5000u16, 0xc500, 0xa1b2, 0xc3d4].into_iter().enumerate() {
+            data[216 + i * 2..218 + i * 2].copy_from_slice(&word.to_le_bytes());
+        }
+
+        // 未声明支持 WWN
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.wwn, None);
+
+        // 字 87: bit 14 + bit 8
+        data[174..176].copy_from_slice(&0x4100u16.to_le_bytes());
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.wwn, Some(0x5000c500a1b2c3d4));
+        assert_eq!(parsed.wwn_string().as_deref(), Some("naa.5000c500a1b2c3d4"));
+
+        // NAA 不是 5
+        data[217] = 0x60;
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.wwn, None);
+    }
+
+    /// 设置 IDENTIFY 字
+    fn set_word(data: &mut [u8; 512], index: usize, value: u16) {
+        data[index * 2..index * 2 + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    #[test]
+    fn test_parse_apm() {
+        let mut data = [0u8; 512];
+        set_word(&mut data, 91, 0x0080);
+        assert_eq!(parse_identify_data(&data).unwrap().apm, None);
+
+        // 支持但未启用
+        set_word(&mut data, 83, 0x4008);
+        let apm = parse_identify_data(&data).unwrap().apm.unwrap();
+        assert!(!apm.enabled);
+        assert_eq!(apm.level, None);
+
+        // 已启用, 级别 0x80
+        set_word(&mut data, 86, 0x0008);
+        let apm = parse_identify_data(&data).unwrap().apm.unwrap();
+        assert!(apm.enabled);
+        assert_eq!(apm.level, Some(0x80));
+        assert!(!apm.is_aggressive());
+
+        set_word(&mut data, 91, 0x0040);
+        let apm = parse_identify_data(&data).unwrap().apm.unwrap();
+        assert!(apm.is_aggressive());
+    }
+
+    #[test]
+    fn test_parse_write_cache_and_lookahead() {
+        let mut data = [0u8; 512];
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.write_cache, FeatureState::Unsupported);
+        assert_eq!(parsed.read_lookahead, FeatureState::Unsupported);
+
+        // 两者都支持,只启用写缓存
+        set_word(&mut data, 82, 0x0061);
+        set_word(&mut data, 85, 0x0021);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.write_cache, FeatureState::Enabled);
+        assert_eq!(parsed.read_lookahead, FeatureState::Disabled);
+        assert!(parsed.read_lookahead.is_supported());
+
+        // 启用字无效时只能确认支持
+        set_word(&mut data, 85, 0xFFFF);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.write_cache, FeatureState::Disabled);
+
+        // 支持字无效
+        set_word(&mut data, 82, 0xFFFF);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.write_cache, FeatureState::Unsupported);
+        assert!(!feature_flags(&data).smart.is_supported());
+    }
+
+    #[test]
+    fn test_parse_queue_depth() {
+        let mut data = [0u8; 512];
+        set_word(&mut data, 75, 0x001F);
+        // PATA 设备
+        assert_eq!(parse_identify_data(&data).unwrap().queue_depth, None);
+
+        // SATA 但不支持 NCQ
+        set_word(&mut data, 76, 0x0006);
+        assert_eq!(parse_identify_data(&data).unwrap().queue_depth, None);
+
+        set_word(&mut data, 76, 0x0106);
+        assert_eq!(parse_identify_data(&data).unwrap().queue_depth, Some(32));
+
+        set_word(&mut data, 75, 0xFFFF);
+        assert_eq!(parse_identify_data(&data).unwrap().queue_depth, None);
+    }
+
+    #[test]
+    fn test_parse_sanitize() {
+        let mut data = [0u8; 512];
+        // 只有多扇区设置 (bit 8 有效, 16 扇区)
+        set_word(&mut data, 59, 0x0110);
+        assert_eq!(parse_identify_data(&data).unwrap().sanitize, None);
+
+        set_word(&mut data, 59, 0xFFFF);
+        assert_eq!(parse_identify_data(&data).unwrap().sanitize, None);
+
+        // 支持 SANITIZE 的 SSD: 加密擦除和块擦除, ANTIFREEZE, 多扇区 16
+        set_word(&mut data, 59, 0xBD10);
+        let sanitize = parse_identify_data(&data).unwrap().sanitize.unwrap();
+        assert!(sanitize.crypto_scramble);
+        assert!(sanitize.block_erase);
+        assert!(!sanitize.overwrite);
+        assert!(sanitize.antifreeze_lock);
+        assert!(sanitize.commands_allowed);
+
+        // 只支持覆写的机械硬盘
+        set_word(&mut data, 59, 0x5000);
+        let sanitize = parse_identify_data(&data).unwrap().sanitize.unwrap();
+        assert!(sanitize.overwrite);
+        assert!(!sanitize.crypto_scramble);
+        assert!(!sanitize.block_erase);
+        assert!(!sanitize.antifreeze_lock);
+    }
+
+    #[test]
+    fn test_parse_zoned() {
+        let mut data = [0u8; 512];
+        let zoned = parse_identify_data(&data).unwrap().zoned;
+        assert_eq!(zoned, ZonedCapability::NotZoned);
+        assert!(!zoned.is_smr());
+
+        // 字 69 的其他位 (确定性 TRIM) 不影响结果
+        set_word(&mut data, 69, 0x4001);
+        let zoned = parse_identify_data(&data).unwrap().zoned;
+        assert_eq!(zoned, ZonedCapability::HostAware);
+        assert!(zoned.is_smr());
+
+        set_word(&mut data, 69, 0x0002);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().zoned,
+            ZonedCapability::DriveManagedHint
+        );
+
+        set_word(&mut data, 69, 0x0003);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().zoned,
+            ZonedCapability::NotZoned
+        );
+
+        assert_eq!(
+            ZonedCapability::from_linux_zoned("host-managed\n"),
+            Some(ZonedCapability::HostManaged)
+        );
+        assert_eq!(ZonedCapability::from_linux_zoned("unknown"), None);
+    }
+
+    #[test]
+    fn test_parse_form_factor() {
+        let mut data = [0u8; 512];
+        assert_eq!(parse_identify_data(&data).unwrap().form_factor, None);
+
+        set_word(&mut data, 168, 0x0002);
+        let form_factor = parse_identify_data(&data).unwrap().form_factor.unwrap();
+        assert_eq!(form_factor, FormFactor::Inch3_5);
+        assert_eq!(form_factor.as_str(), "3.5 inches");
+
+        set_word(&mut data, 168, 0x0005);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().form_factor,
+            Some(FormFactor::Smaller)
+        );
+
+        set_word(&mut data, 168, 0x0009);
+        let form_factor = parse_identify_data(&data).unwrap().form_factor.unwrap();
+        assert_eq!(form_factor, FormFactor::Unknown(9));
+        assert_eq!(form_factor.as_str(), "未知");
+
+        set_word(&mut data, 168, 0xFFFF);
+        assert_eq!(parse_identify_data(&data).unwrap().form_factor, None);
+    }
+
+    #[test]
+    fn test_parse_transport() {
+        let mut data = [0u8; 512];
+        assert_eq!(
+            parse_identify_data(&data).unwrap().transport,
+            Transport::Unknown
+        );
+
+        // SATA, 支持 1.0a 到 3.2
+        set_word(&mut data, 222, 0x10FE);
+        let transport = parse_identify_data(&data).unwrap().transport;
+        assert_eq!(
+            transport,
+            Transport::Serial {
+                revision: Some("SATA 3.2")
+            }
+        );
+        assert_eq!(transport.as_str(), "SATA 3.2");
+
+        set_word(&mut data, 222, 0x1000);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().transport,
+            Transport::Serial { revision: None }
+        );
+
+        set_word(&mut data, 222, 0x0001);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().transport,
+            Transport::Parallel
+        );
+
+        set_word(&mut data, 222, 0xFFFF);
+        assert_eq!(
+            parse_identify_data(&data).unwrap().transport,
+            Transport::Unknown
+        );
+    }
+
+    #[test]
+    fn test_parse_additional_product_id() {
+        let mut data = [0u8; 512];
+        assert_eq!(
+            parse_identify_data(&data).unwrap().additional_product_id,
+            None
+        );
+
+        data[340..348].fill(b' ');
+        assert_eq!(
+            parse_identify_data(&data).unwrap().additional_product_id,
+            None
+        );
+
+        // ATA 字符串在每个字内交换字节
+        data[340..348].copy_from_slice(b"EWSD0F  ");
+        assert_eq!(
+            parse_identify_data(&data)
+                .unwrap()
+                .additional_product_id
+                .as_deref(),
+            Some("WEDSF0")
+        );
+    }
+
+    #[test]
+    fn test_parse_trim() {
+        // 不支持 TRIM 时忽略字 69
+        let mut data = [0u8; 512];
+        set_word(&mut data, 69, 0x4020);
+        assert_eq!(parse_identify_data(&data).unwrap().trim, None);
+
+        set_word(&mut data, 169, 0xFFFF);
+        assert_eq!(parse_identify_data(&data).unwrap().trim, None);
+
+        // 确定性 TRIM, 读零, 最多 8 块
+        set_word(&mut data, 169, 0x0001);
+        set_word(&mut data, 105, 8);
+        let trim = parse_identify_data(&data).unwrap().trim.unwrap();
+        assert!(trim.deterministic);
+        assert!(trim.zeroes_after_trim);
+        assert_eq!(trim.max_dsm_blocks, Some(8));
+
+        // 非确定性 TRIM 时读零位无效
+        set_word(&mut data, 69, 0x0020);
+        set_word(&mut data, 105, 0);
+        let trim = parse_identify_data(&data).unwrap().trim.unwrap();
+        assert!(!trim.deterministic);
+        assert!(!trim.zeroes_after_trim);
+        assert_eq!(trim.max_dsm_blocks, None);
+    }
+
+    #[test]
+    fn test_parse_acoustic_level() {
+        let mut data = [0u8; 512];
+        // 字 94: 厂商推荐 0xFE, 当前 0x80
+        set_word(&mut data, 94, 0xFE80);
+        // 字 83 bit 9: 支持
+        set_word(&mut data, 83, 0x4200);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert!(parsed.aam.is_some());
+        assert_eq!(parsed.acoustic_level(), None);
+
+        // 字 86 bit 9: 已启用
+        set_word(&mut data, 86, 0x0200);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.acoustic_level(), Some(AcousticLevel::Quiet));
+
+        // 字 83 无效时两者一致
+        set_word(&mut data, 83, 0x0200);
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.aam, None);
+        assert_eq!(parsed.acoustic_level(), None);
+    }
+
+    #[test]
+    fn test_parse_aam() {
+        let mut data = [0u8; 512];
+        set_word(&mut data, 94, 0xFE80);
+        assert_eq!(parse_identify_data(&data).unwrap().aam, None);
+
+        // 支持但字 94 为 0
+        set_word(&mut data, 83, 0x4200);
+        set_word(&mut data, 94, 0x0000);
+        assert_eq!(parse_identify_data(&data).unwrap().aam, None);
+
+        set_word(&mut data, 94, 0xFE80);
+        set_word(&mut data, 86, 0x0200);
+        let aam = parse_identify_data(&data).unwrap().aam.unwrap();
+        assert!(aam.enabled);
+        assert_eq!(aam.current, Some(AcousticLevel::Quiet));
+        assert_eq!(aam.recommended, Some(AcousticLevel::MaxPerformance));
+    }
+
+    #[test]
+    fn test_parse_rotation_rate() {
+        let parsed = parse_identify_data(&[0u8; 512]).unwrap();
+        assert_eq!(parsed.rotation_rate, None);
+
+        let mut data = [0u8; 512];
+        data[434] = 0x01;
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.rotation_rate, Some(RotationRate::Ssd));
+
+        data[434..436].copy_from_slice(&5400u16.to_le_bytes());
+        let parsed = parse_identify_data(&data).unwrap();
+        assert_eq!(parsed.rotation_rate, Some(RotationRate::Rpm(5400)));
+    }
 }
@@ -43,20 +43,54 @@
 //! ```
 
 // 模块声明
+#[cfg(feature = "async")]
+mod async_disk;
+#[cfg(feature = "capi")]
+pub mod capi;
 mod disk;
 mod error;
 mod ffi;
 mod identify;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod nvme;
+mod report;
+mod scan;
 mod smart;
+mod snapshot;
 mod types;
 mod utils;
 
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+
 // 公共导出
-pub use disk::{Disk, IdentifyData, SmartData, SmartInfo, SmartThresholds};
+#[cfg(feature = "async")]
+pub use async_disk::AsyncDisk;
+pub use disk::{
+    Disk, DiskOpenOptions, IdentifyData, SmartAttributeIter, SmartData, SmartInfo, SmartThresholds,
+};
 pub use error::{Error, Result};
-pub use smart::{identify_from_blob, read_blob_from_file, smart_info_from_blob, BlobData};
+pub use nvme::NvmeHealthLog;
+pub use report::HealthReport;
+pub use scan::{list_disks, scan_all, scan_candidates, DiskCandidate, ScanOptions};
+pub use smart::{
+    attribute_info, detect_vendor, estimate_failure_risk, failure_risk_score, health_score,
+    identify_from_blob, read_blob_from_file, smart_info_from_blob, AttributeId, BlobData,
+};
+pub use snapshot::DiskSnapshot;
+pub use types::units;
 pub use types::{
-    AttributeUnit, DiskStatistics, DiskType, Duration, IdentifyParsedData,
-    OfflineDataCollectionStatus, SelfTestExecutionStatus, SmartAttributeParsedData, SmartOverall,
-    SmartParsedData, SmartSelfTest, Temperature,
+    AamInfo, AcousticLevel, ApmInfo, AtaVersion, AttributeContext, AttributeFailure,
+    AttributeSummary, AttributeUnit, Capacity, DiskStatistics, DiskType, DiskVendor, Duration,
+    FailureKind, FailureRisk, FeatureFlags, FeatureState, FormFactor, IdentifyParsedData,
+    OfflineDataCollectionCapability, OfflineDataCollectionStatus, OfflineTestType,
+    OverallAssessment, Percent, PhyEventCounter, PowerMode, PowerOnTimeEncoding, PrettyValue,
+    RotationRate, SanitizeSupport, SataGen, SataInfo, SecurityState, SecurityStatus,
+    SelfTestExecutionStatus, SmartAttributeParsedData, SmartErrorCommand, SmartErrorLogEntry,
+    SmartLogDirectory, SmartOverall, SmartParsedData, SmartSelfTest, StandbyTimer, Temperature,
+    TemperatureHistory, TemperatureRange, Transport, TrimSupport, VendorAttributeTable,
+    WakeupPolicy, WearStatistics, ZonedCapability,
 };
+pub use utils::verify_ata_checksum;
@@ -0,0 +1,192 @@
+//! Prometheus 指标导出
+//!
+//! 将磁盘快照渲染为 Prometheus 文本格式,不包含 HTTP 服务
+
+use crate::snapshot::{raw_value, DiskSnapshot};
+use std::fmt::Write;
+
+impl DiskSnapshot {
+    /// 渲染为 Prometheus 文本格式
+    ///
+    /// `labels` 附加到每个样本上,通常用于区分磁盘,例如 `[("disk", "sda")]`。
+    /// 输出以下指标 (快照中没有的数据不输出对应样本):
+    ///
+    /// * `smart_attribute_value` - 属性当前值,附加 `id` 和 `name` 标签
+    /// * `smart_attribute_worst` - 属性最差值
+    /// * `smart_attribute_threshold` - 属性阈值
+    /// * `smart_attribute_raw` - 属性 48 位原始值
+    /// * `smart_temperature_celsius` - 当前温度
+    /// * `smart_power_on_seconds` - 累计开机时间
+    /// * `smart_status_passed` - SMART 自评估是否通过
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let text = disk.snapshot()?.to_prometheus(&[("disk", "sda")]);
+    /// print!("{}", text);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn to_prometheus(&self, labels: &[(&str, &str)]) -> String {
+        let mut out = String::new();
+
+        type Field = fn(&crate::types::SmartAttributeParsedData) -> f64;
+        let attribute_metrics: [(&str, &str, Field); 4] = [
+            ("smart_attribute_value", "SMART 属性当前值", |attr| {
+                attr.current_value as f64
+            }),
+            ("smart_attribute_worst", "SMART 属性最差值", |attr| {
+                attr.worst_value as f64
+            }),
+            ("smart_attribute_threshold", "SMART 属性阈值", |attr| {
+                attr.threshold as f64
+            }),
+            ("smart_attribute_raw", "SMART 属性原始值", |attr| {
+                raw_value(&attr.raw) as f64
+            }),
+        ];
+
+        if !self.attributes.is_empty() {
+            for (name, help, field) in attribute_metrics {
+                write_header(&mut out, name, help);
+                for attr in &self.attributes {
+                    let id = attr.id.to_string();
+                    let mut attr_labels = labels.to_vec();
+                    attr_labels.push(("id", &id));
+                    attr_labels.push(("name", attr.name));
+                    write_sample(&mut out, name, &attr_labels, field(attr));
+                }
+            }
+        }
+
+        if let Some(temperature) = self.temperature() {
+            let name = "smart_temperature_celsius";
+            write_header(&mut out, name, "当前温度 (摄氏度)");
+            write_sample(&mut out, name, labels, temperature.celsius());
+        }
+
+        if let Some(power_on) = self.power_on_duration() {
+            let name = "smart_power_on_seconds";
+            write_header(&mut out, name, "累计开机时间 (秒)");
+            write_sample(&mut out, name, labels, power_on.as_secs() as f64);
+        }
+
+        if let Some(status) = self.status {
+            let name = "smart_status_passed";
+            write_header(&mut out, name, "SMART 自评估是否通过");
+            write_sample(&mut out, name, labels, if status { 1.0 } else { 0.0 });
+        }
+
+        out
+    }
+}
+
+/// 写入 HELP 和 TYPE 行
+fn write_header(out: &mut String, name: &str, help: &str) {
+    let help = help.replace('\\', "\\\\").replace('\n', "\\n");
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+}
+
+/// 写入一个样本
+fn write_sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: f64) {
+    out.push_str(name);
+    if !labels.is_empty() {
+        let labels: Vec<String> = labels
+            .iter()
+            .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+            .collect();
+        let _ = write!(out, "{{{}}}", labels.join(","));
+    }
+    let _ = writeln!(out, " {}", format_value(value));
+}
+
+/// 转义标签值中的反斜杠、双引号和换行
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 按 Prometheus 的写法格式化样本值
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::smart::blob::BlobData;
+
+    #[test]
+    fn test_escape_label() {
+        assert_eq!(escape_label("sda"), "sda");
+        assert_eq!(escape_label(r#"a"b\c"#), r#"a\"b\\c"#);
+        assert_eq!(escape_label("a\nb"), "a\\nb");
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(f64::NAN), "NaN");
+        assert_eq!(format_value(f64::INFINITY), "+Inf");
+        assert_eq!(format_value(f64::NEG_INFINITY), "-Inf");
+        assert_eq!(format_value(35.0), "35");
+        assert_eq!(format_value(0.5), "0.5");
+    }
+
+    #[test]
+    fn test_write_sample() {
+        let mut out = String::new();
+        write_sample(&mut out, "m", &[], f64::NAN);
+        write_sample(&mut out, "m", &[("disk", "s\"da"), ("id", "5")], 1.0);
+        assert_eq!(out, "m NaN\nm{disk=\"s\\\"da\",id=\"5\"} 1\n");
+    }
+
+    #[test]
+    fn test_to_prometheus() {
+        let mut smart_data = [0u8; 512];
+        // 属性 194: 35°C
+        smart_data[2..7].copy_from_slice(&[194, 0x22, 0, 65, 55]);
+        smart_data[7] = 35;
+        let sum = smart_data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        smart_data[511] = sum.wrapping_neg();
+
+        let data = BlobData {
+            identify: Some([0u8; 512]),
+            smart_status: Some(true),
+            smart_data: Some(smart_data),
+            smart_thresholds: None,
+        };
+        let text = DiskSnapshot::from_blob_data(&data)
+            .unwrap()
+            .to_prometheus(&[("disk", "sda")]);
+
+        assert!(text.contains("# TYPE smart_attribute_value gauge\n"));
+        assert!(text.contains(
+            "smart_attribute_value{disk=\"sda\",id=\"194\",name=\"temperature-celsius-2\"} 65\n"
+        ));
+        assert!(text.contains(
+            "smart_attribute_raw{disk=\"sda\",id=\"194\",name=\"temperature-celsius-2\"} 35\n"
+        ));
+        assert!(text.contains("smart_temperature_celsius{disk=\"sda\"} 35\n"));
+        assert!(text.contains("smart_status_passed{disk=\"sda\"} 1\n"));
+        assert!(!text.contains("smart_power_on_seconds"));
+    }
+}
@@ -0,0 +1,154 @@
+//! NVMe SMART / 健康信息
+//!
+//! 读取 NVMe 设备的 SMART / Health Information 日志 (日志页 0x02)
+
+use crate::disk::Disk;
+use crate::error::Result;
+use crate::types::Temperature;
+
+/// SMART / Health Information 日志页标识
+const LOG_SMART_HEALTH: u8 = 0x02;
+
+/// NVMe SMART / 健康信息日志
+///
+/// 参见 NVMe Base Specification 5.16.1.3 节,所有计数器均为小端序
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NvmeHealthLog {
+    /// 严重警告位图 (bit 0: 备用空间不足, bit 1: 温度, bit 2: 可靠性下降,
+    /// bit 3: 只读, bit 4: 易失性存储备份失败)
+    pub critical_warning: u8,
+    /// 综合温度 (开尔文)
+    pub temperature_kelvin: u16,
+    /// 可用备用空间 (百分比)
+    pub available_spare_percent: u8,
+    /// 可用备用空间阈值 (百分比)
+    pub available_spare_threshold_percent: u8,
+    /// 寿命使用百分比,可能超过 100
+    pub percentage_used: u8,
+    /// 读取的数据单元数 (每单元 512000 字节)
+    pub data_units_read: u128,
+    /// 写入的数据单元数 (每单元 512000 字节)
+    pub data_units_written: u128,
+    /// 主机读命令数
+    pub host_read_commands: u128,
+    /// 主机写命令数
+    pub host_write_commands: u128,
+    /// 通电小时数
+    pub power_on_hours: u128,
+    /// 电源循环次数
+    pub power_cycles: u128,
+    /// 非安全关机次数
+    pub unsafe_shutdowns: u128,
+    /// 介质和数据完整性错误数
+    pub media_errors: u128,
+}
+
+impl NvmeHealthLog {
+    /// 从 512 字节的日志页解析
+    pub fn from_bytes(raw: &[u8; 512]) -> Self {
+        let counter = |offset: usize| {
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(&raw[offset..offset + 16]);
+            u128::from_le_bytes(bytes)
+        };
+
+        Self {
+            critical_warning: raw[0],
+            temperature_kelvin: u16::from_le_bytes([raw[1], raw[2]]),
+            available_spare_percent: raw[3],
+            available_spare_threshold_percent: raw[4],
+            percentage_used: raw[5],
+            data_units_read: counter(32),
+            data_units_written: counter(48),
+            host_read_commands: counter(64),
+            host_write_commands: counter(80),
+            power_cycles: counter(112),
+            power_on_hours: counter(128),
+            unsafe_shutdowns: counter(144),
+            media_errors: counter(160),
+        }
+    }
+
+    /// 综合温度
+    pub fn temperature(&self) -> Temperature {
+        Temperature::from_celsius(f64::from(self.temperature_kelvin) - 273.15)
+    }
+
+    /// 是否存在严重警告
+    pub fn has_critical_warning(&self) -> bool {
+        self.critical_warning != 0
+    }
+}
+
+impl Disk {
+    /// 读取 NVMe SMART / 健康信息日志
+    ///
+    /// 通过 `NVME_IOCTL_ADMIN_CMD` 发送 GET LOG PAGE (日志页 0x02,所有命名空间),
+    /// 需要以 NVMe 控制器或命名空间设备打开,例如 `/dev/nvme0n1`。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不是 NVMe 设备
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/nvme0n1")?;
+    /// let health = disk.nvme_smart_health_log()?;
+    /// println!("温度: {}", health.temperature());
+    /// println!("寿命使用: {}%", health.percentage_used);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn nvme_smart_health_log(&self) -> Result<NvmeHealthLog> {
+        let mut data = [0u8; 512];
+        self.nvme_get_log_page(LOG_SMART_HEALTH, &mut data)?;
+        Ok(NvmeHealthLog::from_bytes(&data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_health_log_from_bytes() {
+        let mut raw = [0u8; 512];
+        raw[0] = 0x04;
+        raw[1..3].copy_from_slice(&308u16.to_le_bytes());
+        raw[3] = 100;
+        raw[4] = 10;
+        raw[5] = 3;
+        raw[32..48].copy_from_slice(&123456u128.to_le_bytes());
+        raw[48..64].copy_from_slice(&(u128::from(u64::MAX) + 1).to_le_bytes());
+        raw[112..128].copy_from_slice(&42u128.to_le_bytes());
+        raw[128..144].copy_from_slice(&8760u128.to_le_bytes());
+        raw[160..176].copy_from_slice(&7u128.to_le_bytes());
+
+        let log = NvmeHealthLog::from_bytes(&raw);
+        assert!(log.has_critical_warning());
+        assert_eq!(log.temperature_kelvin, 308);
+        assert!((log.temperature().celsius() - 34.85).abs() < 1e-9);
+        assert_eq!(log.available_spare_percent, 100);
+        assert_eq!(log.available_spare_threshold_percent, 10);
+        assert_eq!(log.percentage_used, 3);
+        assert_eq!(log.data_units_read, 123456);
+        assert_eq!(log.data_units_written, u128::from(u64::MAX) + 1);
+        assert_eq!(log.power_cycles, 42);
+        assert_eq!(log.power_on_hours, 8760);
+        assert_eq!(log.media_errors, 7);
+        assert_eq!(log.unsafe_shutdowns, 0);
+    }
+
+    #[test]
+    fn test_health_log_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.nvme_smart_health_log(),
+            Err(Error::NotSupported(_))
+        ));
+    }
+}
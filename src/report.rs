@@ -0,0 +1,156 @@
+//! 健康报告
+//!
+//! 将各项健康指标汇总到一个结构中
+
+use crate::disk::Disk;
+use crate::error::Result;
+use crate::types::{Duration, SmartOverall, Temperature};
+use std::fmt;
+
+/// 磁盘健康报告
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HealthReport {
+    /// 整体健康状态
+    pub overall: SmartOverall,
+    /// 坏扇区数
+    pub bad_sectors: Option<u64>,
+    /// 温度
+    pub temperature: Option<Temperature>,
+    /// 累计开机时间
+    pub power_on: Option<Duration>,
+    /// 电源循环次数
+    pub power_cycles: Option<u64>,
+    /// 命令超时次数 (属性 188)
+    pub command_timeouts: Option<u64>,
+    /// SMART 自评估结果
+    pub smart_status: bool,
+    /// 警告信息,每个 `warn == true` 的属性一条,UDMA CRC 错误数非零时另加一条
+    pub warnings: Vec<String>,
+}
+
+impl fmt::Display for HealthReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "整体状态: {}", self.overall.as_str())?;
+        writeln!(
+            f,
+            "SMART状态: {}",
+            if self.smart_status {
+                "良好"
+            } else {
+                "异常"
+            }
+        )?;
+
+        if let Some(sectors) = self.bad_sectors {
+            writeln!(f, "坏扇区: {} 扇区", sectors)?;
+        }
+        if let Some(temp) = self.temperature {
+            writeln!(f, "当前温度: {}", temp)?;
+        }
+        if let Some(duration) = self.power_on {
+            writeln!(f, "累计开机时间: {}", duration)?;
+        }
+        if let Some(cycles) = self.power_cycles {
+            writeln!(f, "电源循环次数: {}", cycles)?;
+        }
+        if let Some(timeouts) = self.command_timeouts {
+            writeln!(f, "命令超时: {} 次", timeouts)?;
+        }
+
+        if self.warnings.is_empty() {
+            write!(f, "警告: 无")
+        } else {
+            write!(f, "警告:")?;
+            for warning in &self.warnings {
+                write!(f, "\n  - {}", warning)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Disk {
+    /// 生成健康报告
+    ///
+    /// 从设备重新读取 SMART 数据和自评估结果,一次性填充所有字段。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let report = disk.health_report()?;
+    /// println!("{}", report);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn health_report(&self) -> Result<HealthReport> {
+        let (smart, smart_status) = self.read_smart_with_status()?;
+        let stats = smart.statistics();
+
+        let mut warnings: Vec<String> = smart
+            .parse_attributes()?
+            .into_iter()
+            .filter(|attr| attr.warn)
+            .map(|attr| {
+                format!(
+                    "属性 {} ({}) 异常: 当前值 {}, 最差值 {}, 阈值 {}",
+                    attr.id, attr.name, attr.current_value, attr.worst_value, attr.threshold
+                )
+            })
+            .collect();
+
+        if let Some(errors) = smart.udma_crc_errors().filter(|&errors| errors > 0) {
+            warnings.push(format!(
+                "UDMA CRC 错误 {} 次,数据线或控制器可能存在问题",
+                errors
+            ));
+        }
+
+        Ok(HealthReport {
+            overall: smart.overall_with_crc_threshold(smart_status, self.crc_error_threshold()),
+            bad_sectors: stats.bad_sectors,
+            temperature: stats.temperature,
+            power_on: stats.power_on_duration,
+            power_cycles: stats.power_cycle_count,
+            command_timeouts: smart.command_timeouts(),
+            smart_status,
+            warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let report = HealthReport {
+            overall: SmartOverall::BadSector,
+            bad_sectors: Some(3),
+            temperature: Some(Temperature::from_celsius(35.0)),
+            power_on: None,
+            power_cycles: Some(120),
+            command_timeouts: Some(2),
+            smart_status: true,
+            warnings: vec!["属性 5 异常".to_string()],
+        };
+
+        let output = report.to_string();
+        assert!(output.contains("整体状态: 存在坏扇区"));
+        assert!(output.contains("坏扇区: 3 扇区"));
+        assert!(output.contains("35.0°C"));
+        assert!(output.contains("电源循环次数: 120"));
+        assert!(output.contains("命令超时: 2 次"));
+        assert!(!output.contains("累计开机时间"));
+        assert!(output.ends_with("  - 属性 5 异常"));
+    }
+
+    #[test]
+    fn test_health_report_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.health_report().is_err());
+    }
+}
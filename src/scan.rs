@@ -0,0 +1,342 @@
+//! 磁盘枚举与并行扫描
+
+use crate::disk::{Disk, DiskOpenOptions};
+use crate::error::{Error, Result};
+use crate::snapshot::DiskSnapshot;
+use crate::types::WakeupPolicy;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// 默认并行度
+const DEFAULT_PARALLELISM: usize = 4;
+
+/// 候选磁盘设备
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiskCandidate {
+    /// 内核设备名,例如 `sda`
+    pub name: String,
+    /// 设备路径,例如 `/dev/sda`
+    pub path: PathBuf,
+    /// 设备大小 (字节),来自 sysfs
+    pub size: u64,
+}
+
+/// 列出系统中的磁盘设备
+///
+/// 遍历 `/sys/block`,跳过没有物理设备的虚拟块设备 (loop、ram、dm 等)
+/// 以及光驱和软驱。
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::list_disks;
+///
+/// for candidate in list_disks()? {
+///     println!("{}: {} 字节", candidate.path.display(), candidate.size);
+/// }
+/// # Ok::<(), libatasmart::Error>(())
+/// ```
+pub fn list_disks() -> Result<Vec<DiskCandidate>> {
+    list_disks_in(Path::new("/sys/block"), Path::new("/dev"))
+}
+
+/// 从指定的 sysfs 目录列出磁盘设备
+pub(crate) fn list_disks_in(sys_block: &Path, dev: &Path) -> Result<Vec<DiskCandidate>> {
+    let mut candidates = Vec::new();
+
+    for entry in fs::read_dir(sys_block)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        // 虚拟块设备没有 device 链接
+        if !entry.path().join("device").exists() {
+            continue;
+        }
+        // 光驱和软驱不支持 SMART
+        if name.starts_with("sr") || name.starts_with("fd") {
+            continue;
+        }
+
+        // sysfs 中的大小以 512 字节扇区为单位
+        let size = fs::read_to_string(entry.path().join("size"))
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map_or(0, |sectors| sectors * 512);
+
+        candidates.push(DiskCandidate {
+            path: dev.join(&name),
+            name,
+            size,
+        });
+    }
+
+    candidates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(candidates)
+}
+
+/// 并行扫描选项
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::{scan_all, ScanOptions, WakeupPolicy};
+/// use std::time::Duration;
+///
+/// let results = scan_all(
+///     ScanOptions::new()
+///         .parallelism(8)
+///         .timeout(Duration::from_secs(1))
+///         .wakeup_policy(WakeupPolicy::NeverWake),
+/// )?;
+/// for (candidate, snapshot) in results {
+///     match snapshot {
+///         Ok(snapshot) => println!("{}: {:?}", candidate.name, snapshot.overall),
+///         Err(e) => println!("{}: {}", candidate.name, e),
+///     }
+/// }
+/// # Ok::<(), libatasmart::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    pub(crate) parallelism: usize,
+    pub(crate) open_options: DiskOpenOptions,
+}
+
+impl ScanOptions {
+    /// 创建默认选项
+    pub fn new() -> Self {
+        Self {
+            parallelism: DEFAULT_PARALLELISM,
+            open_options: DiskOpenOptions::new(),
+        }
+    }
+
+    /// 设置同时扫描的设备数,最小为 1
+    pub fn parallelism(&mut self, parallelism: usize) -> &mut Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// 设置单个命令的超时时间
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.open_options.timeout(timeout);
+        self
+    }
+
+    /// 设置设备唤醒策略
+    pub fn wakeup_policy(&mut self, policy: WakeupPolicy) -> &mut Self {
+        self.open_options.wakeup_policy(policy);
+        self
+    }
+
+    /// 设置打开设备时使用的选项
+    pub fn open_options(&mut self, options: DiskOpenOptions) -> &mut Self {
+        self.open_options = options;
+        self
+    }
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 并行扫描所有磁盘
+///
+/// 枚举磁盘 (参见 [`list_disks`]) 并使用固定大小的线程池采集快照。
+/// 每个设备的错误单独返回,某个设备出错或 panic 不影响其他设备。
+/// 同一块磁盘通过多条路径可见时 (WWN 相同),只保留第一个结果。
+///
+/// # 返回
+///
+/// * `Err` - 仅在枚举设备失败时返回
+pub fn scan_all(options: &ScanOptions) -> Result<Vec<(DiskCandidate, Result<DiskSnapshot>)>> {
+    Ok(dedup_by_wwn(scan_candidates(list_disks()?, options)))
+}
+
+/// 按 WWN 去重,没有 WWN 或扫描失败的结果全部保留
+fn dedup_by_wwn(
+    results: Vec<(DiskCandidate, Result<DiskSnapshot>)>,
+) -> Vec<(DiskCandidate, Result<DiskSnapshot>)> {
+    let mut seen = HashSet::new();
+    results
+        .into_iter()
+        .filter(|(_, result)| {
+            let wwn = result
+                .as_ref()
+                .ok()
+                .and_then(|snapshot| snapshot.identify.as_ref())
+                .and_then(|identify| identify.wwn);
+            wwn.is_none_or(|wwn| seen.insert(wwn))
+        })
+        .collect()
+}
+
+/// 并行扫描指定的设备
+///
+/// 结果顺序与输入顺序相同
+pub fn scan_candidates(
+    candidates: Vec<DiskCandidate>,
+    options: &ScanOptions,
+) -> Vec<(DiskCandidate, Result<DiskSnapshot>)> {
+    let workers = options.parallelism.min(candidates.len());
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<DiskSnapshot>>>> =
+        Mutex::new((0..candidates.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(candidate) = candidates.get(index) else {
+                    break;
+                };
+
+                let result = scan_one(&candidate.path, &options.open_options);
+                results.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+            });
+        }
+    });
+
+    let results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    candidates
+        .into_iter()
+        .zip(results)
+        .map(|(candidate, result)| {
+            let result = result.unwrap_or_else(|| Err(io::Error::other("设备未被扫描").into()));
+            (candidate, result)
+        })
+        .collect()
+}
+
+/// 扫描单个设备,将 panic 转换为错误
+fn scan_one(path: &Path, options: &DiskOpenOptions) -> Result<DiskSnapshot> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let disk = Disk::open_with_options(path, options)?;
+        DiskSnapshot::capture(&disk)
+    }))
+    .unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(Error::Io(io::Error::other(format!(
+            "扫描 {} 时发生 panic: {}",
+            path.display(),
+            message
+        ))))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(name: &str, path: &str) -> DiskCandidate {
+        DiskCandidate {
+            name: name.to_string(),
+            path: PathBuf::from(path),
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_list_disks_in() {
+        let root = std::env::temp_dir().join(format!("libatasmart-scan-{}", std::process::id()));
+        let sys_block = root.join("block");
+        for name in ["sdb", "sda", "loop0", "sr0"] {
+            fs::create_dir_all(sys_block.join(name)).unwrap();
+        }
+        for name in ["sda", "sdb", "sr0"] {
+            fs::create_dir_all(sys_block.join(name).join("device")).unwrap();
+        }
+        fs::write(sys_block.join("sda").join("size"), "1000\n").unwrap();
+
+        let candidates = list_disks_in(&sys_block, Path::new("/dev")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].name, "sda");
+        assert_eq!(candidates[0].path, PathBuf::from("/dev/sda"));
+        assert_eq!(candidates[0].size, 512000);
+        assert_eq!(candidates[1].name, "sdb");
+        assert_eq!(candidates[1].size, 0);
+    }
+
+    #[test]
+    fn test_parallelism_minimum() {
+        let mut options = ScanOptions::new();
+        assert_eq!(options.parallelism, DEFAULT_PARALLELISM);
+        options.parallelism(0);
+        assert_eq!(options.parallelism, 1);
+    }
+
+    #[test]
+    fn test_scan_candidates_keeps_per_device_errors() {
+        let candidates = vec![
+            candidate("missing", "/dev/libatasmart-does-not-exist"),
+            candidate("null", "/dev/null"),
+            candidate("dir", "/"),
+        ];
+
+        let results = scan_candidates(candidates, ScanOptions::new().parallelism(2));
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0.name, "missing");
+        assert_eq!(results[1].0.name, "null");
+        assert_eq!(results[2].0.name, "dir");
+        assert!(results.iter().all(|(_, result)| result.is_err()));
+    }
+
+    #[test]
+    fn test_dedup_by_wwn() {
+        let snapshot = |wwn: Option<u64>| {
+            let mut identify = crate::identify::parse::parse_identify_data(&[0u8; 512]).unwrap();
+            identify.wwn = wwn;
+            Ok(DiskSnapshot {
+                taken_at: std::time::SystemTime::now(),
+                identify: Some(identify),
+                smart: None,
+                attributes: Vec::new(),
+                status: None,
+                overall: None,
+                size: 0,
+            })
+        };
+
+        let results = vec![
+            (
+                candidate("sda", "/dev/sda"),
+                snapshot(Some(0x5000c500a1b2c3d4)),
+            ),
+            (candidate("sdb", "/dev/sdb"), snapshot(None)),
+            (
+                candidate("sdc", "/dev/sdc"),
+                snapshot(Some(0x5000c500a1b2c3d4)),
+            ),
+            (candidate("sdd", "/dev/sdd"), snapshot(None)),
+            (candidate("sde", "/dev/sde"), Err(Error::NoData)),
+        ];
+
+        let names: Vec<_> = dedup_by_wwn(results)
+            .into_iter()
+            .map(|(candidate, _)| candidate.name)
+            .collect();
+        assert_eq!(names, ["sda", "sdb", "sdd", "sde"]);
+    }
+
+    #[test]
+    fn test_scan_candidates_empty() {
+        assert!(scan_candidates(Vec::new(), &ScanOptions::new()).is_empty());
+    }
+}
@@ -1,6 +1,13 @@
 //! SMART 属性解析
 
-use crate::types::{AttributeUnit, SmartAttributeParsedData};
+use crate::disk::{Disk, SmartInfo};
+use crate::error::Result;
+use crate::smart::detect_vendor;
+use crate::types::{
+    AttributeContext, AttributeFailure, AttributeUnit, DiskVendor, Duration, FailureKind,
+    PrettyValue, SmartAttributeParsedData, Temperature,
+};
+use std::ops::ControlFlow;
 
 /// 属性信息
 #[derive(Debug, Clone, Copy)]
@@ -9,293 +16,280 @@ pub(crate) struct AttributeInfo {
     pub unit: AttributeUnit,
 }
 
-/// 属性信息表（256 个条目）
-/// 基于 smartmontools 的数据
-pub(crate) static ATTRIBUTE_INFO: [Option<AttributeInfo>; 256] = {
-    let mut arr: [Option<AttributeInfo>; 256] = [None; 256];
+/// 定义属性信息表和 [`AttributeId`]
+///
+/// 两者由同一份列表生成,新增属性时只需要修改下面的 `attribute_table!` 调用
+macro_rules! attribute_table {
+    ($($id:literal => $variant:ident, $name:literal, $unit:ident;)*) => {
+        /// 属性信息表（256 个条目）
+        /// 基于 smartmontools 的数据
+        pub(crate) static ATTRIBUTE_INFO: [Option<AttributeInfo>; 256] = {
+            let mut arr: [Option<AttributeInfo>; 256] = [None; 256];
+            $(
+                arr[$id] = Some(AttributeInfo {
+                    name: $name,
+                    unit: AttributeUnit::$unit,
+                });
+            )*
+            arr
+        };
 
-    // 1-13: 基本属性
-    arr[1] = Some(AttributeInfo {
-        name: "raw-read-error-rate",
-        unit: AttributeUnit::None,
-    });
-    arr[2] = Some(AttributeInfo {
-        name: "throughput-performance",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[3] = Some(AttributeInfo {
-        name: "spin-up-time",
-        unit: AttributeUnit::Milliseconds,
-    });
-    arr[4] = Some(AttributeInfo {
-        name: "start-stop-count",
-        unit: AttributeUnit::None,
-    });
-    arr[5] = Some(AttributeInfo {
-        name: "reallocated-sector-count",
-        unit: AttributeUnit::Sectors,
-    });
-    arr[6] = Some(AttributeInfo {
-        name: "read-channel-margin",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[7] = Some(AttributeInfo {
-        name: "seek-error-rate",
-        unit: AttributeUnit::None,
-    });
-    arr[8] = Some(AttributeInfo {
-        name: "seek-time-performance",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[9] = Some(AttributeInfo {
-        name: "power-on-hours",
-        unit: AttributeUnit::Milliseconds,
-    });
-    arr[10] = Some(AttributeInfo {
-        name: "spin-retry-count",
-        unit: AttributeUnit::None,
-    });
-    arr[11] = Some(AttributeInfo {
-        name: "calibration-retry-count",
-        unit: AttributeUnit::None,
-    });
-    arr[12] = Some(AttributeInfo {
-        name: "power-cycle-count",
-        unit: AttributeUnit::None,
-    });
-    arr[13] = Some(AttributeInfo {
-        name: "read-soft-error-rate",
-        unit: AttributeUnit::None,
-    });
+        /// SMART 属性 ID
+        ///
+        /// 属性信息表中已知属性的类型化表示,表中没有的 ID 为 `Other`。
+        /// 变体名取自 libatasmart 的属性名称,例如属性 194 (`temperature-celsius-2`)
+        /// 为 `TemperatureCelsius2`。`Other` 只用于表中没有的 ID,
+        /// 请通过 `From<u8>` 创建,不要直接构造 `Other(5)` 这样的已知 ID。
+        ///
+        /// # 示例
+        ///
+        /// ```
+        /// use libatasmart::AttributeId;
+        ///
+        /// assert_eq!(AttributeId::from(5), AttributeId::ReallocatedSectorCount);
+        /// assert_eq!(u8::from(AttributeId::TemperatureCelsius2), 194);
+        /// assert_eq!(AttributeId::from(251), AttributeId::Other(251));
+        /// ```
+        #[non_exhaustive]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum AttributeId {
+            $(
+                #[doc = concat!("`", $name, "` (", stringify!($id), ")")]
+                $variant,
+            )*
+            /// 属性信息表中没有的属性
+            Other(u8),
+        }
 
-    // 170-183: SSD 属性
-    arr[170] = Some(AttributeInfo {
-        name: "available-reserved-space",
-        unit: AttributeUnit::Percent,
-    });
-    arr[171] = Some(AttributeInfo {
-        name: "program-fail-count",
-        unit: AttributeUnit::None,
-    });
-    arr[172] = Some(AttributeInfo {
-        name: "erase-fail-count",
-        unit: AttributeUnit::None,
-    });
-    arr[175] = Some(AttributeInfo {
-        name: "program-fail-count-chip",
-        unit: AttributeUnit::None,
-    });
-    arr[176] = Some(AttributeInfo {
-        name: "erase-fail-count-chip",
-        unit: AttributeUnit::None,
-    });
-    arr[177] = Some(AttributeInfo {
-        name: "wear-leveling-count",
-        unit: AttributeUnit::None,
-    });
-    arr[178] = Some(AttributeInfo {
-        name: "used-reserved-blocks-chip",
-        unit: AttributeUnit::None,
-    });
-    arr[179] = Some(AttributeInfo {
-        name: "used-reserved-blocks-total",
-        unit: AttributeUnit::None,
-    });
-    arr[180] = Some(AttributeInfo {
-        name: "unused-reserved-blocks",
-        unit: AttributeUnit::None,
-    });
-    arr[181] = Some(AttributeInfo {
-        name: "program-fail-count-total",
-        unit: AttributeUnit::None,
-    });
-    arr[182] = Some(AttributeInfo {
-        name: "erase-fail-count-total",
-        unit: AttributeUnit::None,
-    });
-    arr[183] = Some(AttributeInfo {
-        name: "runtime-bad-block-total",
-        unit: AttributeUnit::None,
-    });
-
-    // 184-209: 其他属性
-    arr[184] = Some(AttributeInfo {
-        name: "end-to-end-error",
-        unit: AttributeUnit::None,
-    });
-    arr[187] = Some(AttributeInfo {
-        name: "reported-uncorrect",
-        unit: AttributeUnit::Sectors,
-    });
-    arr[188] = Some(AttributeInfo {
-        name: "command-timeout",
-        unit: AttributeUnit::None,
-    });
-    arr[189] = Some(AttributeInfo {
-        name: "high-fly-writes",
-        unit: AttributeUnit::None,
-    });
-    arr[190] = Some(AttributeInfo {
-        name: "airflow-temperature-celsius",
-        unit: AttributeUnit::MilliKelvin,
-    });
-    arr[191] = Some(AttributeInfo {
-        name: "g-sense-error-rate",
-        unit: AttributeUnit::None,
-    });
-    arr[192] = Some(AttributeInfo {
-        name: "power-off-retract-count",
-        unit: AttributeUnit::None,
-    });
-    arr[193] = Some(AttributeInfo {
-        name: "load-cycle-count",
-        unit: AttributeUnit::None,
-    });
-    arr[194] = Some(AttributeInfo {
-        name: "temperature-celsius-2",
-        unit: AttributeUnit::MilliKelvin,
-    });
-    arr[195] = Some(AttributeInfo {
-        name: "hardware-ecc-recovered",
-        unit: AttributeUnit::None,
-    });
-    arr[196] = Some(AttributeInfo {
-        name: "reallocated-event-count",
-        unit: AttributeUnit::None,
-    });
-    arr[197] = Some(AttributeInfo {
-        name: "current-pending-sector",
-        unit: AttributeUnit::Sectors,
-    });
-    arr[198] = Some(AttributeInfo {
-        name: "offline-uncorrectable",
-        unit: AttributeUnit::Sectors,
-    });
-    arr[199] = Some(AttributeInfo {
-        name: "udma-crc-error-count",
-        unit: AttributeUnit::None,
-    });
-    arr[200] = Some(AttributeInfo {
-        name: "multi-zone-error-rate",
-        unit: AttributeUnit::None,
-    });
-    arr[201] = Some(AttributeInfo {
-        name: "soft-read-error-rate",
-        unit: AttributeUnit::None,
-    });
-    arr[202] = Some(AttributeInfo {
-        name: "ta-increase-count",
-        unit: AttributeUnit::None,
-    });
-    arr[203] = Some(AttributeInfo {
-        name: "run-out-cancel",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[204] = Some(AttributeInfo {
-        name: "shock-count-write-open",
-        unit: AttributeUnit::None,
-    });
-    arr[205] = Some(AttributeInfo {
-        name: "shock-rate-write-open",
-        unit: AttributeUnit::None,
-    });
-    arr[206] = Some(AttributeInfo {
-        name: "flying-height",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[207] = Some(AttributeInfo {
-        name: "spin-high-current",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[208] = Some(AttributeInfo {
-        name: "spin-buzz",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[209] = Some(AttributeInfo {
-        name: "offline-seek-performance",
-        unit: AttributeUnit::Unknown,
-    });
+        impl AttributeId {
+            /// 从数值 ID 创建
+            pub const fn from_u8(id: u8) -> Self {
+                match id {
+                    $($id => Self::$variant,)*
+                    other => Self::Other(other),
+                }
+            }
 
+            /// 获取数值 ID
+            pub const fn as_u8(self) -> u8 {
+                match self {
+                    $(Self::$variant => $id,)*
+                    Self::Other(id) => id,
+                }
+            }
+        }
+    };
+}
+
+attribute_table! {
+    // 1-13: 基本属性
+    1 => RawReadErrorRate, "raw-read-error-rate", None;
+    2 => ThroughputPerformance, "throughput-performance", Unknown;
+    3 => SpinUpTime, "spin-up-time", Milliseconds;
+    4 => StartStopCount, "start-stop-count", None;
+    5 => ReallocatedSectorCount, "reallocated-sector-count", Sectors;
+    6 => ReadChannelMargin, "read-channel-margin", Unknown;
+    7 => SeekErrorRate, "seek-error-rate", None;
+    8 => SeekTimePerformance, "seek-time-performance", Unknown;
+    9 => PowerOnHours, "power-on-hours", Milliseconds;
+    10 => SpinRetryCount, "spin-retry-count", None;
+    11 => CalibrationRetryCount, "calibration-retry-count", None;
+    12 => PowerCycleCount, "power-cycle-count", None;
+    13 => ReadSoftErrorRate, "read-soft-error-rate", None;
+    // 22: 充氦硬盘 (HGST Ultrastar He、WD Red/Gold 大容量型号)
+    22 => HeliumLevel, "helium-level", Unknown;
+    // 160-169: SSD 控制器 (Silicon Motion、Phison 等) 的坏块和擦除计数
+    160 => UncorrectableErrorCount, "uncorrectable-error-count", None;
+    161 => ValidSpareBlockCount, "valid-spare-block-count", None;
+    162 => BadBlockCount, "bad-block-count", None;
+    163 => InitialBadBlockCount, "initial-bad-block-count", None;
+    164 => TotalEraseCount, "total-erase-count", None;
+    165 => MaxEraseCount, "max-erase-count", None;
+    166 => MinEraseCount, "min-erase-count", None;
+    167 => AverageEraseCount2, "average-erase-count-2", None;
+    168 => MaxEraseCountOfSpec, "max-erase-count-of-spec", None;
+    169 => RemainingLifetimePercent, "remaining-lifetime-percent", Percent;
+    // 170-183: SSD 属性
+    170 => AvailableReservedSpace, "available-reserved-space", Percent;
+    171 => ProgramFailCount, "program-fail-count", None;
+    172 => EraseFailCount, "erase-fail-count", None;
+    173 => AverageEraseCount, "average-erase-count", None;
+    174 => UnexpectedPowerLossCount, "unexpected-power-loss-count", None;
+    175 => ProgramFailCountChip, "program-fail-count-chip", None;
+    176 => EraseFailCountChip, "erase-fail-count-chip", None;
+    177 => WearLevelingCount, "wear-leveling-count", None;
+    178 => UsedReservedBlocksChip, "used-reserved-blocks-chip", None;
+    179 => UsedReservedBlocksTotal, "used-reserved-blocks-total", None;
+    180 => UnusedReservedBlocks, "unused-reserved-blocks", None;
+    181 => ProgramFailCountTotal, "program-fail-count-total", None;
+    182 => EraseFailCountTotal, "erase-fail-count-total", None;
+    183 => RuntimeBadBlockTotal, "runtime-bad-block-total", None;
+    // 184-218: 其他属性
+    184 => EndToEndError, "end-to-end-error", None;
+    185 => HeadStability, "head-stability", Unknown;
+    186 => InducedOpVibrationDetection, "induced-op-vibration-detection", Unknown;
+    187 => ReportedUncorrect, "reported-uncorrect", Sectors;
+    188 => CommandTimeout, "command-timeout", None;
+    189 => HighFlyWrites, "high-fly-writes", None;
+    190 => AirflowTemperatureCelsius, "airflow-temperature-celsius", MilliKelvin;
+    191 => GSenseErrorRate, "g-sense-error-rate", None;
+    192 => PowerOffRetractCount, "power-off-retract-count", None;
+    193 => LoadCycleCount, "load-cycle-count", None;
+    194 => TemperatureCelsius2, "temperature-celsius-2", MilliKelvin;
+    195 => HardwareEccRecovered, "hardware-ecc-recovered", None;
+    196 => ReallocatedEventCount, "reallocated-event-count", None;
+    197 => CurrentPendingSector, "current-pending-sector", Sectors;
+    198 => OfflineUncorrectable, "offline-uncorrectable", Sectors;
+    199 => UdmaCrcErrorCount, "udma-crc-error-count", None;
+    200 => MultiZoneErrorRate, "multi-zone-error-rate", None;
+    201 => SoftReadErrorRate, "soft-read-error-rate", None;
+    202 => TaIncreaseCount, "ta-increase-count", None;
+    203 => RunOutCancel, "run-out-cancel", Unknown;
+    204 => ShockCountWriteOpen, "shock-count-write-open", None;
+    205 => ShockRateWriteOpen, "shock-rate-write-open", None;
+    206 => FlyingHeight, "flying-height", Unknown;
+    207 => SpinHighCurrent, "spin-high-current", Unknown;
+    208 => SpinBuzz, "spin-buzz", Unknown;
+    209 => OfflineSeekPerformance, "offline-seek-performance", Unknown;
+    210 => SuccessRainRecoveryCount, "success-rain-recovery-count", None;
+    218 => CrcErrorCount, "crc-error-count", None;
     // 220-242: 更多属性
-    arr[220] = Some(AttributeInfo {
-        name: "disk-shift",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[221] = Some(AttributeInfo {
-        name: "g-sense-error-rate-2",
-        unit: AttributeUnit::None,
-    });
-    arr[222] = Some(AttributeInfo {
-        name: "loaded-hours",
-        unit: AttributeUnit::Milliseconds,
-    });
-    arr[223] = Some(AttributeInfo {
-        name: "load-retry-count",
-        unit: AttributeUnit::None,
-    });
-    arr[224] = Some(AttributeInfo {
-        name: "load-friction",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[225] = Some(AttributeInfo {
-        name: "load-cycle-count-2",
-        unit: AttributeUnit::None,
-    });
-    arr[226] = Some(AttributeInfo {
-        name: "load-in-time",
-        unit: AttributeUnit::Milliseconds,
-    });
-    arr[227] = Some(AttributeInfo {
-        name: "torq-amp-count",
-        unit: AttributeUnit::None,
-    });
-    arr[228] = Some(AttributeInfo {
-        name: "power-off-retract-count-2",
-        unit: AttributeUnit::None,
-    });
-    arr[230] = Some(AttributeInfo {
-        name: "head-amplitude",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[231] = Some(AttributeInfo {
-        name: "temperature-celsius",
-        unit: AttributeUnit::MilliKelvin,
-    });
-    arr[232] = Some(AttributeInfo {
-        name: "endurance-remaining",
-        unit: AttributeUnit::Percent,
-    });
-    arr[233] = Some(AttributeInfo {
-        name: "power-on-seconds-2",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[234] = Some(AttributeInfo {
-        name: "uncorrectable-ecc-count",
-        unit: AttributeUnit::Sectors,
-    });
-    arr[235] = Some(AttributeInfo {
-        name: "good-block-rate",
-        unit: AttributeUnit::Unknown,
-    });
-    arr[240] = Some(AttributeInfo {
-        name: "head-flying-hours",
-        unit: AttributeUnit::Milliseconds,
-    });
-    arr[241] = Some(AttributeInfo {
-        name: "total-lbas-written",
-        unit: AttributeUnit::Megabytes,
-    });
-    arr[242] = Some(AttributeInfo {
-        name: "total-lbas-read",
-        unit: AttributeUnit::Megabytes,
-    });
-    arr[250] = Some(AttributeInfo {
-        name: "read-error-retry-rate",
-        unit: AttributeUnit::None,
-    });
-
-    arr
-};
+    220 => DiskShift, "disk-shift", Unknown;
+    221 => GSenseErrorRate2, "g-sense-error-rate-2", None;
+    222 => LoadedHours, "loaded-hours", Milliseconds;
+    223 => LoadRetryCount, "load-retry-count", None;
+    224 => LoadFriction, "load-friction", Unknown;
+    225 => LoadCycleCount2, "load-cycle-count-2", None;
+    226 => LoadInTime, "load-in-time", Milliseconds;
+    227 => TorqAmpCount, "torq-amp-count", None;
+    228 => PowerOffRetractCount2, "power-off-retract-count-2", None;
+    230 => HeadAmplitude, "head-amplitude", Unknown;
+    231 => TemperatureCelsius, "temperature-celsius", MilliKelvin;
+    232 => EnduranceRemaining, "endurance-remaining", Percent;
+    233 => PowerOnSeconds2, "power-on-seconds-2", Unknown;
+    234 => UncorrectableEccCount, "uncorrectable-ecc-count", Sectors;
+    235 => GoodBlockRate, "good-block-rate", Unknown;
+    240 => HeadFlyingHours, "head-flying-hours", Milliseconds;
+    241 => TotalLbasWritten, "total-lbas-written", Megabytes;
+    242 => TotalLbasRead, "total-lbas-read", Megabytes;
+    // 246-248: Crucial/Micron SSD 的写入统计,以扇区或页为单位
+    246 => TotalHostSectorWrites, "total-host-sector-writes", None;
+    247 => HostProgramPageCount, "host-program-page-count", None;
+    248 => FtlProgramPageCount, "ftl-program-page-count", None;
+    250 => ReadErrorRetryRate, "read-error-retry-rate", None;
+    254 => FreeFallSensor, "free-fall-sensor", None;
+}
+
+impl AttributeId {
+    /// 属性名称,属性信息表中没有的属性返回 `None`
+    pub fn name(self) -> Option<&'static str> {
+        ATTRIBUTE_INFO[self.as_u8() as usize].map(|info| info.name)
+    }
+}
+
+impl From<u8> for AttributeId {
+    fn from(id: u8) -> Self {
+        Self::from_u8(id)
+    }
+}
+
+impl From<AttributeId> for u8 {
+    fn from(id: AttributeId) -> Self {
+        id.as_u8()
+    }
+}
+
+impl SmartAttributeParsedData {
+    /// 获取类型化的属性 ID
+    pub fn id_enum(&self) -> AttributeId {
+        AttributeId::from(self.id)
+    }
+
+    /// 获取属性的异常类型,正常时返回 `None`
+    ///
+    /// 同一属性有多种异常时按以下顺序取第一个:当前超过阈值、
+    /// 坏扇区计数 (属性 5、197) 非零、过去超过阈值。
+    /// 前后两种即 `warn` 字段的判定依据。
+    pub fn failure_kind(&self) -> Option<FailureKind> {
+        let bad_sectors = matches!(
+            self.id_enum(),
+            AttributeId::ReallocatedSectorCount | AttributeId::CurrentPendingSector
+        ) && self.pretty_value > 0;
+
+        match threshold_failure(self) {
+            Some(FailureKind::ThresholdNow) => Some(FailureKind::ThresholdNow),
+            _ if bad_sectors => Some(FailureKind::BadSectors),
+            failure => failure,
+        }
+    }
+
+    /// 获取异常信息,正常时返回 `None`,参见 [`SmartAttributeParsedData::failure_kind`]
+    pub fn failure(&self) -> Option<AttributeFailure> {
+        let kind = self.failure_kind()?;
+        Some(AttributeFailure {
+            attribute: self.clone(),
+            prefailure: self.prefailure,
+            kind,
+            threshold: (kind != FailureKind::BadSectors).then_some(self.threshold),
+        })
+    }
+
+    /// 获取带单位的属性值
+    ///
+    /// 根据 `pretty_unit` 转换 `pretty_value`,单位未知时返回 48 位原始值。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for attr in disk.read_smart()?.parse_attributes()? {
+    ///     println!("{}: {}", attr.name, attr.value());
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn value(&self) -> PrettyValue {
+        let value = self.pretty_value;
+        match self.pretty_unit {
+            AttributeUnit::Milliseconds => PrettyValue::Duration(Duration::from_millis(value)),
+            AttributeUnit::MilliKelvin => {
+                PrettyValue::Temperature(Temperature::from_millikelvin(value))
+            }
+            AttributeUnit::Sectors => PrettyValue::Sectors(value),
+            AttributeUnit::SmallPercent => PrettyValue::Percent(value as f64 / 1000.0),
+            AttributeUnit::Percent => PrettyValue::Percent(value as f64),
+            AttributeUnit::Megabytes => PrettyValue::Bytes(value.saturating_mul(1_000_000)),
+            AttributeUnit::GigaBytesWritten => {
+                PrettyValue::Bytes(value.saturating_mul(1_000_000_000))
+            }
+            AttributeUnit::None => PrettyValue::Count(value),
+            AttributeUnit::Unknown => {
+                let mut raw = [0u8; 8];
+                raw[..6].copy_from_slice(&self.raw);
+                PrettyValue::Raw(u64::from_le_bytes(raw))
+            }
+        }
+    }
+}
+
+/// 厂商属性单位覆盖表
+///
+/// (型号前缀, 属性 ID, 单位),用于与通用属性表不一致的设备
+static VENDOR_UNIT_OVERRIDES: &[(&str, u8, AttributeUnit)] = &[
+    // Intel S3710 系列以 GB 报告主机写入/读取量
+    ("INTEL SSDSC2BA", 241, AttributeUnit::GigaBytesWritten),
+    ("INTEL SSDSC2BA", 242, AttributeUnit::GigaBytesWritten),
+];
+
+/// 查找厂商覆盖的属性单位
+fn vendor_override_unit(model: Option<&str>, id: u8) -> Option<AttributeUnit> {
+    let model = model?;
+    VENDOR_UNIT_OVERRIDES
+        .iter()
+        .find(|(prefix, attr_id, _)| *attr_id == id && model.starts_with(prefix))
+        .map(|(_, _, unit)| *unit)
+}
 
 /// 计算 pretty value
 ///
@@ -317,6 +311,12 @@ fn make_pretty(attr: &mut SmartAttributeParsedData) {
         0,
     ]);
 
+    // 厂商直接以 GB 报告的值不需要换算
+    if attr.pretty_unit == AttributeUnit::GigaBytesWritten {
+        attr.pretty_value = fourtyeight;
+        return;
+    }
+
     attr.pretty_value = match attr.name {
         "spin-up-time" => fourtyeight & 0xFFFF,
 
@@ -341,8 +341,8 @@ fn make_pretty(attr: &mut SmartAttributeParsedData) {
         "endurance-remaining" | "available-reserved-space" => attr.current_value as u64,
 
         "total-lbas-written" | "total-lbas-read" => {
-            // 转换为 MB: LBAs * 65536 * 512 / 1000000
-            fourtyeight * 65536 * 512 / 1000000
+            // 转换为 MB: LBAs * 65536 * 512 / 1000000,中间结果可能超过 64 位
+            (fourtyeight as u128 * 65536 * 512 / 1000000) as u64
         }
 
         "timed-workload-media-wear" | "timed-workload-host-reads" => fourtyeight / 1024,
@@ -353,13 +353,64 @@ fn make_pretty(attr: &mut SmartAttributeParsedData) {
     };
 }
 
+/// 未定义属性的通用名称 `attribute-<ID>`,每项为名称字节和长度
+static UNKNOWN_ATTRIBUTE_NAMES: [([u8; 13], usize); 256] = {
+    let mut names = [([0u8; 13], 0); 256];
+    let prefix = b"attribute-";
+
+    let mut id = 0;
+    while id < 256 {
+        let mut name = [0u8; 13];
+        let mut len = 0;
+        while len < prefix.len() {
+            name[len] = prefix[len];
+            len += 1;
+        }
+
+        let digits = if id >= 100 {
+            3
+        } else if id >= 10 {
+            2
+        } else {
+            1
+        };
+        let mut value = id;
+        let mut i = digits;
+        while i > 0 {
+            name[len + i - 1] = b'0' + (value % 10) as u8;
+            value /= 10;
+            i -= 1;
+        }
+
+        names[id] = (name, len + digits);
+        id += 1;
+    }
+    names
+};
+
+/// 获取未定义属性的通用名称,不分配内存
+fn unknown_attribute_name(id: u8) -> &'static str {
+    let (name, len) = &UNKNOWN_ATTRIBUTE_NAMES[id as usize];
+    std::str::from_utf8(&name[..*len]).expect("属性名称为 ASCII")
+}
+
+/// 上下文中的型号是否属于 Seagate
+pub(crate) fn is_seagate_model(context: &AttributeContext) -> bool {
+    context
+        .model
+        .as_deref()
+        .is_some_and(|model| detect_vendor(model) == DiskVendor::Seagate)
+}
+
 /// 解析单个属性
 ///
-/// 从 12 字节的属性数据中解析出结构化信息
+/// 从 12 字节的属性数据中解析出结构化信息。
+/// 上下文中有设备型号时会应用厂商属性单位覆盖表,
+/// Seagate 硬盘的属性 188 只保留超时命令总数 (参见 [`SmartInfo::command_timeouts`])。
 pub(crate) fn parse_attribute(
     raw_data: &[u8],
     threshold_data: Option<&[u8]>,
-    disk_size: u64,
+    context: &AttributeContext,
 ) -> Option<SmartAttributeParsedData> {
     if raw_data.len() < 12 {
         return None;
@@ -375,10 +426,20 @@ pub(crate) fn parse_attribute(
         (info.name, info.unit)
     } else {
         // 未定义的属性，使用通用名称
-        let name = Box::leak(format!("attribute-{}", id).into_boxed_str());
-        (name as &'static str, AttributeUnit::Unknown)
+        (unknown_attribute_name(id), AttributeUnit::Unknown)
     };
 
+    // 部分型号的属性 9 不以小时计数
+    let name = if id == 9 && name == "power-on-hours" {
+        context.power_on_encoding.attribute_name()
+    } else {
+        name
+    };
+
+    let vendor_override_unit =
+        vendor_override_unit(context.model.as_deref(), id).filter(|u| *u != unit);
+    let unit = vendor_override_unit.unwrap_or(unit);
+
     // 解析标志位
     let flags = u16::from_le_bytes([raw_data[1], raw_data[2]]);
     let prefailure = (raw_data[1] & 1) != 0;
@@ -399,6 +460,7 @@ pub(crate) fn parse_attribute(
         id,
         name,
         pretty_unit: unit,
+        vendor_override_unit,
         flags,
         threshold: 0,
         threshold_valid: false,
@@ -420,6 +482,11 @@ pub(crate) fn parse_attribute(
     // 计算 pretty value
     make_pretty(&mut attr);
 
+    // Seagate 的属性 188 由三个 16 位计数组成,字节 0-1 为超时命令总数
+    if id == 188 && is_seagate_model(context) {
+        attr.pretty_value &= 0xFFFF;
+    }
+
     // 查找并应用阈值
     if let Some(threshold_raw) = threshold_data {
         if threshold_raw.len() >= 2 && threshold_raw[0] == id {
@@ -440,19 +507,29 @@ pub(crate) fn parse_attribute(
                 }
             }
 
-            attr.warn = (attr.good_now_valid && !attr.good_now)
-                || (attr.good_in_the_past_valid && !attr.good_in_the_past);
+            attr.warn = threshold_failure(&attr).is_some();
         }
     }
 
     // 验证属性值
-    verify_attribute(&mut attr, disk_size);
+    verify_attribute(&mut attr, context);
 
     Some(attr)
 }
 
+/// 阈值检查的结果,当前失败优先于过去失败
+pub(crate) fn threshold_failure(attr: &SmartAttributeParsedData) -> Option<FailureKind> {
+    if attr.good_now_valid && !attr.good_now {
+        Some(FailureKind::ThresholdNow)
+    } else if attr.good_in_the_past_valid && !attr.good_in_the_past {
+        Some(FailureKind::ThresholdInThePast)
+    } else {
+        None
+    }
+}
+
 /// 验证属性值的合理性
-fn verify_attribute(attr: &mut SmartAttributeParsedData, disk_size: u64) {
+fn verify_attribute(attr: &mut SmartAttributeParsedData, context: &AttributeContext) {
     match attr.pretty_unit {
         AttributeUnit::MilliKelvin => {
             // 温度范围：-15°C 到 100°C
@@ -463,6 +540,9 @@ fn verify_attribute(attr: &mut SmartAttributeParsedData, disk_size: u64) {
             }
         }
 
+        // SSD 没有主轴电机,启动时间通常为 0,不做检查
+        AttributeUnit::Milliseconds if context.is_ssd() && attr.name == "spin-up-time" => {}
+
         AttributeUnit::Milliseconds => {
             // 时间范围验证
             const MIN: u64 = 1;
@@ -480,28 +560,319 @@ fn verify_attribute(attr: &mut SmartAttributeParsedData, disk_size: u64) {
             }
         }
 
-        AttributeUnit::Sectors => {
-            // 扇区数验证
-            if disk_size > 0 {
-                let max_sectors = disk_size / 512;
-                if attr.pretty_value == 0xFFFFFFFF
-                    || attr.pretty_value == 0xFFFFFFFFFFFF
-                    || attr.pretty_value > max_sectors
-                {
-                    attr.pretty_unit = AttributeUnit::Unknown;
-                } else if (attr.name == "reallocated-sector-count"
-                    || attr.name == "current-pending-sector")
-                    && attr.pretty_value > 0
-                {
-                    attr.warn = true;
-                }
+        // 扇区数验证,设备大小未知 (0) 时跳过
+        AttributeUnit::Sectors if context.disk_size > 0 => {
+            let max_sectors = context.sector_count();
+            if attr.pretty_value == 0xFFFFFFFF
+                || attr.pretty_value == 0xFFFFFFFFFFFF
+                || attr.pretty_value > max_sectors
+            {
+                attr.pretty_unit = AttributeUnit::Unknown;
+            } else if (attr.name == "reallocated-sector-count"
+                || attr.name == "current-pending-sector")
+                && attr.pretty_value > 0
+            {
+                attr.warn = true;
             }
         }
 
+        // 厂商直接报告的写入量,全 1 表示无效,其余值均视为有效
+        AttributeUnit::GigaBytesWritten if attr.pretty_value == 0xFFFFFFFFFFFF => {
+            attr.pretty_unit = AttributeUnit::Unknown;
+        }
+
         _ => {}
     }
 }
 
+/// 查询属性表中的名称和单位
+///
+/// 不考虑厂商编码 (例如属性 9 的分钟编码),表中没有的 ID 返回 `None`
+///
+/// # 示例
+///
+/// ```
+/// use libatasmart::{attribute_info, AttributeUnit};
+///
+/// assert_eq!(
+///     attribute_info(5),
+///     Some(("reallocated-sector-count", AttributeUnit::Sectors))
+/// );
+/// assert_eq!(attribute_info(0), None);
+/// ```
+pub fn attribute_info(id: u8) -> Option<(&'static str, AttributeUnit)> {
+    ATTRIBUTE_INFO[id as usize].map(|info| (info.name, info.unit))
+}
+
+/// smartmontools 使用的属性名称
+///
+/// 只列出不能由本库名称直接转换得到的条目,其余名称按
+/// `"power-on-hours"` -> `"Power_On_Hours"` 的规则转换。
+/// smartctl JSON 导出和按名称查找属性 (别名) 共用这张表
+pub(crate) static SMARTCTL_NAMES: &[(&str, &str)] = &[
+    ("reallocated-sector-count", "Reallocated_Sector_Ct"),
+    ("available-reserved-space", "Available_Reservd_Space"),
+    ("unexpected-power-loss-count", "Unexpect_Power_Loss_Ct"),
+    ("used-reserved-blocks-chip", "Used_Rsvd_Blk_Cnt_Chip"),
+    ("used-reserved-blocks-total", "Used_Rsvd_Blk_Cnt_Tot"),
+    ("unused-reserved-blocks", "Unused_Rsvd_Blk_Cnt_Tot"),
+    ("program-fail-count-total", "Program_Fail_Cnt_Total"),
+    ("runtime-bad-block-total", "Runtime_Bad_Block"),
+    ("end-to-end-error", "End-to-End_Error"),
+    ("airflow-temperature-celsius", "Airflow_Temperature_Cel"),
+    ("g-sense-error-rate", "G-Sense_Error_Rate"),
+    ("power-off-retract-count", "Power-Off_Retract_Count"),
+    ("temperature-celsius-2", "Temperature_Celsius"),
+    ("hardware-ecc-recovered", "Hardware_ECC_Recovered"),
+    ("udma-crc-error-count", "UDMA_CRC_Error_Count"),
+    ("offline-seek-performance", "Offline_Seek_Performnce"),
+    ("g-sense-error-rate-2", "G-Sense_Error_Rate"),
+    ("load-cycle-count-2", "Load_Cycle_Count"),
+    ("load-in-time", "Load-in_Time"),
+    ("torq-amp-count", "Torq-amp_Count"),
+    ("power-off-retract-count-2", "Power-off_Retract_Count"),
+    ("total-lbas-written", "Total_LBAs_Written"),
+    ("total-lbas-read", "Total_LBAs_Read"),
+];
+
+/// 将属性名称转换为 smartmontools 的写法
+pub(crate) fn smartctl_attribute_name(name: &str) -> String {
+    if let Some((_, smartctl)) = SMARTCTL_NAMES.iter().find(|(ours, _)| *ours == name) {
+        return smartctl.to_string();
+    }
+
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// 名称匹配的优先级,数值越小越优先,不匹配时返回 `None`
+///
+/// 0. 本库的属性名称,不区分大小写
+/// 1. [`SMARTCTL_NAMES`] 中的 smartmontools 名称,不区分大小写
+/// 2. 按规则转换的 smartmontools 名称,即 `-` 与 `_` 视为相同,不区分大小写
+fn name_match_rank(ours: &str, query: &str) -> Option<u8> {
+    if ours.eq_ignore_ascii_case(query) {
+        return Some(0);
+    }
+
+    if SMARTCTL_NAMES
+        .iter()
+        .any(|(name, smartctl)| *name == ours && smartctl.eq_ignore_ascii_case(query))
+    {
+        return Some(1);
+    }
+
+    let rule_match = ours.len() == query.len()
+        && ours.bytes().zip(query.bytes()).all(|(a, b)| match a {
+            b'-' => b == b'_' || b == b'-',
+            _ => a.eq_ignore_ascii_case(&b),
+        });
+    rule_match.then_some(2)
+}
+
+impl SmartInfo {
+    /// 按名称查找属性
+    ///
+    /// 名称不区分大小写,也接受 smartmontools 的写法,例如
+    /// `"Reallocated_Sector_Ct"` 与 `"reallocated-sector-count"` 等价。
+    /// 多个属性匹配同一名称时按以下优先级选择,同一优先级取槽位靠前的:
+    ///
+    /// 1. 本库的属性名称,例如 `"temperature-celsius"` 为属性 231
+    /// 2. smartmontools 的专用名称,例如 `"Temperature_Celsius"` 为属性 194
+    /// 3. 按 `-` 与 `_` 互换得到的 smartmontools 名称,
+    ///    例如设备没有属性 194 时 `"Temperature_Celsius"` 匹配属性 231
+    ///
+    /// 设备没有报告匹配的属性时返回 `Ok(None)`。
+    pub fn attribute_by_name(&self, name: &str) -> Result<Option<SmartAttributeParsedData>> {
+        let mut best: Option<(u8, SmartAttributeParsedData)> = None;
+        self.for_each_attribute(|attr| {
+            if let Some(rank) = name_match_rank(attr.name, name) {
+                if best.as_ref().is_none_or(|(best_rank, _)| rank < *best_rank) {
+                    best = Some((rank, attr.clone()));
+                }
+                if rank == 0 {
+                    return ControlFlow::Break(());
+                }
+            }
+            ControlFlow::Continue(())
+        })?;
+        Ok(best.map(|(_, attr)| attr))
+    }
+
+    /// 获取异常的属性及原因,参见 [`Disk::smart_failing_attributes`]
+    pub fn failing_attributes(&self) -> Result<Vec<AttributeFailure>> {
+        Ok(self
+            .attributes()
+            .filter_map(|attr| attr.failure())
+            .collect())
+    }
+
+    /// 按 ID 查找属性,只解析匹配的槽位,参见 [`crate::SmartData::attribute`]
+    pub fn attribute_by_id(&self, id: u8) -> Result<Option<SmartAttributeParsedData>> {
+        Ok(self.data.attribute(id, self.thresholds.as_ref()))
+    }
+}
+
+impl Disk {
+    /// 按名称获取 SMART 属性
+    ///
+    /// 名称不区分大小写,也接受 smartmontools 的写法 (如 `"Current_Pending_Sector"`),
+    /// 匹配规则和优先级参见 [`SmartInfo::attribute_by_name`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 设备没有报告该属性时返回 `Ok(None)`。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// if let Some(attr) = disk.smart_get_attribute_by_name("reallocated-sector-count")? {
+    ///     println!("重新分配扇区: {}", attr.pretty_value);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_attribute_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<SmartAttributeParsedData>> {
+        self.smart_info()?.attribute_by_name(name)
+    }
+
+    /// 按 ID 获取单个 SMART 属性
+    ///
+    /// 只解析匹配的槽位及其阈值,不解析整个属性表,参见 [`crate::SmartData::attribute`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 设备没有报告该属性时返回 `Ok(None)`。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{AttributeId, Disk};
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// if let Some(attr) = disk.smart_get_attribute(AttributeId::PowerCycleCount.as_u8())? {
+    ///     println!("通电次数: {}", attr.pretty_value);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_attribute(&self, id: u8) -> Result<Option<SmartAttributeParsedData>> {
+        self.smart_info()?.attribute_by_id(id)
+    }
+
+    /// 按 ID 获取 SMART 属性
+    ///
+    /// 与 [`Disk::smart_get_attribute`] 相同
+    pub fn smart_get_attribute_by_id(&self, id: u8) -> Result<Option<SmartAttributeParsedData>> {
+        self.smart_get_attribute(id)
+    }
+
+    /// 逐个解析 SMART 属性并交给回调处理
+    ///
+    /// 与 libatasmart 的 `sk_disk_smart_parse_attributes` 相同,不分配属性列表,
+    /// 回调返回 `ControlFlow::Break(())` 时提前结束。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let mut prefailure = 0;
+    /// disk.for_each_smart_attribute(|attr| {
+    ///     if attr.prefailure {
+    ///         prefailure += 1;
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// })?;
+    /// println!("预失败属性: {}", prefailure);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn for_each_smart_attribute<F>(&self, f: F) -> Result<()>
+    where
+        F: FnMut(&SmartAttributeParsedData) -> ControlFlow<()>,
+    {
+        self.smart_info()?.for_each_attribute(f)
+    }
+
+    /// 获取处于警告状态 (`warn == true`) 的 SMART 属性
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 没有警告时返回空列表。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for attr in disk.smart_attribute_warnings()? {
+    ///     println!("{} ({}) 异常", attr.id, attr.name);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_attribute_warnings(&self) -> Result<Vec<SmartAttributeParsedData>> {
+        self.smart_attributes_where(|attr| attr.warn)
+    }
+
+    /// 获取异常的 SMART 属性及原因
+    ///
+    /// 包括当前或过去超过阈值的属性,以及坏扇区计数非零的属性,
+    /// 每个属性最多一条,参见 [`SmartAttributeParsedData::failure_kind`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 没有异常时返回空列表。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for failure in disk.smart_failing_attributes()? {
+    ///     print!("{} ({}): {}", failure.attribute.id, failure.attribute.name, failure.kind.as_str());
+    ///     if let Some(threshold) = failure.threshold {
+    ///         print!(", 阈值 {}", threshold);
+    ///     }
+    ///     println!();
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_failing_attributes(&self) -> Result<Vec<AttributeFailure>> {
+        self.smart_info()?.failing_attributes()
+    }
+
+    /// 获取预失败类型 (`prefailure == true`) 的 SMART 属性
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 没有预失败属性时返回空列表。
+    pub fn smart_prefailure_attributes(&self) -> Result<Vec<SmartAttributeParsedData>> {
+        self.smart_attributes_where(|attr| attr.prefailure)
+    }
+
+    /// 获取满足条件的 SMART 属性
+    fn smart_attributes_where(
+        &self,
+        predicate: impl Fn(&SmartAttributeParsedData) -> bool,
+    ) -> Result<Vec<SmartAttributeParsedData>> {
+        let smart = self.smart_info()?;
+        Ok(smart
+            .data
+            .attributes(smart.thresholds.as_ref())
+            .filter(|attr| predicate(attr))
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,7 +900,7 @@ mod tests {
         raw_data[5] = 0xE8;
         raw_data[6] = 0x03;
 
-        let attr = parse_attribute(&raw_data, None, 0).unwrap();
+        let attr = parse_attribute(&raw_data, None, &AttributeContext::default()).unwrap();
 
         assert_eq!(attr.id, 9);
         assert_eq!(attr.name, "power-on-hours");
@@ -540,4 +911,545 @@ mod tests {
         // 1000 小时 = 1000 * 60 * 60 * 1000 毫秒
         assert_eq!(attr.pretty_value, 1000 * 60 * 60 * 1000);
     }
+
+    #[test]
+    fn test_vendor_override_unit() {
+        // ID=241 (total-lbas-written), 原始值 1234
+        let mut raw_data = [0u8; 12];
+        raw_data[0] = 241;
+        raw_data[3] = 100;
+        raw_data[4] = 100;
+        raw_data[5..7].copy_from_slice(&1234u16.to_le_bytes());
+
+        let mut context = AttributeContext {
+            model: Some("INTEL SSDSC2BA400G4".to_string()),
+            ..Default::default()
+        };
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(
+            attr.vendor_override_unit,
+            Some(AttributeUnit::GigaBytesWritten)
+        );
+        assert_eq!(attr.pretty_unit, AttributeUnit::GigaBytesWritten);
+        assert_eq!(attr.pretty_value, 1234);
+
+        context.model = Some("Samsung SSD 850".to_string());
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.vendor_override_unit, None);
+        assert_eq!(attr.pretty_unit, AttributeUnit::Megabytes);
+        assert_eq!(attr.pretty_value, 1234 * 65536 * 512 / 1000000);
+    }
+
+    #[test]
+    fn test_power_on_encoding() {
+        // ID=9, 原始值 120
+        let mut raw_data = [0u8; 12];
+        raw_data[0] = 9;
+        raw_data[3] = 100;
+        raw_data[4] = 100;
+        raw_data[5] = 120;
+
+        let context = AttributeContext {
+            power_on_encoding: crate::types::PowerOnTimeEncoding::Minutes,
+            ..Default::default()
+        };
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.name, "power-on-minutes");
+        assert_eq!(attr.pretty_value, 120 * 60 * 1000);
+
+        let context = AttributeContext {
+            power_on_encoding: crate::types::PowerOnTimeEncoding::HalfMinutes,
+            ..Default::default()
+        };
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.name, "power-on-half-minutes");
+        assert_eq!(attr.pretty_value, 120 * 30 * 1000);
+
+        let context = AttributeContext {
+            power_on_encoding: crate::types::PowerOnTimeEncoding::Seconds,
+            ..Default::default()
+        };
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.name, "power-on-seconds");
+        assert_eq!(attr.pretty_value, 120 * 1000);
+    }
+
+    #[test]
+    fn test_power_on_encoding_from_identify() {
+        use crate::disk::{IdentifyData, SmartData};
+
+        // IDENTIFY 字 27-46 为型号,每个字内两个字节交换
+        fn identify_with_model(model: &str) -> IdentifyData {
+            let mut identify = [0u8; 512];
+            let mut field = [b' '; 40];
+            field[..model.len()].copy_from_slice(model.as_bytes());
+            for (i, pair) in field.chunks(2).enumerate() {
+                identify[54 + i * 2] = pair[1];
+                identify[54 + i * 2 + 1] = pair[0];
+            }
+            IdentifyData::new(identify)
+        }
+
+        // 属性 9, 原始值 90
+        let mut raw = [0u8; 512];
+        raw[2] = 9;
+        raw[5] = 100;
+        raw[6] = 100;
+        raw[7] = 90;
+
+        let cases = [
+            ("Maxtor 6B200M0", "power-on-minutes", 90 * 60 * 1000),
+            ("FUJITSU MHR2040AT", "power-on-seconds", 90 * 1000),
+            ("FUJITSU MHS2060AT", "power-on-seconds", 90 * 1000),
+            ("Maxtor 6Y080M0", "power-on-hours", 90 * 60 * 60 * 1000),
+        ];
+        for (model, name, millis) in cases {
+            let identify = identify_with_model(model);
+            assert_eq!(identify.parse().unwrap().model, model);
+
+            let smart = SmartData::new(raw, 0).with_identify(Some(&identify));
+            let attr = smart.parse_attributes(None).unwrap().remove(0);
+            assert_eq!(attr.name, name, "{}", model);
+            assert_eq!(attr.pretty_unit, AttributeUnit::Milliseconds, "{}", model);
+            assert_eq!(attr.pretty_value, millis, "{}", model);
+        }
+    }
+
+    #[test]
+    fn test_spin_up_time_on_ssd() {
+        // ID=3 (spin-up-time), 原始值 0
+        let mut raw_data = [0u8; 12];
+        raw_data[0] = 3;
+        raw_data[3] = 100;
+        raw_data[4] = 100;
+
+        let attr = parse_attribute(&raw_data, None, &AttributeContext::default()).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::Unknown);
+
+        let context = AttributeContext {
+            rotation_rate: Some(crate::types::RotationRate::Ssd),
+            ..Default::default()
+        };
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::Milliseconds);
+    }
+
+    #[test]
+    fn test_total_lbas_written_no_overflow() {
+        let mut raw_data = [0u8; 12];
+        raw_data[0] = 241;
+        raw_data[3] = 100;
+        raw_data[4] = 100;
+        raw_data[5..11].fill(0xFF);
+
+        let attr = parse_attribute(&raw_data, None, &AttributeContext::default()).unwrap();
+        assert_eq!(
+            attr.pretty_value,
+            (0xFFFFFFFFFFFFu128 * 65536 * 512 / 1000000) as u64
+        );
+    }
+
+    #[test]
+    fn test_attribute_lookup() {
+        use crate::disk::SmartData;
+
+        let mut raw = [0u8; 512];
+        // 槽位 0: power-on-hours, 槽位 1: reallocated-sector-count
+        raw[2] = 9;
+        raw[5] = 100;
+        raw[14] = 5;
+        raw[17] = 100;
+        raw[19] = 7;
+
+        let smart = SmartInfo::new(SmartData::new(raw, 0), None);
+
+        let attr = smart
+            .attribute_by_name("reallocated-sector-count")
+            .unwrap()
+            .unwrap();
+        assert_eq!(attr.id, 5);
+        assert_eq!(attr.pretty_value, 7);
+
+        let attr = smart.attribute_by_id(9).unwrap().unwrap();
+        assert_eq!(attr.name, "power-on-hours");
+
+        assert!(smart
+            .attribute_by_name("spin-retry-count")
+            .unwrap()
+            .is_none());
+        assert!(smart.attribute_by_id(10).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_attribute_lookup_aliases() {
+        use crate::disk::SmartData;
+
+        /// 按槽位顺序放入属性
+        fn smart_with_ids(ids: &[u8]) -> SmartInfo {
+            let mut raw = [0u8; 512];
+            for (slot, id) in ids.iter().enumerate() {
+                raw[2 + slot * 12] = *id;
+                raw[2 + slot * 12 + 3] = 100;
+            }
+            SmartInfo::new(SmartData::new(raw, 0), None)
+        }
+        let lookup = |smart: &SmartInfo, name: &str| {
+            smart.attribute_by_name(name).unwrap().map(|attr| attr.id)
+        };
+
+        let smart = smart_with_ids(&[231, 5, 197, 194, 9]);
+        for name in [
+            "reallocated-sector-count",
+            "REALLOCATED-SECTOR-COUNT",
+            "Reallocated_Sector_Ct",
+            "reallocated_sector_ct",
+            "Reallocated_Sector_Count",
+        ] {
+            assert_eq!(lookup(&smart, name), Some(5), "{}", name);
+        }
+        assert_eq!(lookup(&smart, "Current_Pending_Sector"), Some(197));
+        assert_eq!(lookup(&smart, "Power_On_Hours"), Some(9));
+
+        // 本库名称优先,其次是 smartmontools 的专用名称
+        assert_eq!(lookup(&smart, "temperature-celsius"), Some(231));
+        assert_eq!(lookup(&smart, "Temperature_Celsius"), Some(194));
+        assert_eq!(lookup(&smart, "temperature-celsius-2"), Some(194));
+        let smart_231 = smart_with_ids(&[231]);
+        assert_eq!(lookup(&smart_231, "Temperature_Celsius"), Some(231));
+
+        assert_eq!(lookup(&smart, "no-such-attribute"), None);
+        assert_eq!(lookup(&smart, "Spin_Retry_Count"), None);
+        assert_eq!(lookup(&smart, ""), None);
+    }
+
+    #[test]
+    fn test_failing_attributes() {
+        use crate::disk::{SmartData, SmartThresholds};
+
+        let mut raw = [0u8; 512];
+        let mut thresholds = [0u8; 512];
+        // (ID, 标志, 当前值, 最差值, 原始值, 阈值)
+        let slots = [
+            (1u8, 0x0Bu8, 10u8, 10u8, 0u8, 50u8), // 当前超过阈值
+            (3, 0x03, 100, 20, 0, 50),            // 过去超过阈值
+            (5, 0x33, 100, 100, 3, 10),           // 重新分配扇区
+            (197, 0x32, 100, 100, 0, 0),          // 没有待处理扇区
+            (9, 0x32, 90, 90, 100, 0),            // 正常
+        ];
+        for (slot, (id, flags, current, worst, raw_value, threshold)) in
+            slots.into_iter().enumerate()
+        {
+            let offset = 2 + slot * 12;
+            raw[offset..offset + 6].copy_from_slice(&[id, flags, 0, current, worst, raw_value]);
+            thresholds[offset] = id;
+            thresholds[offset + 1] = threshold;
+        }
+
+        let smart = SmartInfo::new(
+            SmartData::new(raw, 0),
+            Some(SmartThresholds::new(thresholds)),
+        );
+        let failures = smart.failing_attributes().unwrap();
+        let summary: Vec<_> = failures
+            .iter()
+            .map(|f| {
+                (
+                    f.attribute.id,
+                    f.kind,
+                    f.prefailure,
+                    f.threshold,
+                    f.is_current(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, FailureKind::ThresholdNow, true, Some(50), true),
+                (3, FailureKind::ThresholdInThePast, true, Some(50), false),
+                (5, FailureKind::BadSectors, true, None, true),
+            ]
+        );
+
+        // warn 只反映阈值检查
+        let warned: Vec<_> = smart
+            .attributes()
+            .filter(|attr| attr.warn)
+            .map(|attr| attr.id)
+            .collect();
+        assert_eq!(warned, [1, 3]);
+    }
+
+    #[test]
+    fn test_single_attribute_matches_iterator() {
+        use crate::disk::{SmartData, SmartThresholds};
+
+        let mut raw = [0u8; 512];
+        let mut thresholds = [0u8; 512];
+        // 槽位 0: 属性 5 (阈值 36), 槽位 1: 属性 194, 槽位 2: 重复的属性 5
+        for (slot, (id, value, raw_value)) in [(5u8, 100u8, 3u8), (194, 64, 36), (5, 90, 99)]
+            .into_iter()
+            .enumerate()
+        {
+            let offset = 2 + slot * 12;
+            raw[offset] = id;
+            raw[offset + 3] = value;
+            raw[offset + 4] = value;
+            raw[offset + 5] = raw_value;
+        }
+        // 阈值表中的顺序与数据表不同
+        thresholds[2] = 194;
+        thresholds[3] = 0;
+        thresholds[14] = 5;
+        thresholds[15] = 36;
+
+        let data = SmartData::new(raw, 0);
+        let thresholds = SmartThresholds::new(thresholds);
+
+        for id in [5, 194] {
+            let single = data.attribute(id, Some(&thresholds)).unwrap();
+            let iterated = data
+                .attributes(Some(&thresholds))
+                .find(|attr| attr.id == id)
+                .unwrap();
+            assert_eq!(single.name, iterated.name);
+            assert_eq!(single.pretty_value, iterated.pretty_value);
+            assert_eq!(single.threshold, iterated.threshold);
+            assert_eq!(single.warn, iterated.warn);
+        }
+
+        // 重复的 ID 使用第一个槽位
+        let attr = data.attribute(5, Some(&thresholds)).unwrap();
+        assert_eq!(attr.current_value, 100);
+        assert_eq!(attr.threshold, 36);
+
+        assert!(data.attribute(9, Some(&thresholds)).is_none());
+        assert!(data.attribute(0, None).is_none());
+        assert!(!data.attribute(194, None).unwrap().threshold_valid);
+    }
+
+    #[test]
+    fn test_for_each_attribute_early_exit() {
+        use crate::disk::SmartData;
+
+        let mut raw = [0u8; 512];
+        for (slot, id) in [9u8, 5, 194].into_iter().enumerate() {
+            raw[2 + slot * 12] = id;
+            raw[2 + slot * 12 + 3] = 100;
+        }
+        let smart = SmartInfo::new(SmartData::new(raw, 0), None);
+
+        let mut ids = Vec::new();
+        smart
+            .for_each_attribute(|attr| {
+                ids.push(attr.id);
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(ids, [9, 5, 194]);
+        assert_eq!(
+            ids,
+            smart
+                .parse_attributes()
+                .unwrap()
+                .iter()
+                .map(|attr| attr.id)
+                .collect::<Vec<_>>()
+        );
+
+        let mut visited = 0;
+        smart
+            .for_each_attribute(|attr| {
+                visited += 1;
+                if attr.id == 5 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .unwrap();
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn test_attribute_id() {
+        assert_eq!(AttributeId::from(5), AttributeId::ReallocatedSectorCount);
+        assert_eq!(AttributeId::from(194), AttributeId::TemperatureCelsius2);
+        assert!(matches!(AttributeId::from(251), AttributeId::Other(251)));
+        assert!(matches!(AttributeId::from(9), AttributeId::PowerOnHours));
+        assert_eq!(AttributeId::PowerOnHours.name(), Some("power-on-hours"));
+        assert_eq!(AttributeId::Other(0).name(), None);
+
+        // 与属性信息表一致
+        for id in 0..=u8::MAX {
+            let attribute_id = AttributeId::from(id);
+            assert_eq!(u8::from(attribute_id), id);
+            assert_eq!(
+                matches!(attribute_id, AttributeId::Other(_)),
+                ATTRIBUTE_INFO[id as usize].is_none(),
+                "{}",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_attribute_value() {
+        let ctx = AttributeContext::default();
+        let parse = |data: [u8; 12]| parse_attribute(&data, None, &ctx).unwrap().value();
+
+        // power-on-hours: 1000 小时
+        assert_eq!(
+            parse([9, 0, 0, 100, 100, 0xE8, 0x03, 0, 0, 0, 0, 0]),
+            PrettyValue::Duration(Duration::from_hours(1000))
+        );
+        // reallocated-sector-count: 5 个扇区
+        assert_eq!(
+            parse([5, 0, 0, 100, 100, 5, 0, 0, 0, 0, 0, 0]),
+            PrettyValue::Sectors(5)
+        );
+        // temperature-celsius-2: 35°C
+        assert_eq!(
+            parse([194, 0, 0, 100, 100, 35, 0, 0, 0, 0, 0, 0]),
+            PrettyValue::Temperature(Temperature::from_millikelvin(35 * 1000 + 273150))
+        );
+        // 未知属性保留 48 位原始值
+        assert_eq!(
+            parse([251, 0, 0, 100, 100, 0x01, 0x02, 0, 0, 0, 0x03, 0]),
+            PrettyValue::Raw(0x0300_0000_0201)
+        );
+    }
+
+    #[test]
+    fn test_unknown_attribute_name() {
+        assert_eq!(unknown_attribute_name(0), "attribute-0");
+        assert_eq!(unknown_attribute_name(7), "attribute-7");
+        assert_eq!(unknown_attribute_name(42), "attribute-42");
+        assert_eq!(unknown_attribute_name(100), "attribute-100");
+        assert_eq!(unknown_attribute_name(255), "attribute-255");
+
+        let attr = parse_attribute(
+            &[251, 0, 0, 100, 100, 0, 0, 0, 0, 0, 0, 0],
+            None,
+            &AttributeContext::default(),
+        )
+        .unwrap();
+        assert_eq!(attr.name, "attribute-251");
+    }
+
+    #[test]
+    fn test_attribute_iter_thresholds() {
+        use crate::disk::{SmartData, SmartThresholds};
+
+        let mut raw = [0u8; 512];
+        // 槽位 0: 属性 5, 槽位 2: 属性 194 (槽位 1 为空)
+        raw[2] = 5;
+        raw[2 + 3] = 100;
+        raw[26] = 194;
+        raw[26 + 3] = 40;
+
+        let mut thresholds = [0u8; 512];
+        // 阈值槽位顺序与数据不同,属性 5 出现两次时使用第一个
+        thresholds[2] = 194;
+        thresholds[3] = 10;
+        thresholds[14] = 5;
+        thresholds[15] = 36;
+        thresholds[26] = 5;
+        thresholds[27] = 99;
+
+        let data = SmartData::new(raw, 0);
+        let thresholds = SmartThresholds::new(thresholds);
+        let attributes: Vec<_> = data.attributes(Some(&thresholds)).collect();
+        assert_eq!(attributes.len(), 2);
+        assert_eq!((attributes[0].id, attributes[0].threshold), (5, 36));
+        assert_eq!((attributes[1].id, attributes[1].threshold), (194, 10));
+
+        let without: Vec<_> = data.attributes(None).collect();
+        assert!(without.iter().all(|attr| !attr.threshold_valid));
+        let parsed = data.parse_attributes(Some(&thresholds)).unwrap();
+        assert_eq!(
+            parsed.iter().map(|attr| attr.threshold).collect::<Vec<_>>(),
+            [36, 10]
+        );
+    }
+
+    #[test]
+    fn test_verify_sectors_uses_sector_size() {
+        // ID=5 (reallocated-sector-count), 原始值 300000
+        let mut raw_data = [0u8; 12];
+        raw_data[0] = 5;
+        raw_data[3] = 100;
+        raw_data[4] = 100;
+        raw_data[5..9].copy_from_slice(&300000u32.to_le_bytes());
+
+        // 1 GB: 512 字节扇区约 200 万个, 4096 字节扇区约 24 万个
+        let mut context = AttributeContext::new(1_000_000_000);
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::Sectors);
+
+        context.sector_size = 4096;
+        let attr = parse_attribute(&raw_data, None, &context).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::Unknown);
+    }
+
+    #[test]
+    fn test_verify_sectors_4k_native_from_identify() {
+        use crate::disk::{IdentifyData, SmartData};
+
+        // 4 TB 4K 原生设备: 字 106 bit 12 表示逻辑扇区大于 512 字节, 字 117 为 2048 字
+        let mut identify = [0u8; 512];
+        identify[212..214].copy_from_slice(&(0x4000u16 | (1 << 12)).to_le_bytes());
+        identify[234..236].copy_from_slice(&2048u16.to_le_bytes());
+        let identify = IdentifyData::new(identify);
+
+        let disk_size = 4_000_787_030_016u64;
+        let mut raw = [0u8; 512];
+        raw[2] = 5;
+        raw[5] = 100;
+        raw[6] = 100;
+        raw[7..11].copy_from_slice(&1000u32.to_le_bytes());
+
+        let smart = SmartData::new(raw, disk_size).with_identify(Some(&identify));
+        assert_eq!(smart.context().sector_size, 4096);
+        assert_eq!(smart.context().sector_count(), 976_754_646);
+
+        let attr = smart.parse_attributes(None).unwrap().remove(0);
+        assert_eq!(attr.pretty_unit, AttributeUnit::Sectors);
+        assert_eq!(attr.pretty_value, 1000);
+
+        // 超过 4K 扇区总数, 但小于按 512 字节计算的扇区数
+        raw[7..11].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+        let smart = SmartData::new(raw, disk_size).with_identify(Some(&identify));
+        let attr = smart.parse_attributes(None).unwrap().remove(0);
+        assert_eq!(attr.pretty_unit, AttributeUnit::Unknown);
+    }
+
+    #[test]
+    fn test_smartctl_attribute_name() {
+        assert_eq!(smartctl_attribute_name("power-on-hours"), "Power_On_Hours");
+        assert_eq!(
+            smartctl_attribute_name("reallocated-sector-count"),
+            "Reallocated_Sector_Ct"
+        );
+        assert_eq!(
+            smartctl_attribute_name("temperature-celsius-2"),
+            "Temperature_Celsius"
+        );
+        assert_eq!(
+            smartctl_attribute_name("udma-crc-error-count"),
+            "UDMA_CRC_Error_Count"
+        );
+
+        // 映射表中的名称都必须存在于属性表中
+        for (ours, _) in SMARTCTL_NAMES {
+            assert!(
+                ATTRIBUTE_INFO
+                    .iter()
+                    .flatten()
+                    .any(|info| info.name == *ours),
+                "{}",
+                ours
+            );
+        }
+    }
 }
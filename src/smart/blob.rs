@@ -5,6 +5,7 @@
 use crate::disk::Disk;
 use crate::error::{Error, Result};
 use crate::types::DiskType;
+use crate::utils::verify_ata_checksum;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
@@ -37,6 +38,7 @@ impl BlobTag {
 }
 
 /// Blob 数据结构
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BlobData {
     /// IDENTIFY 数据
     pub identify: Option<[u8; 512]>,
@@ -58,6 +60,65 @@ impl BlobData {
             smart_thresholds: None,
         }
     }
+
+    /// 创建磁盘
+    ///
+    /// 与 [`Disk::from_raw_buffers`] 相同,可以在修改 blob 数据后再创建磁盘
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::InvalidData)` - SMART 数据或阈值的校验和错误
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::read_blob_from_file;
+    ///
+    /// let mut blob = read_blob_from_file("disk.blob")?;
+    /// // 模拟 SMART 自评估失败
+    /// blob.smart_status = Some(false);
+    /// let disk = blob.to_disk()?;
+    /// println!("{:?}", disk.smart_get_overall()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn to_disk(self) -> Result<Disk> {
+        Disk::from_raw_buffers(
+            self.identify,
+            self.smart_data,
+            self.smart_thresholds,
+            self.smart_status,
+        )
+    }
+
+    /// 序列化为 blob 格式
+    ///
+    /// 按 IDENTIFY、SMART 状态、SMART 数据、SMART 阈值的顺序写入存在的块
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        fn push_block(out: &mut Vec<u8>, tag: BlobTag, data: &[u8]) {
+            out.extend_from_slice(&(tag as u32).to_be_bytes());
+            out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            out.extend_from_slice(data);
+        }
+
+        let mut out = Vec::new();
+        if let Some(identify) = &self.identify {
+            push_block(&mut out, BlobTag::Identify, identify);
+        }
+        if let Some(status) = self.smart_status {
+            push_block(
+                &mut out,
+                BlobTag::SmartStatus,
+                &(status as u32).to_be_bytes(),
+            );
+        }
+        if let Some(smart_data) = &self.smart_data {
+            push_block(&mut out, BlobTag::SmartData, smart_data);
+        }
+        if let Some(thresholds) = &self.smart_thresholds {
+            push_block(&mut out, BlobTag::SmartThresholds, thresholds);
+        }
+        out
+    }
 }
 
 /// 从文件读取 blob 数据
@@ -70,7 +131,7 @@ pub fn read_blob_from_file<P: AsRef<Path>>(path: P) -> Result<BlobData> {
 }
 
 /// 解析 blob 数据
-fn parse_blob(data: &[u8]) -> Result<BlobData> {
+pub(crate) fn parse_blob(data: &[u8]) -> Result<BlobData> {
     let mut blob_data = BlobData::new();
     let mut pos = 0;
 
@@ -124,12 +185,14 @@ fn parse_blob(data: &[u8]) -> Result<BlobData> {
                 if size != 512 || has_smart_data {
                     return Err(Error::InvalidData("无效的 SMART DATA 块".to_string()));
                 }
+                check_sector_checksum(&data[temp_pos..temp_pos + 512])?;
                 has_smart_data = true;
             }
             Some(BlobTag::SmartThresholds) => {
                 if size != 512 || has_smart_thresholds {
                     return Err(Error::InvalidData("无效的 SMART THRESHOLDS 块".to_string()));
                 }
+                check_sector_checksum(&data[temp_pos..temp_pos + 512])?;
                 has_smart_thresholds = true;
             }
             None => {
@@ -190,6 +253,17 @@ fn parse_blob(data: &[u8]) -> Result<BlobData> {
     Ok(blob_data)
 }
 
+/// 校验 blob 中 SMART 扇区的校验和
+fn check_sector_checksum(sector: &[u8]) -> Result<()> {
+    let sector: &[u8; 512] = sector
+        .try_into()
+        .map_err(|_| Error::InvalidData("无效的 SMART 扇区".to_string()))?;
+    if !verify_ata_checksum(sector) {
+        return Err(Error::InvalidData("SMART checksum mismatch".to_string()));
+    }
+    Ok(())
+}
+
 /// 从 blob 文件读取数据并创建 SmartInfo
 ///
 /// 这个函数读取 blob 文件并返回包含 SMART 数据的 `SmartInfo` 对象。
@@ -205,13 +279,23 @@ fn parse_blob(data: &[u8]) -> Result<BlobData> {
 /// # Ok::<(), libatasmart::Error>(())
 /// ```
 pub fn smart_info_from_blob<P: AsRef<Path>>(path: P) -> Result<crate::disk::SmartInfo> {
-    let blob_data = read_blob_from_file(path)?;
+    smart_info_from_blob_data(&read_blob_from_file(path)?)
+}
 
+/// 从已解析的 blob 数据创建 SmartInfo
+pub(crate) fn smart_info_from_blob_data(blob_data: &BlobData) -> Result<crate::disk::SmartInfo> {
     let smart_data = blob_data
         .smart_data
         .ok_or(Error::InvalidData("Blob 缺少 SMART 数据".to_string()))?;
 
-    let smart_data_obj = crate::disk::SmartData::new(smart_data, 0);
+    let identify = blob_data.identify.map(crate::disk::IdentifyData::new);
+    // Blob 没有设备大小,使用 IDENTIFY 报告的容量进行扇区数检查
+    let disk_size = identify
+        .as_ref()
+        .and_then(|identify| identify.capacity())
+        .unwrap_or(0);
+    let smart_data_obj =
+        crate::disk::SmartData::new(smart_data, disk_size).with_identify(identify.as_ref());
     let thresholds_obj = blob_data
         .smart_thresholds
         .map(crate::disk::SmartThresholds::new);
@@ -265,4 +349,68 @@ mod tests {
         assert!(blob_data.smart_data.is_none());
         assert!(blob_data.smart_thresholds.is_none());
     }
+
+    #[test]
+    fn test_blob_round_trip() {
+        let mut identify = [0u8; 512];
+        identify[0] = 0x40;
+        let blob_data = BlobData {
+            identify: Some(identify),
+            smart_status: Some(false),
+            smart_data: Some([0u8; 512]),
+            smart_thresholds: None,
+        };
+
+        let bytes = blob_data.to_bytes();
+        assert_eq!(bytes.len(), 3 * 8 + 512 + 4 + 512);
+
+        let parsed = parse_blob(&bytes).unwrap();
+        assert_eq!(parsed.identify, Some(identify));
+        assert_eq!(parsed.smart_status, Some(false));
+        assert_eq!(parsed.smart_data, Some([0u8; 512]));
+        assert!(parsed.smart_thresholds.is_none());
+        assert_eq!(parsed.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_blob_to_disk_and_capture() {
+        let mut identify = [0u8; 512];
+        identify[0] = 0x40;
+        let blob_data = BlobData {
+            identify: Some(identify),
+            smart_status: Some(true),
+            smart_data: Some([0u8; 512]),
+            smart_thresholds: Some([0u8; 512]),
+        };
+
+        let disk = blob_data.clone().to_disk().unwrap();
+        assert_eq!(disk.disk_type(), DiskType::Blob);
+        assert_eq!(disk.capture_blob(), blob_data);
+
+        // 校验和错误
+        let mut bad = blob_data;
+        bad.smart_data.as_mut().unwrap()[2] = 1;
+        assert!(bad.to_disk().is_err());
+    }
+
+    #[test]
+    fn test_parse_blob_smart_checksum() {
+        let mut blob = Vec::new();
+        blob.extend_from_slice(&0x49444659u32.to_be_bytes());
+        blob.extend_from_slice(&512u32.to_be_bytes());
+        blob.extend_from_slice(&[0u8; 512]);
+
+        let mut sector = [0u8; 512];
+        sector[2] = 5;
+        sector[511] = 0u8.wrapping_sub(5);
+        blob.extend_from_slice(&0x534D4454u32.to_be_bytes());
+        blob.extend_from_slice(&512u32.to_be_bytes());
+        blob.extend_from_slice(&sector);
+        assert!(parse_blob(&blob).unwrap().smart_data.is_some());
+
+        // 单个字节翻转
+        let len = blob.len();
+        blob[len - 512 + 2] ^= 0x01;
+        assert!(parse_blob(&blob).is_err());
+    }
 }
@@ -1,7 +1,22 @@
 //! SMART 数据读取
 
 use crate::disk::Disk;
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::ffi;
+use crate::types::{DiskType, OfflineTestType, SmartParsedData};
+
+/// 设备是否支持以离线模式立即执行 `subtest`
+///
+/// 离线数据收集 (子命令 0x00) 取决于离线数据收集能力,
+/// 其他子命令取决于对应的自检能力
+fn offline_subtest_available(parsed: &SmartParsedData, subtest: OfflineTestType) -> bool {
+    match subtest.self_test() {
+        Some(test) => parsed.self_test_available(test),
+        None => parsed
+            .offline_data_collection_capability
+            .supports_execute_immediate(),
+    }
+}
 
 impl Disk {
     // 注意: SMART 数据读取方法已在 src/disk/device.rs 中实现
@@ -9,6 +24,151 @@ impl Disk {
     // - read_smart_data()
     // - read_smart_thresholds()
     // - smart_status()
+
+    /// 立即执行离线数据收集
+    ///
+    /// 发送 SMART EXECUTE OFF-LINE IMMEDIATE (0xD4) 命令,子命令 0x00 表示
+    /// 以离线模式执行离线数据收集例程。收集在后台进行,
+    /// 进度可通过 [`SmartParsedData::offline_data_collection_status`] 查询。
+    ///
+    /// # 返回
+    ///
+    /// * `Ok(())` - 离线数据收集已成功启动
+    /// * `Err(Error::NotSupported)` - 设备不支持立即执行离线数据收集
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.smart_initiate_offline_diagnostic()?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    ///
+    /// [`SmartParsedData::offline_data_collection_status`]: crate::SmartParsedData::offline_data_collection_status
+    pub fn smart_initiate_offline_diagnostic(&self) -> Result<()> {
+        self.smart_execute_immediate_offline(OfflineTestType::Offline)
+    }
+
+    /// 以离线模式立即执行测试
+    ///
+    /// 发送 SMART EXECUTE OFF-LINE IMMEDIATE (0xD4) 命令,子命令 (0-3) 放在
+    /// LBA LOW 寄存器中。与 [`Disk::start_self_test`] 不同,这里只接受离线模式的
+    /// 测试类型,测试在设备后台运行。
+    ///
+    /// # 返回
+    ///
+    /// * `Ok(())` - 测试已成功启动
+    /// * `Err(Error::NotSupported)` - 设备不支持该测试类型
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, OfflineTestType};
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.smart_execute_immediate_offline(OfflineTestType::Short)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_execute_immediate_offline(&self, subtest: OfflineTestType) -> Result<()> {
+        self.ensure_writable()?;
+
+        // 检查SMART是否可用
+        let identify = self.read_identify_cached()?;
+        if !Self::is_smart_available(&identify)? {
+            return Err(Error::NotSupported("SMART功能不可用".to_string()));
+        }
+
+        // Blob类型不支持
+        if self.disk_type() == DiskType::Blob {
+            return Err(Error::NotSupported(format!(
+                "Blob类型不支持 {} 测试",
+                subtest.as_str()
+            )));
+        }
+
+        // 检查设备是否支持该测试类型
+        let parsed = self.read_smart_data()?.parse()?;
+        if !offline_subtest_available(&parsed, subtest) {
+            return Err(Error::NotSupported(format!(
+                "{} 测试不可用",
+                subtest.as_str()
+            )));
+        }
+
+        let mut registers = ffi::commands::AtaRegisters::new();
+
+        // 设置SMART EXECUTE OFFLINE IMMEDIATE命令参数
+        registers.set_features(ffi::ata::SmartCommand::ExecuteOfflineImmediate as u8);
+        registers.set_lba_mid(0x4F);
+        registers.set_lba_high(0xC2);
+        // 子命令 0-3: 离线模式执行
+        registers.set_lba_low(subtest as u8);
+
+        // 发送 SMART 命令
+        self.send_command(
+            ffi::ata::AtaCommand::Smart,
+            ffi::ata::Direction::None,
+            &mut registers,
+            None,
+        )
+    }
+
+    /// 启用或禁用 SMART 属性自动保存
+    ///
+    /// 发送 SMART ENABLE/DISABLE ATTRIBUTE AUTOSAVE (0xD2) 命令,
+    /// SECTOR COUNT 为 0xF1 时启用,0x00 时禁用。启用后设备会在进入省电模式
+    /// 或断电前自行把属性值写入非易失存储;SMART SAVE ATTRIBUTE VALUES (0xD3)
+    /// 则只在收到命令时保存一次。
+    ///
+    /// ATA 规范没有在 IDENTIFY 中报告该功能的当前状态
+    /// (字 85 bit 6 为预读,参见 [`IdentifyParsedData::read_lookahead`])。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持 SMART
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// disk.smart_enable_attribute_auto_save(true)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    ///
+    /// [`IdentifyParsedData::read_lookahead`]: crate::IdentifyParsedData::read_lookahead
+    pub fn smart_enable_attribute_auto_save(&self, enable: bool) -> Result<()> {
+        self.ensure_writable()?;
+
+        // Blob类型不支持
+        if self.disk_type() == DiskType::Blob {
+            return Err(Error::NotSupported(
+                "Blob类型不支持设置属性自动保存".to_string(),
+            ));
+        }
+
+        // 检查SMART是否可用
+        let identify = self.read_identify_cached()?;
+        if !Self::is_smart_available(&identify)? {
+            return Err(Error::NotSupported("SMART功能不可用".to_string()));
+        }
+
+        let mut registers = ffi::commands::AtaRegisters::new();
+        registers.set_features(ffi::ata::SmartCommand::AttributeAutosave as u8);
+        registers.set_lba_mid(0x4F);
+        registers.set_lba_high(0xC2);
+        registers.set_sector_count(if enable { 0xF1 } else { 0x00 });
+
+        self.send_command(
+            ffi::ata::AtaCommand::Smart,
+            ffi::ata::Direction::None,
+            &mut registers,
+            None,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -19,4 +179,68 @@ mod tests {
     fn test_smart_operations() {
         // 需要真实设备才能测试
     }
+
+    /// 解析离线数据收集能力 (字节 367) 为 `capability` 的 SMART 数据
+    fn parsed_with_capability(capability: u8) -> SmartParsedData {
+        let mut raw = [0u8; 512];
+        raw[367] = capability;
+        let sum = raw.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        raw[511] = sum.wrapping_neg();
+        crate::disk::SmartData::new(raw, 0).parse().unwrap()
+    }
+
+    #[test]
+    fn test_offline_subtest_available() {
+        // bit 0 清零: 不支持 EXECUTE OFF-LINE IMMEDIATE
+        let parsed = parsed_with_capability(0x00);
+        assert!(!offline_subtest_available(
+            &parsed,
+            OfflineTestType::Offline
+        ));
+        assert!(!offline_subtest_available(&parsed, OfflineTestType::Short));
+
+        // 只由厂商安排离线扫描时同样不支持
+        let parsed = parsed_with_capability(0x08);
+        assert!(!offline_subtest_available(
+            &parsed,
+            OfflineTestType::Offline
+        ));
+
+        // bit 0 置位: 支持离线数据收集,但没有自检能力
+        let parsed = parsed_with_capability(0x01);
+        assert!(offline_subtest_available(&parsed, OfflineTestType::Offline));
+        assert!(!offline_subtest_available(&parsed, OfflineTestType::Short));
+
+        // bit 4 置位: 支持短/扩展自检
+        let parsed = parsed_with_capability(0x11);
+        assert!(offline_subtest_available(&parsed, OfflineTestType::Offline));
+        assert!(offline_subtest_available(&parsed, OfflineTestType::Short));
+    }
+
+    #[test]
+    fn test_offline_diagnostic_blob_not_supported() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.smart_initiate_offline_diagnostic(),
+            Err(Error::NotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_attribute_auto_save_blob_not_supported() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.smart_enable_attribute_auto_save(true),
+            Err(Error::NotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_execute_immediate_offline_blob_not_supported() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.smart_execute_immediate_offline(OfflineTestType::Short),
+            Err(Error::NotSupported(_))
+        ));
+    }
 }
@@ -0,0 +1,273 @@
+//! SMART 错误日志
+//!
+//! 解析扩展综合错误日志 (GPL 日志 0x03)
+
+use crate::disk::Disk;
+use crate::error::{Error, Result};
+use crate::types::{SmartErrorCommand, SmartErrorLogEntry};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// 扩展综合错误日志地址
+const LOG_EXT_COMPREHENSIVE_ERROR: u8 = 0x03;
+
+/// 每页的错误条目数
+const ENTRIES_PER_PAGE: usize = 4;
+
+/// 每个错误条目的字节数 (5 个命令 × 18 字节 + 34 字节错误描述)
+const ENTRY_SIZE: usize = 124;
+
+/// 最多读取的错误条目数
+const MAX_ENTRIES: usize = 64;
+
+/// 小端序读取 `bytes.len()` 字节的无符号整数
+fn read_le(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0u64, |value, &byte| (value << 8) | u64::from(byte))
+}
+
+/// 读取 48 位 LBA
+///
+/// ACS 将 6 个字节交错存放: LBA(7:0)、LBA(31:24)、LBA(15:8)、
+/// LBA(39:32)、LBA(23:16)、LBA(47:40)
+fn read_lba(bytes: &[u8]) -> u64 {
+    [0, 2, 4, 1, 3, 5]
+        .iter()
+        .enumerate()
+        .fold(0u64, |lba, (i, &index)| {
+            lba | (u64::from(bytes[index]) << (8 * i))
+        })
+}
+
+/// 解析 18 字节的命令描述,全零时返回 `None`
+fn parse_command(raw: &[u8]) -> Option<SmartErrorCommand> {
+    if raw.iter().all(|&b| b == 0) {
+        return None;
+    }
+
+    Some(SmartErrorCommand {
+        device_control: raw[0],
+        features: read_le(&raw[1..3]) as u16,
+        count: read_le(&raw[3..5]) as u16,
+        lba: read_lba(&raw[5..11]),
+        device: raw[11],
+        command: raw[12],
+        timestamp_ms: read_le(&raw[14..18]) as u32,
+    })
+}
+
+/// 解析 124 字节的错误条目 (ACS-4 扩展错误数据结构),未使用的条目返回 `None`
+fn parse_entry(raw: &[u8], error_number: u32) -> Option<SmartErrorLogEntry> {
+    if raw.iter().all(|&b| b == 0) {
+        return None;
+    }
+
+    // 命令描述按时间倒序存放,第一个为出错的命令
+    let mut commands: Vec<_> = raw[..90]
+        .chunks_exact(18)
+        .filter_map(parse_command)
+        .collect();
+    commands.reverse();
+
+    let error = &raw[90..];
+    Some(SmartErrorLogEntry {
+        error_number,
+        lifetime_hours: read_le(&error[32..34]) as u16,
+        state: error[31],
+        error: error[1],
+        status: error[11],
+        count: read_le(&error[2..4]) as u16,
+        lba: read_lba(&error[4..10]),
+        device: error[10],
+        commands,
+    })
+}
+
+/// 从日志页中解析错误条目
+///
+/// `read_page` 按页号读取日志页,条目从最近的错误开始倒序排列,
+/// 最多返回 [`MAX_ENTRIES`] 条
+fn parse_ext_error_log<F>(page_count: u16, mut read_page: F) -> Result<Vec<SmartErrorLogEntry>>
+where
+    F: FnMut(u16) -> Result<Vec<u8>>,
+{
+    let mut pages: HashMap<u16, Vec<u8>> = HashMap::new();
+    let first = read_page(0)?;
+
+    // 字节 2-3 为最近错误的条目索引 (从 1 开始),字节 500-501 为设备错误总数
+    let index = read_le(&first[2..4]) as usize;
+    let error_count = read_le(&first[500..502]) as u32;
+    pages.insert(0, first);
+
+    let capacity = usize::from(page_count) * ENTRIES_PER_PAGE;
+    if index == 0 || index > capacity {
+        return Ok(Vec::new());
+    }
+
+    let total = capacity.min(MAX_ENTRIES).min(error_count as usize);
+    let mut entries = Vec::with_capacity(total);
+
+    for i in 0..total {
+        // 条目循环存放,从最近的错误往前回绕
+        let slot = (index - 1 + capacity - i) % capacity;
+        let page_number = (slot / ENTRIES_PER_PAGE) as u16;
+
+        let page = match pages.entry(page_number) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(read_page(page_number)?),
+        };
+
+        let offset = 4 + (slot % ENTRIES_PER_PAGE) * ENTRY_SIZE;
+        match parse_entry(&page[offset..offset + ENTRY_SIZE], error_count - i as u32) {
+            Some(entry) => entries.push(entry),
+            None => break,
+        }
+    }
+
+    Ok(entries)
+}
+
+impl Disk {
+    /// 读取扩展综合错误日志 (GPL 日志 0x03)
+    ///
+    /// 先从日志目录中查询该日志的页数,再按需读取各页。
+    /// 条目从最近的错误开始排列,最多返回 64 条。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持通用日志功能,或日志目录中该日志为 0 页
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for entry in disk.read_ext_error_log()? {
+    ///     println!(
+    ///         "错误 {}: {} 小时, LBA {}",
+    ///         entry.error_number, entry.lifetime_hours, entry.lba
+    ///     );
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn read_ext_error_log(&self) -> Result<Vec<SmartErrorLogEntry>> {
+        let page_count = self.log_ext_page_count(LOG_EXT_COMPREHENSIVE_ERROR)?;
+        if page_count == 0 {
+            return Err(Error::NotSupported(
+                "设备不支持扩展综合错误日志".to_string(),
+            ));
+        }
+
+        parse_ext_error_log(page_count, |page| {
+            self.read_log_ext_page(LOG_EXT_COMPREHENSIVE_ERROR, page)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在日志页中写入一个错误条目
+    fn write_entry(page: &mut [u8], slot: usize, lba: u8, hours: u16) {
+        let entry = &mut page[4 + slot * ENTRY_SIZE..4 + (slot + 1) * ENTRY_SIZE];
+        // 出错的命令: READ FPDMA QUEUED
+        entry[12] = 0x60;
+        entry[5] = lba;
+        entry[14..18].copy_from_slice(&1000u32.to_le_bytes());
+        // 更早的命令
+        entry[18 + 12] = 0xEC;
+        // 错误描述
+        entry[90 + 1] = 0x40; // UNC
+        entry[90 + 4] = lba;
+        entry[90 + 11] = 0x51;
+        entry[90 + 31] = 0x03;
+        entry[90 + 32..90 + 34].copy_from_slice(&hours.to_le_bytes());
+    }
+
+    #[test]
+    fn test_parse_ext_error_log() {
+        // 两页共 8 个槽位,记录了 10 个错误,最近的在第 2 个槽位 (索引 2)
+        let mut pages = [vec![0u8; 512], vec![0u8; 512]];
+        for slot in 0..8 {
+            let (page, slot_in_page) = (slot / 4, slot % 4);
+            write_entry(
+                &mut pages[page],
+                slot_in_page,
+                slot as u8 + 1,
+                100 + slot as u16,
+            );
+        }
+        pages[0][2..4].copy_from_slice(&2u16.to_le_bytes());
+        pages[0][500..502].copy_from_slice(&10u16.to_le_bytes());
+
+        let mut reads = Vec::new();
+        let entries = parse_ext_error_log(2, |page| {
+            reads.push(page);
+            Ok(pages[page as usize].clone())
+        })
+        .unwrap();
+
+        assert_eq!(entries.len(), 8);
+        assert_eq!(entries[0].error_number, 10);
+        assert_eq!(entries[0].lba, 2);
+        assert_eq!(entries[0].lifetime_hours, 101);
+        assert_eq!(entries[0].error, 0x40);
+        assert_eq!(entries[0].status, 0x51);
+        assert_eq!(entries[0].state, 0x03);
+        assert_eq!(entries[0].commands.len(), 2);
+        assert_eq!(entries[0].commands[0].command, 0xEC);
+        assert_eq!(entries[0].commands[1].command, 0x60);
+        assert_eq!(entries[0].commands[1].timestamp_ms, 1000);
+        // 回绕到第二页的最后一个槽位
+        assert_eq!(entries[2].lba, 8);
+        assert_eq!(entries[7].error_number, 3);
+        // 每页只读取一次
+        assert_eq!(reads, [0, 1]);
+    }
+
+    #[test]
+    fn test_parse_ext_error_log_multibyte_lba() {
+        let mut page = vec![0u8; 512];
+        write_entry(&mut page, 0, 0, 10);
+        // LBA 0x0000_1234_5678 按交错顺序存放
+        let lba = [0x78, 0x12, 0x56, 0x00, 0x34, 0x00];
+        page[4 + 5..4 + 11].copy_from_slice(&lba);
+        page[4 + 90 + 4..4 + 90 + 10].copy_from_slice(&lba);
+        page[2..4].copy_from_slice(&1u16.to_le_bytes());
+        page[500..502].copy_from_slice(&1u16.to_le_bytes());
+
+        let entries = parse_ext_error_log(1, |_| Ok(page.clone())).unwrap();
+        assert_eq!(entries[0].lba, 0x0000_1234_5678);
+        assert_eq!(entries[0].commands[1].lba, 0x0000_1234_5678);
+        assert_eq!(read_lba(&[1, 2, 3, 4, 5, 6]), 0x0604_0205_0301);
+    }
+
+    #[test]
+    fn test_parse_ext_error_log_stops_at_empty_entry() {
+        let mut page = vec![0u8; 512];
+        write_entry(&mut page, 0, 1, 10);
+        page[2..4].copy_from_slice(&1u16.to_le_bytes());
+        page[500..502].copy_from_slice(&1u16.to_le_bytes());
+
+        let entries = parse_ext_error_log(1, |_| Ok(page.clone())).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].error_number, 1);
+
+        // 没有错误
+        let entries = parse_ext_error_log(1, |_| Ok(vec![0u8; 512])).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_read_ext_error_log_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.read_ext_error_log(),
+            Err(Error::NotSupported(_))
+        ));
+    }
+}
@@ -0,0 +1,479 @@
+//! 通用日志 (GPL) 读写
+
+use crate::disk::Disk;
+use crate::error::{Error, Result};
+use crate::ffi;
+use crate::types::{DiskType, PhyEventCounter, SmartLogDirectory};
+
+/// 日志目录的日志地址
+const LOG_DIRECTORY: u8 = 0x00;
+
+/// SATA Phy 事件计数器日志地址
+const LOG_SATA_PHY_EVENT_COUNTERS: u8 = 0x11;
+
+/// 主机厂商特定日志的地址范围,任何软件都可以读写
+const LOG_HOST_VENDOR_SPECIFIC: std::ops::RangeInclusive<u8> = 0x80..=0x9F;
+
+/// 检查日志地址是否为主机厂商特定日志
+fn check_host_vendor_log(log_address: u8) -> Result<()> {
+    if !LOG_HOST_VENDOR_SPECIFIC.contains(&log_address) {
+        return Err(Error::InvalidData(format!(
+            "日志地址 0x{:02X} 不是主机厂商特定日志 (0x80-0x9F)",
+            log_address
+        )));
+    }
+    Ok(())
+}
+
+/// 从日志目录中取出指定日志的页数
+///
+/// 日志目录的第 N 个字 (小端序) 为日志地址 N 的页数,
+/// 字 0 为目录版本号
+fn log_page_count(directory: &[u8], log_address: u8) -> u16 {
+    let offset = log_address as usize * 2;
+    u16::from_le_bytes([directory[offset], directory[offset + 1]])
+}
+
+/// 解析 SMART 日志目录
+///
+/// 格式与 GPL 日志目录相同,字 0 为版本号,字 N 为日志地址 N 的扇区数
+fn parse_log_directory(data: &[u8]) -> SmartLogDirectory {
+    let logs = (1..=u8::MAX)
+        .map(|address| (address, log_page_count(data, address)))
+        .filter(|&(_, count)| count > 0)
+        .collect();
+
+    SmartLogDirectory {
+        version: log_page_count(data, LOG_DIRECTORY),
+        logs,
+    }
+}
+
+/// 解析 SATA Phy 事件计数器日志
+///
+/// 从字节 4 开始,每个计数器为一个 ID 字 (bit 14:12 为计数值的字数)
+/// 加上对应长度的计数值,ID 为 0 时结束
+fn parse_phy_event_counters(data: &[u8]) -> Result<Vec<PhyEventCounter>> {
+    let mut counters = Vec::new();
+    let mut offset = 4;
+
+    while offset + 2 <= data.len() {
+        let word = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let id = word & 0x8FFF;
+        if id == 0 {
+            break;
+        }
+        offset += 2;
+
+        let size = usize::from((word >> 12) & 0x7) * 2;
+        if !(2..=8).contains(&size) || offset + size > data.len() {
+            return Err(Error::InvalidData(format!(
+                "Phy 事件计数器 0x{:03X} 的长度无效: {} 字节",
+                id, size
+            )));
+        }
+
+        let mut bytes = [0u8; 8];
+        bytes[..size].copy_from_slice(&data[offset..offset + size]);
+        let value = u64::from_le_bytes(bytes);
+        let max = u64::MAX >> (64 - size * 8);
+        offset += size;
+
+        counters.push(PhyEventCounter {
+            id,
+            value,
+            saturated: value == max,
+        });
+    }
+
+    Ok(counters)
+}
+
+impl Disk {
+    /// 读取 SATA Phy 事件计数器 (GPL 日志 0x11)
+    ///
+    /// 配合 [`crate::SataInfo`] 使用,可以判断 CRC 错误是否伴随链路降速。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持通用日志功能
+    /// * `Err(Error::InvalidData)` - 设备没有该日志或日志格式错误
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for counter in disk.sata_phy_event_counters()? {
+    ///     if counter.is_crc_error() && counter.value > 0 {
+    ///         println!("0x{:03X}: {}", counter.id, counter.value);
+    ///     }
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn sata_phy_event_counters(&self) -> Result<Vec<PhyEventCounter>> {
+        let data = self.smart_read_log_ext(LOG_SATA_PHY_EVENT_COUNTERS, 0)?;
+        parse_phy_event_counters(&data)
+    }
+
+    /// 读取 SMART 日志 (SMART READ LOG)
+    ///
+    /// 发送 SMART READ LOG (0xD5) 命令,读取指定日志地址的第一个扇区。
+    /// 与 [`Disk::smart_read_log_ext`] 使用的 GPL 不同,这是 SMART 功能集
+    /// 自带的旧式日志接口,日志地址空间独立,只支持 28 位命令,
+    /// 不支持 GPL 的设备也可以使用。例如 SMART 日志 0x01 为摘要错误日志,
+    /// 而 GPL 中对应的是扩展错误日志 0x03。
+    ///
+    /// # 参数
+    ///
+    /// * `log_address` - 日志地址,例如 0x01 (摘要错误日志)、0x06 (自检日志)
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持 SMART
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// // 自检日志
+    /// let log = disk.smart_read_log(0x06)?;
+    /// println!("自检日志版本: {}", u16::from_le_bytes([log[0], log[1]]));
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_read_log(&self, log_address: u8) -> Result<[u8; 512]> {
+        // Blob类型不支持
+        if self.disk_type() == DiskType::Blob {
+            return Err(Error::NotSupported("Blob类型不支持读取日志".to_string()));
+        }
+
+        // 避免唤醒睡眠中的设备
+        self.ensure_awake()?;
+
+        // 检查SMART是否可用
+        let identify = self.read_identify_cached()?;
+        if !Self::is_smart_available(&identify)? {
+            return Err(Error::NotSupported("SMART功能不可用".to_string()));
+        }
+
+        let mut data = [0u8; 512];
+        let mut registers = ffi::commands::AtaRegisters::new();
+        registers.set_features(ffi::ata::SmartCommand::ReadLog as u8);
+        registers.set_lba_low(log_address);
+        registers.set_lba_mid(0x4F);
+        registers.set_lba_high(0xC2);
+        registers.set_sector_count(1);
+
+        self.send_command(
+            ffi::ata::AtaCommand::Smart,
+            ffi::ata::Direction::In,
+            &mut registers,
+            Some(&mut data),
+        )?;
+
+        Ok(data)
+    }
+
+    /// 读取 SMART 日志目录 (SMART READ LOG 日志地址 0x00)
+    ///
+    /// 返回设备支持的 SMART 日志地址及其扇区数,参见 [`Disk::smart_read_log`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// for (address, sectors) in disk.smart_read_log_directory()?.logs {
+    ///     println!("日志 0x{:02X}: {} 扇区", address, sectors);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_read_log_directory(&self) -> Result<SmartLogDirectory> {
+        let data = self.smart_read_log(LOG_DIRECTORY)?;
+        Ok(parse_log_directory(&data))
+    }
+
+    /// 读取通用日志 (GPL) 页
+    ///
+    /// 发送 READ LOG EXT (0x2F) 命令,读取指定日志地址的一页 (512 字节)。
+    /// 读取前会先查询日志目录,确认页号在该日志的页数范围内。
+    /// 页号大于 255 时需要 16 字节 ATA Passthrough。
+    ///
+    /// # 参数
+    ///
+    /// * `log_address` - 日志地址,例如 0x03 (扩展错误日志)、0x04 (设备统计)
+    /// * `page_number` - 页号,从 0 开始
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NotSupported)` - 设备不支持通用日志功能
+    /// * `Err(Error::InvalidData)` - 页号超出该日志的页数
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// // 设备统计日志的第 1 页 (通用统计)
+    /// let page = disk.smart_read_log_ext(0x04, 1)?;
+    /// assert_eq!(page.len(), 512);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_read_log_ext(&self, log_address: u8, page_number: u16) -> Result<Vec<u8>> {
+        let page_count = self.log_ext_page_count(log_address)?;
+        if page_number >= page_count {
+            return Err(Error::InvalidData(format!(
+                "日志 0x{:02X} 只有 {} 页,无法读取第 {} 页",
+                log_address, page_count, page_number
+            )));
+        }
+
+        self.read_log_ext_page(log_address, page_number)
+    }
+
+    /// 读取主机厂商特定日志 (0x80-0x9F) 的第一页
+    ///
+    /// 这些日志由主机软件自由使用,设备只负责保存,
+    /// 可以配合 [`Disk::write_vendor_log`] 在磁盘上持久保存少量元数据,
+    /// 例如监控程序最近一次检查的时间。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::InvalidData)` - 日志地址不在 0x80-0x9F 范围内
+    /// * `Err(Error::NotSupported)` - 设备不支持通用日志功能或该日志
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let page = disk.read_vendor_log(0x80)?;
+    /// println!("{:02X?}", &page[..16]);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn read_vendor_log(&self, log_address: u8) -> Result<[u8; 512]> {
+        check_host_vendor_log(log_address)?;
+        self.ensure_host_vendor_log(log_address)?;
+
+        let data = self.read_log_ext_page(log_address, 0)?;
+        let mut page = [0u8; 512];
+        page.copy_from_slice(&data);
+        Ok(page)
+    }
+
+    /// 写入主机厂商特定日志 (0x80-0x9F) 的第一页
+    ///
+    /// 发送 WRITE LOG EXT (0x3F) 命令,需要以读写方式打开磁盘
+    /// (参见 [`Disk::open_read_write`])。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::InvalidData)` - 日志地址不在 0x80-0x9F 范围内
+    /// * `Err(Error::NotSupported)` - 磁盘以只读方式打开,或设备不支持通用日志功能或该日志
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open_read_write("/dev/sda")?;
+    /// let mut page = [0u8; 512];
+    /// page[..8].copy_from_slice(&1_700_000_000u64.to_le_bytes());
+    /// disk.write_vendor_log(0x80, &page)?;
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn write_vendor_log(&self, log_address: u8, data: &[u8; 512]) -> Result<()> {
+        check_host_vendor_log(log_address)?;
+        self.ensure_writable()?;
+        self.ensure_host_vendor_log(log_address)?;
+
+        let mut data = *data;
+        let mut registers = ffi::commands::AtaRegisters::new();
+        registers.set_lba48(u64::from(log_address));
+        registers.set_sector_count(1);
+
+        self.send_command(
+            ffi::ata::AtaCommand::WriteLogExt,
+            ffi::ata::Direction::Out,
+            &mut registers,
+            Some(&mut data),
+        )
+    }
+
+    /// 检查设备是否提供指定的主机厂商特定日志
+    fn ensure_host_vendor_log(&self, log_address: u8) -> Result<()> {
+        if self.log_ext_page_count(log_address)? == 0 {
+            return Err(Error::NotSupported(format!(
+                "设备不支持日志 0x{:02X}",
+                log_address
+            )));
+        }
+        Ok(())
+    }
+
+    /// 查询通用日志的页数
+    ///
+    /// 检查设备是否支持通用日志功能,然后从日志目录中读取页数
+    pub(crate) fn log_ext_page_count(&self, log_address: u8) -> Result<u16> {
+        // Blob类型不支持
+        if self.disk_type() == DiskType::Blob {
+            return Err(Error::NotSupported("Blob类型不支持读取日志".to_string()));
+        }
+
+        // 避免唤醒睡眠中的设备
+        self.ensure_awake()?;
+
+        // IDENTIFY 字 84 bit 5 表示支持通用日志功能,不支持的设备会中止 READ LOG EXT
+        let identify = self.read_identify_cached()?.parse()?;
+        if !identify.features.gpl.is_supported() {
+            return Err(Error::NotSupported("设备不支持通用日志功能".to_string()));
+        }
+
+        // 早于 ATA/ATAPI-6 的设备可能错误地设置了上述位
+        if let Some(version) = identify.ata_version {
+            if !version.supports_gpl() {
+                return Err(Error::NotSupported(format!(
+                    "{} 设备不支持通用日志功能",
+                    version.name()
+                )));
+            }
+        }
+
+        // 日志目录只有一页,其他日志的页数从目录中读取
+        if log_address == LOG_DIRECTORY {
+            return Ok(1);
+        }
+
+        let directory = self.read_log_ext_page(LOG_DIRECTORY, 0)?;
+        Ok(log_page_count(&directory, log_address))
+    }
+
+    /// 发送 READ LOG EXT 读取一页,不做任何检查
+    pub(crate) fn read_log_ext_page(&self, log_address: u8, page_number: u16) -> Result<Vec<u8>> {
+        let mut data = vec![0u8; 512];
+        let mut registers = ffi::commands::AtaRegisters::new();
+
+        // LBA 7:0 为日志地址, 15:8 为页号低 8 位, 39:32 为页号高 8 位
+        let page = u64::from(page_number);
+        registers.set_lba48(u64::from(log_address) | ((page & 0xFF) << 8) | ((page >> 8) << 32));
+        registers.set_sector_count(1);
+
+        self.send_command(
+            ffi::ata::AtaCommand::ReadLogExt,
+            ffi::ata::Direction::In,
+            &mut registers,
+            Some(&mut data),
+        )?;
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_page_count() {
+        let mut directory = [0u8; 512];
+        directory[0] = 0x01; // 版本号
+        directory[0x04 * 2] = 8; // 设备统计日志 8 页
+        directory[0x03 * 2..0x03 * 2 + 2].copy_from_slice(&300u16.to_le_bytes());
+
+        assert_eq!(log_page_count(&directory, 0x04), 8);
+        assert_eq!(log_page_count(&directory, 0x03), 300);
+        assert_eq!(log_page_count(&directory, 0xE0), 0);
+    }
+
+    #[test]
+    fn test_parse_log_directory() {
+        let mut directory = [0u8; 512];
+        directory[0] = 0x01;
+        directory[2] = 1; // 摘要错误日志 (0x01)
+        directory[0x06 * 2] = 1; // 自检日志
+        directory[0x80 * 2] = 16; // 主机特定日志
+
+        let parsed = parse_log_directory(&directory);
+        assert_eq!(parsed.version, 1);
+        assert_eq!(parsed.logs, [(0x01, 1), (0x06, 1), (0x80, 16)]);
+        assert_eq!(parsed.sector_count(0x80), 16);
+        assert_eq!(parsed.sector_count(0x03), 0);
+    }
+
+    #[test]
+    fn test_read_log_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.smart_read_log(0x06),
+            Err(Error::NotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_phy_event_counters() {
+        let mut data = [0u8; 512];
+        // 0x001: 32 位, 值 5
+        data[4..6].copy_from_slice(&0x2001u16.to_le_bytes());
+        data[6..10].copy_from_slice(&5u32.to_le_bytes());
+        // 0x00A: 16 位, 饱和
+        data[10..12].copy_from_slice(&0x100Au16.to_le_bytes());
+        data[12..14].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        // 厂商特定 0x8005: 64 位
+        data[14..16].copy_from_slice(&0xC005u16.to_le_bytes());
+        data[16..24].copy_from_slice(&7u64.to_le_bytes());
+
+        let counters = parse_phy_event_counters(&data).unwrap();
+        assert_eq!(counters.len(), 3);
+        assert_eq!(counters[0].id, 0x001);
+        assert_eq!(counters[0].value, 5);
+        assert!(counters[0].is_crc_error());
+        assert!(!counters[0].saturated);
+        assert_eq!(counters[1].id, 0x00A);
+        assert!(counters[1].saturated);
+        assert_eq!(counters[2].id, 0x8005);
+        assert_eq!(counters[2].value, 7);
+        assert_eq!(counters[2].description(), None);
+
+        // 长度字段为 0
+        let mut data = [0u8; 512];
+        data[4..6].copy_from_slice(&0x0001u16.to_le_bytes());
+        assert!(parse_phy_event_counters(&data).is_err());
+    }
+
+    #[test]
+    fn test_vendor_log_address_range() {
+        let disk = Disk::from_blob().unwrap();
+        for address in [0x00, 0x7F, 0xA0, 0xE0] {
+            assert!(matches!(
+                disk.read_vendor_log(address),
+                Err(Error::InvalidData(_))
+            ));
+            assert!(matches!(
+                disk.write_vendor_log(address, &[0u8; 512]),
+                Err(Error::InvalidData(_))
+            ));
+        }
+
+        assert!(check_host_vendor_log(0x80).is_ok());
+        assert!(check_host_vendor_log(0x9F).is_ok());
+        assert!(matches!(
+            disk.read_vendor_log(0x80),
+            Err(Error::NotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_log_ext_blob() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(matches!(
+            disk.smart_read_log_ext(0x04, 0),
+            Err(Error::NotSupported(_))
+        ));
+    }
+}
@@ -3,10 +3,19 @@
 pub mod attributes;
 pub mod blob;
 pub mod data;
+pub mod error_log;
+pub mod log;
 pub mod parse;
+pub mod recovery;
+pub mod scoring;
 pub mod statistics;
+pub mod vendor;
 
+pub use attributes::{attribute_info, AttributeId};
 pub use blob::{identify_from_blob, read_blob_from_file, smart_info_from_blob, BlobData};
+pub use recovery::{estimate_failure_risk, failure_risk_score};
+pub use scoring::health_score;
+pub use vendor::detect_vendor;
 
 pub(crate) use attributes::*;
 pub(crate) use data::*;
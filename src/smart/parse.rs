@@ -1,12 +1,17 @@
 //! SMART 数据解析
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::types::*;
+use crate::utils::verify_ata_checksum;
 
 /// 解析 SMART 数据
 ///
 /// 从 512 字节的 SMART 数据中解析出结构化信息
 pub(crate) fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
+    if !verify_ata_checksum(raw) {
+        return Err(Error::InvalidData("SMART checksum mismatch".to_string()));
+    }
+
     // 解析离线数据收集状态（字节 362）
     let offline_data_collection_status = match raw[362] {
         0x00 | 0x80 => OfflineDataCollectionStatus::Never,
@@ -18,6 +23,9 @@ pub(crate) fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
         _ => OfflineDataCollectionStatus::Unknown,
     };
 
+    // 自动保存/自动离线数据收集标志（字节 362 bit 7）
+    let auto_save_enabled = (raw[362] & 0x80) != 0;
+
     // 解析自检执行状态和剩余百分比（字节 363）
     let self_test_execution_percent_remaining = (10 * (raw[363] & 0xF)) as u32;
     let self_test_execution_status = match (raw[363] >> 4) & 0xF {
@@ -42,6 +50,8 @@ pub(crate) fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
     let short_and_extended_test_available = (raw[367] & 16) != 0;
     let start_test_available = (raw[367] & 1) != 0;
     let abort_test_available = (raw[367] & 41) != 0;
+    let offline_data_collection_capability =
+        OfflineDataCollectionCapability::from_capability_byte(raw[367]);
 
     // 解析自检轮询时间（字节 372-376）
     let short_test_polling_minutes = raw[372] as u16;
@@ -60,10 +70,12 @@ pub(crate) fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
         total_offline_data_collection_seconds,
         self_test_execution_status,
         self_test_execution_percent_remaining,
+        auto_save_enabled,
         short_and_extended_test_available,
         conveyance_test_available,
         start_test_available,
         abort_test_available,
+        offline_data_collection_capability,
         short_test_polling_minutes,
         extended_test_polling_minutes,
         conveyance_test_polling_minutes,
@@ -74,6 +86,12 @@ pub(crate) fn parse_smart_data(raw: &[u8; 512]) -> Result<SmartParsedData> {
 mod tests {
     use super::*;
 
+    /// 填写校验和字节,使扇区通过校验
+    fn set_checksum(data: &mut [u8; 512]) {
+        let sum = data[..511].iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        data[511] = sum.wrapping_neg();
+    }
+
     #[test]
     fn test_parse_smart_data() {
         let mut data = [0u8; 512];
@@ -96,6 +114,7 @@ mod tests {
         data[373] = 60; // extended test: 60 分钟
         data[374] = 2; // conveyance test: 2 分钟
 
+        set_checksum(&mut data);
         let parsed = parse_smart_data(&data).unwrap();
 
         assert_eq!(
@@ -110,6 +129,33 @@ mod tests {
         assert_eq!(parsed.total_offline_data_collection_seconds, 100);
         assert_eq!(parsed.short_test_polling_minutes, 2);
         assert_eq!(parsed.extended_test_polling_minutes, 60);
+        assert!(!parsed.auto_save_enabled);
+        assert_eq!(
+            parsed.offline_data_collection_capability,
+            OfflineDataCollectionCapability::ExecuteImmediate
+        );
+    }
+
+    #[test]
+    fn test_parse_auto_save_and_capability() {
+        let mut data = [0u8; 512];
+
+        // 自动保存已启用,离线数据收集从未启动
+        data[362] = 0x80;
+        // 支持立即执行和自动离线数据收集
+        data[367] = 0x03;
+
+        set_checksum(&mut data);
+        let parsed = parse_smart_data(&data).unwrap();
+        assert!(parsed.auto_save_enabled);
+        assert_eq!(
+            parsed.offline_data_collection_status,
+            OfflineDataCollectionStatus::Never
+        );
+        assert_eq!(
+            parsed.offline_data_collection_capability,
+            OfflineDataCollectionCapability::Automatic
+        );
     }
 
     #[test]
@@ -121,7 +167,22 @@ mod tests {
         data[375] = 0x2C; // 300 分钟的低字节
         data[376] = 0x01; // 300 分钟的高字节
 
+        set_checksum(&mut data);
         let parsed = parse_smart_data(&data).unwrap();
         assert_eq!(parsed.extended_test_polling_minutes, 300);
     }
+
+    #[test]
+    fn test_parse_checksum_mismatch() {
+        let mut data = [0u8; 512];
+        data[362] = 0x82;
+        set_checksum(&mut data);
+        assert!(parse_smart_data(&data).is_ok());
+
+        data[362] ^= 0x01;
+        assert!(matches!(
+            parse_smart_data(&data),
+            Err(Error::InvalidData(msg)) if msg == "SMART checksum mismatch"
+        ));
+    }
 }
@@ -0,0 +1,121 @@
+//! 故障风险估计
+//!
+//! 根据公开的大规模硬盘故障研究,以下属性的原始值非零时与近期故障高度相关:
+//!
+//! - E. Pinheiro, W.-D. Weber, L. A. Barroso, "Failure Trends in a Large Disk
+//!   Drive Population", FAST 2007 (Google):重新分配扇区 (5)、待处理扇区 (197)、
+//!   离线不可纠正扇区 (198) 出现后,60 天内的故障率提高 14-39 倍。
+//! - Backblaze, "What SMART Stats Tell Us About Hard Drives" (2016):
+//!   属性 5、187、188、197、198 是唯一与故障持续相关的五个属性,
+//!   故障硬盘中 76.7% 至少有一项非零,正常硬盘中只有 4.2%。
+//!
+//! 评分规则为经验值,只用于排序和提示,不代表故障概率。
+
+use crate::disk::{Disk, SmartInfo};
+use crate::error::Result;
+use crate::types::{FailureRisk, SmartAttributeParsedData};
+
+/// 属性权重 (属性 ID, 分数)
+///
+/// 不可纠正错误 (187、198) 直接意味着数据丢失,权重最高;
+/// 重新分配和待处理扇区 (5、197) 次之;命令超时 (188) 也可能由线缆或电源引起
+static RISK_WEIGHTS: &[(u8, u32)] = &[(5, 2), (187, 3), (188, 1), (197, 2), (198, 3)];
+
+/// 属性的 48 位原始值
+pub(crate) fn raw_value(attr: &SmartAttributeParsedData) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes[..6].copy_from_slice(&attr.raw);
+    u64::from_le_bytes(bytes)
+}
+
+/// 计算风险分数
+///
+/// 每个原始值非零的关键属性累加对应的权重
+pub fn failure_risk_score(attributes: &[SmartAttributeParsedData]) -> u32 {
+    RISK_WEIGHTS
+        .iter()
+        .filter(|(id, _)| {
+            attributes
+                .iter()
+                .any(|attr| attr.id == *id && raw_value(attr) != 0)
+        })
+        .map(|(_, weight)| weight)
+        .sum()
+}
+
+/// 估计故障风险
+///
+/// 分数映射规则:0 为低,1-2 为中等,3-5 为高,6 及以上为严重
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::{estimate_failure_risk, Disk};
+///
+/// let disk = Disk::open("/dev/sda")?;
+/// let attributes = disk.read_smart()?.parse_attributes()?;
+/// println!("故障风险: {}", estimate_failure_risk(&attributes).as_str());
+/// # Ok::<(), libatasmart::Error>(())
+/// ```
+pub fn estimate_failure_risk(attributes: &[SmartAttributeParsedData]) -> FailureRisk {
+    FailureRisk::from_score(failure_risk_score(attributes))
+}
+
+impl SmartInfo {
+    /// 估计故障风险,参见 [`estimate_failure_risk`]
+    pub fn failure_risk(&self) -> Result<FailureRisk> {
+        Ok(estimate_failure_risk(&self.parse_attributes()?))
+    }
+}
+
+impl Disk {
+    /// 估计故障风险
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    /// 参见 [`estimate_failure_risk`]。
+    pub fn smart_get_failure_risk(&self) -> Result<FailureRisk> {
+        self.smart_info()?.failure_risk()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AttributeContext;
+
+    /// 构造原始值为 `raw` 的属性
+    fn attribute(id: u8, raw: u8) -> SmartAttributeParsedData {
+        let mut data = [0u8; 12];
+        data[0] = id;
+        data[3] = 100;
+        data[4] = 100;
+        data[5] = raw;
+        crate::smart::attributes::parse_attribute(&data, None, &AttributeContext::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_failure_risk_score() {
+        assert_eq!(estimate_failure_risk(&[]), FailureRisk::Low);
+
+        let healthy = [attribute(5, 0), attribute(187, 0), attribute(9, 100)];
+        assert_eq!(failure_risk_score(&healthy), 0);
+        assert_eq!(estimate_failure_risk(&healthy), FailureRisk::Low);
+
+        let attributes = [attribute(188, 1)];
+        assert_eq!(estimate_failure_risk(&attributes), FailureRisk::Moderate);
+
+        let attributes = [attribute(187, 2)];
+        assert_eq!(estimate_failure_risk(&attributes), FailureRisk::High);
+
+        let attributes = [attribute(5, 8), attribute(197, 1), attribute(198, 1)];
+        assert_eq!(failure_risk_score(&attributes), 7);
+        assert_eq!(estimate_failure_risk(&attributes), FailureRisk::Critical);
+    }
+
+    #[test]
+    fn test_failure_risk_ordering() {
+        assert!(FailureRisk::Low < FailureRisk::Moderate);
+        assert!(FailureRisk::High < FailureRisk::Critical);
+    }
+}
@@ -0,0 +1,221 @@
+//! 健康评分
+//!
+//! 把 SMART 自评估结果和关键属性折算为 0-100 的单个数字,便于在监控面板中展示和排序。
+//! 扣分项与 [`crate::estimate_failure_risk`] 使用同一组属性,
+//! 规则为经验值,分数不代表故障概率。
+
+use crate::disk::{Disk, SmartInfo};
+use crate::error::Result;
+use crate::smart::recovery::raw_value;
+use crate::types::SmartAttributeParsedData;
+
+/// 满分
+const MAX_SCORE: u32 = 100;
+
+/// 原始值非零时的扣分 (属性 ID, 分数)
+static ATTRIBUTE_PENALTIES: &[(u8, u32)] = &[(5, 20), (187, 10), (188, 5), (197, 15), (198, 20)];
+
+/// SMART 自评估失败的扣分
+const BAD_STATUS_PENALTY: u32 = 40;
+
+/// 每个超过阈值的属性的扣分
+const WARN_PENALTY: u32 = 5;
+
+/// 超过阈值的属性合计最多扣分
+const MAX_WARN_PENALTY: u32 = 20;
+
+/// 计算健康评分
+///
+/// 从 100 分开始扣分,结果限制在 0-100:
+///
+/// - 属性 5 (重新分配扇区) 非零: -20
+/// - 属性 197 (待处理扇区) 非零: -15
+/// - 属性 198 (离线不可纠正扇区) 非零: -20
+/// - 属性 187 (报告的不可纠正错误) 非零: -10
+/// - 属性 188 (命令超时) 非零: -5,Seagate 硬盘只看解析时解码出的超时命令总数
+///   (参见 [`SmartInfo::command_timeouts`])
+/// - SMART 自评估失败: -40
+/// - 每个 `warn` 为真的属性: -5,合计最多 -20
+///
+/// # 示例
+///
+/// ```no_run
+/// use libatasmart::{health_score, Disk};
+///
+/// let disk = Disk::open("/dev/sda")?;
+/// let attributes = disk.read_smart()?.parse_attributes()?;
+/// println!("健康评分: {}", health_score(&attributes, disk.is_healthy()?));
+/// # Ok::<(), libatasmart::Error>(())
+/// ```
+pub fn health_score(attributes: &[SmartAttributeParsedData], smart_status: bool) -> u8 {
+    let count = |attr: &SmartAttributeParsedData| match attr.id {
+        188 => attr.pretty_value,
+        _ => raw_value(attr),
+    };
+
+    let attribute_penalty: u32 = ATTRIBUTE_PENALTIES
+        .iter()
+        .filter(|(id, _)| {
+            attributes
+                .iter()
+                .any(|attr| attr.id == *id && count(attr) != 0)
+        })
+        .map(|(_, penalty)| penalty)
+        .sum();
+
+    let warnings = attributes.iter().filter(|attr| attr.warn).count() as u32;
+    let warn_penalty = warnings.saturating_mul(WARN_PENALTY).min(MAX_WARN_PENALTY);
+
+    let status_penalty = if smart_status { 0 } else { BAD_STATUS_PENALTY };
+
+    MAX_SCORE.saturating_sub(attribute_penalty + warn_penalty + status_penalty) as u8
+}
+
+impl SmartInfo {
+    /// 计算健康评分,参见 [`health_score`]
+    pub fn health_score(&self, smart_status: bool) -> Result<u8> {
+        Ok(health_score(&self.parse_attributes()?, smart_status))
+    }
+}
+
+impl Disk {
+    /// 计算健康评分
+    ///
+    /// 结合 SMART 自评估结果和属性数据,参见 [`health_score`]。
+    /// 总是与自评估结果一起重新读取 SMART 数据,参见 [`Disk::read_smart_with_status`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("健康评分: {}/100", disk.health_score()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn health_score(&self) -> Result<u8> {
+        let (smart, status_good) = self.read_smart_with_status()?;
+        smart.health_score(status_good)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AttributeContext;
+    use proptest::prelude::*;
+
+    /// 构造原始值为 `raw` 的属性
+    fn attribute(id: u8, raw: u8, warn: bool) -> SmartAttributeParsedData {
+        let mut data = [0u8; 12];
+        data[0] = id;
+        data[3] = 100;
+        data[4] = 100;
+        data[5] = raw;
+        let mut attr =
+            crate::smart::attributes::parse_attribute(&data, None, &AttributeContext::default())
+                .unwrap();
+        attr.warn = warn;
+        attr
+    }
+
+    fn arb_attribute() -> impl Strategy<Value = SmartAttributeParsedData> {
+        let ids = prop_oneof![
+            Just(5u8),
+            Just(187),
+            Just(197),
+            Just(198),
+            Just(188),
+            Just(9),
+            1..=255u8
+        ];
+        (ids, any::<u8>(), any::<bool>()).prop_map(|(id, raw, warn)| attribute(id, raw, warn))
+    }
+
+    #[test]
+    fn test_health_score() {
+        assert_eq!(health_score(&[], true), 100);
+        assert_eq!(health_score(&[], false), 60);
+
+        let healthy = [attribute(5, 0, false), attribute(9, 100, false)];
+        assert_eq!(health_score(&healthy, true), 100);
+
+        assert_eq!(health_score(&[attribute(5, 3, false)], true), 80);
+        assert_eq!(health_score(&[attribute(197, 1, false)], true), 85);
+        assert_eq!(health_score(&[attribute(198, 1, false)], true), 80);
+        assert_eq!(health_score(&[attribute(187, 1, false)], true), 90);
+        assert_eq!(health_score(&[attribute(188, 1, false)], true), 95);
+
+        // Seagate 的属性 188 只看字节 0-1 的超时命令总数
+        let mut data = [0u8; 12];
+        data[0] = 188;
+        data[3] = 100;
+        data[4] = 100;
+        data[7] = 1;
+        let parse = |model: &str| {
+            let context = AttributeContext {
+                model: Some(model.to_string()),
+                ..Default::default()
+            };
+            crate::smart::attributes::parse_attribute(&data, None, &context).unwrap()
+        };
+        assert_eq!(health_score(&[parse("WDC WD10EZEX-00BN5A0")], true), 95);
+        assert_eq!(health_score(&[parse("ST3500320AS")], true), 100);
+
+        // 同一属性重复出现只扣一次
+        let duplicated = [attribute(5, 1, false), attribute(5, 2, false)];
+        assert_eq!(health_score(&duplicated, true), 80);
+
+        // 超过阈值的属性最多扣 20 分
+        let warned: Vec<_> = (1..=6).map(|id| attribute(id, 0, true)).collect();
+        assert_eq!(health_score(&warned[..2], true), 90);
+        assert_eq!(health_score(&warned, true), 80);
+
+        let worst = [
+            attribute(5, 1, true),
+            attribute(187, 1, true),
+            attribute(197, 1, true),
+            attribute(198, 1, true),
+        ];
+        assert_eq!(health_score(&worst, false), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_health_score_in_range(
+            attributes in prop::collection::vec(arb_attribute(), 0..32),
+            status in any::<bool>(),
+        ) {
+            prop_assert!(health_score(&attributes, status) <= 100);
+        }
+
+        #[test]
+        fn prop_bad_status_never_scores_higher(
+            attributes in prop::collection::vec(arb_attribute(), 0..32),
+        ) {
+            prop_assert!(health_score(&attributes, false) <= health_score(&attributes, true));
+        }
+
+        #[test]
+        fn prop_adding_attribute_never_raises_score(
+            attributes in prop::collection::vec(arb_attribute(), 0..32),
+            extra in arb_attribute(),
+            status in any::<bool>(),
+        ) {
+            let before = health_score(&attributes, status);
+            let mut more = attributes.clone();
+            more.push(extra);
+            prop_assert!(health_score(&more, status) <= before);
+        }
+
+        #[test]
+        fn prop_order_does_not_matter(
+            attributes in prop::collection::vec(arb_attribute(), 0..32),
+            status in any::<bool>(),
+        ) {
+            let mut reversed = attributes.clone();
+            reversed.reverse();
+            prop_assert_eq!(health_score(&attributes, status), health_score(&reversed, status));
+        }
+    }
+}
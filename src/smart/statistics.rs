@@ -2,26 +2,26 @@
 //!
 //! 从 SMART 属性中提取高级统计信息
 
-use crate::disk::SmartInfo;
-use crate::types::{DiskStatistics, Duration, Temperature};
+use crate::disk::{Disk, SmartInfo};
+use crate::error::{Error, Result};
+use crate::smart::attributes::is_seagate_model;
+use crate::smart::{threshold_failure, AttributeId};
+use crate::types::{
+    AttributeFailure, AttributeUnit, DiskStatistics, DiskVendor, Duration, FailureKind,
+    OverallAssessment, Percent, SmartAttributeParsedData, SmartOverall, Temperature,
+    WearStatistics,
+};
+
+/// 命令超时次数超过该值时整体状态至少为 `BadAttributeInThePast`
+pub(crate) const COMMAND_TIMEOUT_THRESHOLD: u64 = 10;
 
 impl SmartInfo {
     /// 获取坏扇区总数
     ///
     /// 包括已重新分配的扇区和待处理的扇区
     pub fn bad_sectors(&self) -> Option<u64> {
-        let attributes = self.parse_attributes().ok()?;
-
-        let mut reallocated = None;
-        let mut pending = None;
-
-        for attr in attributes {
-            match attr.id {
-                5 => reallocated = Some(attr.pretty_value), // reallocated-sector-count
-                197 => pending = Some(attr.pretty_value),   // current-pending-sector
-                _ => {}
-            }
-        }
+        let reallocated = self.attribute_value(AttributeId::ReallocatedSectorCount);
+        let pending = self.attribute_value(AttributeId::CurrentPendingSector);
 
         match (reallocated, pending) {
             (Some(r), Some(p)) => Some(r + p),
@@ -32,49 +32,316 @@ impl SmartInfo {
     }
 
     /// 获取累计开机时间
+    ///
+    /// 属性 9 的编码方式由设备型号决定,参见 [`crate::VendorAttributeTable`]
     pub fn power_on_duration(&self) -> Option<Duration> {
-        let attributes = self.parse_attributes().ok()?;
+        self.attribute(AttributeId::PowerOnHours)
+            .filter(|attr| attr.pretty_unit == AttributeUnit::Milliseconds)
+            .map(|attr| Duration::from_millis(attr.pretty_value))
+    }
 
-        for attr in attributes {
-            if attr.id == 9 && attr.name == "power-on-hours" {
-                return Some(Duration::from_millis(attr.pretty_value));
-            }
+    /// 获取磁头飞行时间 (属性 240)
+    ///
+    /// 只有带磁头的机械硬盘才会报告该属性,SSD 没有
+    pub fn head_flying_duration(&self) -> Option<Duration> {
+        self.attribute(AttributeId::HeadFlyingHours)
+            .filter(|attr| attr.pretty_unit == AttributeUnit::Milliseconds)
+            .map(|attr| Duration::from_millis(attr.pretty_value))
+    }
+
+    /// 获取 UDMA CRC 错误数 (属性 199)
+    pub fn udma_crc_errors(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::UdmaCrcErrorCount)
+    }
+
+    /// 获取命令超时次数 (属性 188)
+    ///
+    /// Seagate 硬盘的 48 位原始值由三个 16 位计数组成:
+    /// 字节 0-1 为超时中止的命令总数,字节 2-3 和 4-5 分别为
+    /// 完成时间超过 5 秒和 7.5 秒的命令数 (smartctl 显示为 `0 0 0` 的形式),
+    /// 此时只返回字节 0-1 的总数。其他厂商直接返回 48 位原始计数。
+    /// 厂商由 [`SmartInfo::is_seagate`] 判断。
+    pub fn command_timeouts(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::CommandTimeout)
+    }
+
+    /// 读取 SMART 数据时记录的型号是否属于 Seagate
+    ///
+    /// 决定属性 1、7、188 按 Seagate 的编码解析,参见 [`SmartData::context`]。
+    /// 没有记录型号 (例如没有 IDENTIFY 数据) 时返回 `false`。
+    ///
+    /// [`SmartData::context`]: crate::SmartData::context
+    pub fn is_seagate(&self) -> bool {
+        is_seagate_model(self.data.context())
+    }
+
+    /// 获取主轴电机启停次数 (属性 4)
+    pub fn start_stop_count(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::StartStopCount)
+    }
+
+    /// 获取主轴起转重试次数 (属性 10)
+    ///
+    /// 非零值说明电机起转困难,可能是电机或供电问题
+    pub fn spin_retry_count(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::SpinRetryCount)
+    }
+
+    /// 获取磁头校准重试次数 (属性 11)
+    pub fn calibration_retry_count(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::CalibrationRetryCount)
+    }
+
+    /// 获取磁头加载/卸载次数 (属性 193)
+    pub fn load_cycle_count(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::LoadCycleCount)
+    }
+
+    /// 获取加速度传感器检测到的冲击次数 (属性 191)
+    ///
+    /// 返回 48 位原始计数。没有属性 191 时使用部分 Hitachi/Toshiba
+    /// 硬盘报告的属性 221,两者含义相同
+    pub fn g_sense_error_rate(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::GSenseErrorRate)
+            .or_else(|| self.attribute_value(AttributeId::GSenseErrorRate2))
+    }
+
+    /// 获取磁头飞行高度异常的写入次数 (属性 189)
+    ///
+    /// 主要由 Seagate 硬盘报告,返回 48 位原始计数
+    pub fn high_fly_writes(&self) -> Option<u64> {
+        self.attribute_value(AttributeId::HighFlyWrites)
+    }
+
+    /// 获取气流温度 (属性 190)
+    ///
+    /// 原始值超出合理范围时 (参见属性单位的校验) 返回 `None`
+    pub fn airflow_temperature(&self) -> Option<Temperature> {
+        self.attribute(AttributeId::AirflowTemperatureCelsius)
+            .filter(|attr| attr.pretty_unit == AttributeUnit::MilliKelvin)
+            .map(|attr| Temperature::from_millikelvin(attr.pretty_value))
+    }
+
+    /// 获取 SSD 剩余寿命 (属性 232)
+    ///
+    /// 取属性的当前值,100 表示全新
+    pub fn endurance_remaining(&self) -> Option<Percent> {
+        self.attribute_value(AttributeId::EnduranceRemaining)
+            .map(|value| Percent::new(value as f64))
+    }
+
+    /// 获取 SSD 剩余备用块比例 (属性 170)
+    pub fn available_reserved_space(&self) -> Option<Percent> {
+        self.attribute_value(AttributeId::AvailableReservedSpace)
+            .map(|value| Percent::new(value as f64))
+    }
+
+    /// 获取机械磨损统计
+    pub fn wear_statistics(&self) -> WearStatistics {
+        WearStatistics {
+            start_stop_count: self.start_stop_count(),
+            spin_retry_count: self.spin_retry_count(),
+            calibration_retry_count: self.calibration_retry_count(),
+            power_cycle_count: self.power_cycle_count(),
+            load_cycle_count: self.load_cycle_count(),
+            head_flying_duration: self.head_flying_duration(),
         }
+    }
+
+    /// 解析指定的单个属性,参见 [`crate::SmartData::attribute`]
+    fn attribute(&self, id: AttributeId) -> Option<SmartAttributeParsedData> {
+        self.data.attribute(id.as_u8(), self.thresholds.as_ref())
+    }
 
-        None
+    /// 获取指定属性的解析值
+    fn attribute_value(&self, id: AttributeId) -> Option<u64> {
+        self.attribute(id).map(|attr| attr.pretty_value)
     }
 
     /// 获取电源循环次数
     pub fn power_cycle_count(&self) -> Option<u64> {
-        let attributes = self.parse_attributes().ok()?;
+        self.attribute_value(AttributeId::PowerCycleCount)
+    }
 
-        for attr in attributes {
-            if attr.id == 12 && attr.name == "power-cycle-count" {
-                return Some(attr.pretty_value);
-            }
+    /// 获取温度
+    ///
+    /// 依次查找属性 194、190、231,使用第一个存在且读数在合理范围内的
+    pub fn temperature(&self) -> Option<Temperature> {
+        [
+            AttributeId::TemperatureCelsius2,
+            AttributeId::AirflowTemperatureCelsius,
+            AttributeId::TemperatureCelsius,
+        ]
+        .into_iter()
+        .find_map(|id| {
+            self.attribute(id)
+                .filter(|attr| attr.pretty_unit == AttributeUnit::MilliKelvin)
+        })
+        .map(|attr| Temperature::from_millikelvin(attr.pretty_value))
+    }
+
+    /// 获取寻道错误率 (属性 7)
+    ///
+    /// Seagate 硬盘的 48 位原始值并不是简单的计数:
+    /// 高 16 位 (字节 4-5) 为寻道错误次数,低 32 位 (字节 0-3) 为寻道总次数,
+    /// 此时返回 `错误次数 / 寻道总次数`。其他厂商直接返回原始计数。
+    /// 厂商由 [`SmartInfo::is_seagate`] 判断。
+    pub fn seek_error_rate(&self) -> Option<f64> {
+        self.error_rate(AttributeId::SeekErrorRate)
+    }
+
+    /// 获取读取错误率 (属性 1)
+    ///
+    /// Seagate 硬盘的编码与寻道错误率 (属性 7) 相同:
+    /// 高 16 位 (字节 4-5) 为读取错误次数,低 32 位 (字节 0-3) 为读取的扇区数,
+    /// 此时返回 `错误次数 / 扇区数`。低 32 位在每次开机后从零开始计数,
+    /// 因此这个值只反映本次开机以来的错误率。其他厂商直接返回 48 位原始计数。
+    /// 厂商由 [`SmartInfo::is_seagate`] 判断。
+    ///
+    /// 编码方式参见 smartmontools 的 drivedb.h 中 Seagate 条目对属性 1
+    /// (`-v 1,raw48:54`) 的说明。
+    pub fn read_error_rate(&self) -> Option<f64> {
+        self.error_rate(AttributeId::RawReadErrorRate)
+    }
+
+    /// 按 Seagate 的错误率编码解析属性原始值
+    fn error_rate(&self, id: AttributeId) -> Option<f64> {
+        let attr = self.attribute(id)?;
+
+        let raw = u64::from_le_bytes([
+            attr.raw[0],
+            attr.raw[1],
+            attr.raw[2],
+            attr.raw[3],
+            attr.raw[4],
+            attr.raw[5],
+            0,
+            0,
+        ]);
+
+        if !self.is_seagate() {
+            return Some(raw as f64);
         }
 
-        None
+        let errors = (raw >> 32) & 0xFFFF;
+        let seeks = raw & 0xFFFFFFFF;
+        if seeks == 0 {
+            Some(0.0)
+        } else {
+            Some(errors as f64 / seeks as f64)
+        }
     }
 
-    /// 获取温度
-    pub fn temperature(&self) -> Option<Temperature> {
-        let attributes = self.parse_attributes().ok()?;
-
-        // 优先查找常见的温度属性
-        for attr in attributes {
-            match attr.id {
-                194 | 190 | 231 => {
-                    // temperature-celsius-2, airflow-temperature-celsius, temperature-celsius
-                    if attr.name.contains("temperature") {
-                        return Some(Temperature::from_millikelvin(attr.pretty_value));
-                    }
-                }
-                _ => {}
-            }
+    /// 计算整体健康状态
+    ///
+    /// 与 libatasmart 的判定规则相同,按以下顺序检查:
+    /// SMART 自评估、大量坏扇区 (超过 `log2(扇区数) * 1024`)、
+    /// 当前超过阈值的预失败属性、坏扇区、过去超过阈值的预失败属性。
+    /// 此外,命令超时次数 (属性 188) 超过 10 次时视为过去存在问题的属性。
+    ///
+    /// # 参数
+    ///
+    /// * `status_good` - SMART RETURN STATUS 的结果,参见 [`Disk::is_healthy`]
+    pub fn overall(&self, status_good: bool) -> SmartOverall {
+        self.overall_detailed(status_good).overall
+    }
+
+    /// 计算整体健康状态及判定依据
+    ///
+    /// 判定规则参见 [`SmartInfo::overall`],不会因为前面的条件成立而跳过后面的检查,
+    /// 因此返回所有异常项。
+    ///
+    /// # 参数
+    ///
+    /// * `status_good` - SMART RETURN STATUS 的结果,参见 [`Disk::is_healthy`]
+    pub fn overall_detailed(&self, status_good: bool) -> OverallAssessment {
+        self.overall_detailed_with_crc_threshold(status_good, None)
+    }
+
+    /// 计算整体健康状态及判定依据,
+    /// UDMA CRC 错误数超过 `crc_threshold` 时至少为 `BadAttributeNow`
+    pub(crate) fn overall_detailed_with_crc_threshold(
+        &self,
+        status_good: bool,
+        crc_threshold: Option<u64>,
+    ) -> OverallAssessment {
+        let reallocated_sectors = self.attribute_value(AttributeId::ReallocatedSectorCount);
+        let pending_sectors = self.attribute_value(AttributeId::CurrentPendingSector);
+        let sectors = self.bad_sectors().unwrap_or(0);
+        let sector_count = self.data.context().sector_count();
+        let many_sectors = sector_count > 0 && sectors > u64::from(sector_count.ilog2()) * 1024;
+
+        // 坏扇区属性同样按阈值检查,坏扇区计数单独记录
+        let attributes: Vec<AttributeFailure> = self
+            .parse_attributes()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|attr| attr.prefailure)
+            .filter_map(|attr| {
+                let kind = threshold_failure(&attr)?;
+                Some(AttributeFailure {
+                    threshold: Some(attr.threshold),
+                    prefailure: true,
+                    kind,
+                    attribute: attr,
+                })
+            })
+            .collect();
+        let has_failure = |kind| attributes.iter().any(|failure| failure.kind == kind);
+
+        let command_timeouts = self
+            .command_timeouts()
+            .filter(|&timeouts| timeouts > COMMAND_TIMEOUT_THRESHOLD);
+        let udma_crc_errors = match (crc_threshold, self.udma_crc_errors()) {
+            (Some(threshold), Some(errors)) if errors > threshold => Some(errors),
+            _ => None,
+        };
+
+        let overall = if !status_good {
+            SmartOverall::BadStatus
+        } else if many_sectors {
+            SmartOverall::BadSectorMany
+        } else if has_failure(FailureKind::ThresholdNow) {
+            SmartOverall::BadAttributeNow
+        } else if sectors > 0 {
+            SmartOverall::BadSector
+        } else if has_failure(FailureKind::ThresholdInThePast) || command_timeouts.is_some() {
+            SmartOverall::BadAttributeInThePast
+        } else {
+            SmartOverall::Good
+        };
+        let overall = match udma_crc_errors {
+            Some(_) => SmartOverall::worst(overall, SmartOverall::BadAttributeNow),
+            None => overall,
+        };
+
+        let failed_self_test = self
+            .data
+            .parse()
+            .ok()
+            .map(|parsed| parsed.self_test_execution_status)
+            .filter(|status| status.is_failure());
+
+        OverallAssessment {
+            overall,
+            smart_status: status_good,
+            reallocated_sectors,
+            pending_sectors,
+            attributes,
+            command_timeouts,
+            udma_crc_errors,
+            failed_self_test,
         }
+    }
 
-        None
+    /// 计算整体健康状态,UDMA CRC 错误数超过 `crc_threshold` 时至少为 `BadAttributeNow`
+    pub(crate) fn overall_with_crc_threshold(
+        &self,
+        status_good: bool,
+        crc_threshold: Option<u64>,
+    ) -> SmartOverall {
+        self.overall_detailed_with_crc_threshold(status_good, crc_threshold)
+            .overall
     }
 
     /// 获取所有统计信息
@@ -88,13 +355,693 @@ impl SmartInfo {
     }
 }
 
+impl Disk {
+    /// 获取整体健康状态
+    ///
+    /// 结合 SMART 自评估结果和属性数据,参见 [`SmartInfo::overall`]。
+    /// 设置了 [`Disk::set_crc_error_threshold`] 时同时检查 UDMA CRC 错误数。
+    /// 需要判定依据时使用 [`Disk::smart_get_overall_detailed`]。
+    /// 总是与自评估结果一起重新读取 SMART 数据,参见 [`Disk::read_smart_with_status`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let overall = disk.smart_get_overall()?;
+    /// println!("整体状态: {}", overall.as_str());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_overall(&self) -> Result<SmartOverall> {
+        Ok(self.smart_get_overall_detailed()?.overall)
+    }
+
+    /// 获取整体健康状态及判定依据
+    ///
+    /// 除 [`Disk::smart_get_overall`] 的结果外,还包含坏扇区数、超过阈值的预失败属性、
+    /// SMART 自评估结果和上次自检结果,参见 [`OverallAssessment`]。
+    /// 总是与自评估结果一起重新读取 SMART 数据,参见 [`Disk::read_smart_with_status`]。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::{Disk, SmartOverall};
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let assessment = disk.smart_get_overall_detailed()?;
+    /// if assessment.overall != SmartOverall::Good {
+    ///     println!("{}: {}", assessment.overall.as_str(), assessment);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_overall_detailed(&self) -> Result<OverallAssessment> {
+        let (smart, status_good) = self.read_smart_with_status()?;
+        Ok(smart.overall_detailed_with_crc_threshold(status_good, self.crc_error_threshold()))
+    }
+
+    /// 获取当前温度
+    ///
+    /// 参见 [`SmartInfo::temperature`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告温度属性
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let temperature = disk.smart_get_temperature()?;
+    /// println!("温度: {} ({:.1}°F)", temperature, temperature.fahrenheit());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_temperature(&self) -> Result<Temperature> {
+        self.smart_info()?.temperature().ok_or(Error::NoData)
+    }
+
+    /// 获取累计通电时间 (属性 9)
+    ///
+    /// 已按型号的编码方式换算,参见 [`SmartInfo::power_on_duration`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 9
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let power_on = disk.smart_get_power_on()?;
+    /// println!("通电时间: {} ({} 小时)", power_on, power_on.as_hours());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_power_on(&self) -> Result<Duration> {
+        self.smart_info()?.power_on_duration().ok_or(Error::NoData)
+    }
+
+    /// 获取 UDMA CRC 错误数 (属性 199)
+    ///
+    /// 该计数反映主机与设备之间传输数据时的校验错误,与盘片介质无关。
+    /// 任何非零值都值得怀疑,即使只有一次错误也可能说明数据线损坏、
+    /// 接触不良或控制器存在间歇性故障。计数不会清零,更换数据线后应观察是否继续增长。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 199
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let errors = disk.smart_get_udma_crc_errors()?;
+    /// if errors > 0 {
+    ///     println!("UDMA CRC 错误 {} 次,请检查数据线", errors);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_udma_crc_errors(&self) -> Result<u64> {
+        self.smart_info()?.udma_crc_errors().ok_or(Error::NoData)
+    }
+
+    /// 获取命令超时次数 (属性 188)
+    ///
+    /// 超时通常由控制器、数据线或供电问题引起。
+    /// Seagate 硬盘只返回超时命令总数,参见 [`SmartInfo::command_timeouts`]。
+    /// 厂商由读取 SMART 数据时记录的型号判断,不会额外发送 IDENTIFY。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 188
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("命令超时: {} 次", disk.smart_get_command_timeout()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_command_timeout(&self) -> Result<u64> {
+        let smart = self.smart_info()?;
+
+        smart.command_timeouts().ok_or(Error::NoData)
+    }
+
+    /// 获取寻道错误率 (属性 7)
+    ///
+    /// Seagate 硬盘按厂商编码解析,参见 [`SmartInfo::seek_error_rate`]。
+    /// 厂商由读取 SMART 数据时记录的型号判断。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 7
+    pub fn smart_get_seek_error_rate(&self) -> Result<f64> {
+        let smart = self.smart_info()?;
+
+        smart.seek_error_rate().ok_or(Error::NoData)
+    }
+
+    /// 获取读取错误率 (属性 1)
+    ///
+    /// Seagate 硬盘返回错误次数与读取扇区数之比,
+    /// 其他厂商返回原始计数,参见 [`SmartInfo::read_error_rate`]。
+    /// 厂商由读取 SMART 数据时记录的型号判断。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 1
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("读取错误率: {}", disk.smart_get_read_error_rate()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_read_error_rate(&self) -> Result<f64> {
+        let smart = self.smart_info()?;
+
+        smart.read_error_rate().ok_or(Error::NoData)
+    }
+
+    /// 获取磁头飞行时间 (属性 240)
+    ///
+    /// 只统计磁头实际处于工作状态的时间,比开机时间更能反映机械磨损。
+    /// 只有带磁头的机械硬盘才会报告该属性,SSD 上通常返回 `Error::NoData`。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 240
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let flying = disk.smart_get_head_flying_hours()?;
+    /// println!("磁头飞行时间: {}", flying);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_head_flying_hours(&self) -> Result<Duration> {
+        self.smart_info()?
+            .head_flying_duration()
+            .ok_or(Error::NoData)
+    }
+
+    /// 获取主轴电机启停次数 (属性 4)
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 4
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("启停次数: {}", disk.smart_get_start_stop_count()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_start_stop_count(&self) -> Result<u64> {
+        self.smart_info()?.start_stop_count().ok_or(Error::NoData)
+    }
+
+    /// 获取主轴起转重试次数 (属性 10)
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 10
+    pub fn smart_get_spin_retry_count(&self) -> Result<u64> {
+        self.smart_info()?.spin_retry_count().ok_or(Error::NoData)
+    }
+
+    /// 获取磁头校准重试次数 (属性 11)
+    ///
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 11
+    pub fn smart_get_calibration_retry_count(&self) -> Result<u64> {
+        self.smart_info()?
+            .calibration_retry_count()
+            .ok_or(Error::NoData)
+    }
+
+    /// 获取加速度传感器检测到的冲击次数 (属性 191)
+    ///
+    /// 笔记本硬盘通过加速度传感器检测跌落和震动,并在冲击前停放磁头。
+    /// 数值持续增长说明设备经常受到震动。
+    /// 没有属性 191 时使用属性 221,参见 [`SmartInfo::g_sense_error_rate`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 191 或 221
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("冲击次数: {}", disk.smart_get_g_sense_error_rate()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_g_sense_error_rate(&self) -> Result<u64> {
+        self.smart_info()?.g_sense_error_rate().ok_or(Error::NoData)
+    }
+
+    /// 获取磁头飞行高度异常的写入次数 (属性 189)
+    ///
+    /// 写入时磁头飞行高度超出正常范围的次数,主要由 Seagate 硬盘报告。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 189
+    pub fn smart_get_high_fly_writes(&self) -> Result<u64> {
+        self.smart_info()?.high_fly_writes().ok_or(Error::NoData)
+    }
+
+    /// 获取气流温度 (属性 190)
+    ///
+    /// 硬盘内部气流的温度,通常略低于属性 194 报告的温度。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 190,或原始值超出合理范围
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("气流温度: {}", disk.smart_get_airflow_temperature()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_airflow_temperature(&self) -> Result<Temperature> {
+        self.smart_info()?
+            .airflow_temperature()
+            .ok_or(Error::NoData)
+    }
+
+    /// 获取 SSD 剩余寿命 (属性 232)
+    ///
+    /// 参见 [`SmartInfo::endurance_remaining`]。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 返回
+    ///
+    /// * `Err(Error::NoData)` - 设备没有报告属性 232,机械硬盘通常如此
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("剩余寿命: {}", disk.smart_get_endurance_remaining()?);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_endurance_remaining(&self) -> Result<Percent> {
+        self.smart_info()?
+            .endurance_remaining()
+            .ok_or(Error::NoData)
+    }
+
+    /// 获取机械磨损统计
+    ///
+    /// 设备没有报告的属性在结果中为 `None`,SSD 通常全部为 `None`。
+    /// 使用已缓存的 SMART 数据,没有缓存时从设备读取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let wear = disk.smart_get_wear_statistics()?;
+    /// if let Some(retries) = wear.spin_retry_count.filter(|&n| n > 0) {
+    ///     println!("主轴起转重试 {} 次", retries);
+    /// }
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn smart_get_wear_statistics(&self) -> Result<WearStatistics> {
+        Ok(self.smart_info()?.wear_statistics())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::disk::{IdentifyData, SmartData, SmartThresholds};
+    use crate::types::SelfTestExecutionStatus;
+
+    /// 记录读取时的型号,IDENTIFY 字 27-46 中每个字内两个字节交换
+    fn with_model(smart: &SmartInfo, model: &str) -> SmartInfo {
+        let mut identify = [0u8; 512];
+        let mut field = [b' '; 40];
+        field[..model.len()].copy_from_slice(model.as_bytes());
+        for (i, pair) in field.chunks(2).enumerate() {
+            identify[54 + i * 2] = pair[1];
+            identify[54 + i * 2 + 1] = pair[0];
+        }
+
+        let identify = IdentifyData::new(identify);
+        SmartInfo::new(
+            SmartData::new(*smart.data.raw(), 0).with_identify(Some(&identify)),
+            smart.thresholds.clone(),
+        )
+    }
+
+    #[test]
+    fn test_is_seagate() {
+        let smart = smart_with_attribute(188, 0x32, 100, 1, 0);
+        assert!(!smart.is_seagate());
+        assert!(with_model(&smart, "ST3500320AS").is_seagate());
+        assert!(!with_model(&smart, "WDC WD10EZEX-00BN5A0").is_seagate());
+    }
+
     #[test]
     fn test_statistics_methods_exist() {
         // 这些方法应该存在并可以编译
         // 实际测试需要真实的 SMART 数据
     }
+
+    #[test]
+    fn test_seek_error_rate() {
+        let mut raw = [0u8; 512];
+        // 属性 7: 寻道总次数 1000, 寻道错误 10
+        raw[2] = 7;
+        raw[5] = 100;
+        raw[6] = 100;
+        raw[7..11].copy_from_slice(&1000u32.to_le_bytes());
+        raw[11..13].copy_from_slice(&10u16.to_le_bytes());
+
+        let smart = SmartInfo::new(SmartData::new(raw, 0), None);
+        let seagate = with_model(&smart, "ST3500320AS").seek_error_rate().unwrap();
+        assert!((seagate - 0.01).abs() < f64::EPSILON);
+
+        let other = smart.seek_error_rate().unwrap();
+        assert_eq!(other, ((10u64 << 32) | 1000) as f64);
+    }
+
+    #[test]
+    fn test_read_error_rate() {
+        let mut raw = [0u8; 512];
+        // 属性 1: 读取扇区 200000, 读取错误 5
+        raw[2] = 1;
+        raw[5] = 100;
+        raw[6] = 100;
+        raw[7..11].copy_from_slice(&200_000u32.to_le_bytes());
+        raw[11..13].copy_from_slice(&5u16.to_le_bytes());
+
+        let smart = SmartInfo::new(SmartData::new(raw, 0), None);
+        let seagate = with_model(&smart, "ST3500320AS").read_error_rate().unwrap();
+        assert!((seagate - 5.0 / 200_000.0).abs() < f64::EPSILON);
+
+        let other = smart.read_error_rate().unwrap();
+        assert_eq!(other, ((5u64 << 32) | 200_000) as f64);
+
+        assert!(smart.seek_error_rate().is_none());
+    }
+
+    #[test]
+    fn test_ssd_wear_percent() {
+        let smart = smart_with_attribute(232, 0x03, 97, 0, 10);
+        assert_eq!(smart.endurance_remaining(), Some(Percent::new(97.0)));
+        assert_eq!(smart.available_reserved_space(), None);
+
+        let smart = smart_with_attribute(170, 0x03, 85, 0, 10);
+        assert_eq!(smart.available_reserved_space(), Some(Percent::new(85.0)));
+        assert_eq!(smart.endurance_remaining(), None);
+    }
+
+    /// 构造包含一个属性的 SMART 信息
+    fn smart_with_attribute(id: u8, flags: u8, value: u8, raw: u32, threshold: u8) -> SmartInfo {
+        let mut data = [0u8; 512];
+        data[2] = id;
+        data[3] = flags;
+        data[5] = value;
+        data[6] = value;
+        data[7..11].copy_from_slice(&raw.to_le_bytes());
+
+        let mut thresholds = [0u8; 512];
+        thresholds[2] = id;
+        thresholds[3] = threshold;
+
+        SmartInfo::new(
+            SmartData::new(data, 1_000_000_000_000),
+            Some(SmartThresholds::new(thresholds)),
+        )
+    }
+
+    #[test]
+    fn test_head_flying_duration() {
+        let smart = smart_with_attribute(240, 0x00, 100, 1234, 0);
+        let duration = smart.head_flying_duration().unwrap();
+        assert_eq!(duration.as_millis(), 1234 * 60 * 60 * 1000);
+
+        let smart = smart_with_attribute(9, 0x00, 100, 1234, 0);
+        assert!(smart.head_flying_duration().is_none());
+    }
+
+    #[test]
+    fn test_wear_statistics() {
+        let mut data = [0u8; 512];
+        // 属性 4: 启停 1500 次, 属性 10: 起转重试 2 次, 属性 193: 加载 30000 次
+        data[2..7].copy_from_slice(&[4, 0x32, 0, 99, 99]);
+        data[7..11].copy_from_slice(&1500u32.to_le_bytes());
+        data[14..19].copy_from_slice(&[10, 0x33, 0, 100, 100]);
+        data[19] = 2;
+        data[26..31].copy_from_slice(&[193, 0x32, 0, 90, 90]);
+        data[31..35].copy_from_slice(&30000u32.to_le_bytes());
+
+        let smart = SmartInfo::new(SmartData::new(data, 0), None);
+        assert_eq!(smart.start_stop_count(), Some(1500));
+        assert_eq!(smart.spin_retry_count(), Some(2));
+        assert_eq!(smart.calibration_retry_count(), None);
+
+        let wear = smart.wear_statistics();
+        assert_eq!(
+            wear,
+            WearStatistics {
+                start_stop_count: Some(1500),
+                spin_retry_count: Some(2),
+                load_cycle_count: Some(30000),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_udma_crc_errors() {
+        let smart = smart_with_attribute(199, 0x00, 200, 5, 0);
+        assert_eq!(smart.udma_crc_errors(), Some(5));
+        assert_eq!(
+            smart.overall_with_crc_threshold(true, None),
+            SmartOverall::Good
+        );
+        assert_eq!(
+            smart.overall_with_crc_threshold(true, Some(5)),
+            SmartOverall::Good
+        );
+        assert_eq!(
+            smart.overall_with_crc_threshold(true, Some(4)),
+            SmartOverall::BadAttributeNow
+        );
+        // 更严重的状态不受影响
+        assert_eq!(
+            smart.overall_with_crc_threshold(false, Some(0)),
+            SmartOverall::BadStatus
+        );
+
+        let smart = smart_with_attribute(5, 0x00, 100, 0, 10);
+        assert_eq!(smart.udma_crc_errors(), None);
+    }
+
+    #[test]
+    fn test_overall() {
+        let smart = smart_with_attribute(1, 0x01, 100, 0, 50);
+        assert_eq!(smart.overall(false), SmartOverall::BadStatus);
+        assert_eq!(smart.overall(true), SmartOverall::Good);
+
+        // 预失败属性当前值低于阈值
+        let smart = smart_with_attribute(1, 0x01, 10, 0, 50);
+        assert_eq!(smart.overall(true), SmartOverall::BadAttributeNow);
+
+        // 少量重新分配扇区
+        let smart = smart_with_attribute(5, 0x00, 100, 3, 10);
+        assert_eq!(smart.overall(true), SmartOverall::BadSector);
+
+        // 约 19.5 亿扇区, log2 = 30, 阈值为 30720
+        let smart = smart_with_attribute(5, 0x00, 100, 40000, 10);
+        assert_eq!(smart.overall(true), SmartOverall::BadSectorMany);
+    }
+
+    #[test]
+    fn test_overall_detailed() {
+        let smart = smart_with_attribute(1, 0x01, 100, 0, 50);
+        let assessment = smart.overall_detailed(true);
+        assert_eq!(assessment.overall, SmartOverall::Good);
+        assert!(assessment.attributes.is_empty());
+        assert_eq!(assessment.to_string(), "良好");
+
+        // 状态为负面时仍然收集其他依据
+        let smart = smart_with_attribute(197, 0x00, 100, 2, 0);
+        let assessment = smart.overall_detailed(false);
+        assert_eq!(assessment.overall, SmartOverall::BadStatus);
+        assert_eq!(assessment.pending_sectors, Some(2));
+        assert_eq!(assessment.bad_sectors(), 2);
+        assert_eq!(
+            assessment.to_string(),
+            "SMART 自评估为负面; 待处理扇区 2 个"
+        );
+
+        // 重新分配扇区的最差值曾经低于阈值
+        let mut smart = smart_with_attribute(5, 0x01, 100, 3, 10);
+        let mut raw = *smart.data.raw();
+        raw[6] = 5;
+        // 上次自检读取元件失败
+        raw[363] = 0x70;
+        let sum = raw[..511].iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+        raw[511] = sum.wrapping_neg();
+        smart.data = SmartData::new(raw, 1_000_000_000_000);
+        let assessment = smart.overall_detailed(true);
+        assert_eq!(assessment.overall, SmartOverall::BadSector);
+        assert_eq!(assessment.attributes.len(), 1);
+        assert_eq!(
+            assessment.attributes[0].kind,
+            FailureKind::ThresholdInThePast
+        );
+        assert_eq!(
+            assessment.failed_self_test,
+            Some(SelfTestExecutionStatus::ErrorRead)
+        );
+        assert_eq!(
+            assessment.to_string(),
+            "已重新分配扇区 3 个; 属性 5 (reallocated-sector-count) 过去超过阈值; \
+             上次自检读取元件失败"
+        );
+
+        let smart = smart_with_attribute(199, 0x00, 200, 5, 0);
+        let assessment = smart.overall_detailed_with_crc_threshold(true, Some(4));
+        assert_eq!(assessment.overall, SmartOverall::BadAttributeNow);
+        assert_eq!(assessment.udma_crc_errors, Some(5));
+        assert_eq!(assessment.to_string(), "UDMA CRC 错误 5 次");
+
+        let smart = smart_with_attribute(188, 0x32, 100, 11, 0);
+        let assessment = smart.overall_detailed(true);
+        assert_eq!(assessment.command_timeouts, Some(11));
+        assert_eq!(assessment.to_string(), "命令超时 11 次");
+    }
+
+    #[test]
+    fn test_shock_and_airflow_attributes() {
+        // 48 位原始值不截断
+        let mut smart = smart_with_attribute(191, 0x32, 100, 0x1234_5678, 0);
+        let mut raw = *smart.data.raw();
+        raw[11] = 0x01;
+        smart.data = SmartData::new(raw, 0);
+        assert_eq!(smart.g_sense_error_rate(), Some(0x01_1234_5678));
+        let attr = smart.attribute(AttributeId::GSenseErrorRate).unwrap();
+        assert_eq!(attr.pretty_unit, AttributeUnit::None);
+        assert_eq!(attr.pretty_value, 0x01_1234_5678);
+
+        let smart = smart_with_attribute(221, 0x32, 100, 7, 0);
+        assert_eq!(smart.g_sense_error_rate(), Some(7));
+
+        let smart = smart_with_attribute(189, 0x3A, 100, 3, 0);
+        assert_eq!(smart.high_fly_writes(), Some(3));
+        assert_eq!(smart.g_sense_error_rate(), None);
+
+        // Seagate 在高位字节记录最低/最高温度
+        let smart = smart_with_attribute(190, 0x22, 69, 0x2014_001F, 45);
+        assert_eq!(
+            smart.airflow_temperature(),
+            Some(Temperature::from_celsius(31.0))
+        );
+        assert_eq!(smart.temperature(), Some(Temperature::from_celsius(31.0)));
+
+        // 超出合理范围
+        let smart = smart_with_attribute(190, 0x22, 69, 200, 45);
+        assert_eq!(smart.airflow_temperature(), None);
+        assert_eq!(smart.temperature(), None);
+
+        // 194 超出合理范围时回退到 190
+        let mut smart = smart_with_attribute(194, 0x22, 100, 200, 0);
+        let mut raw = *smart.data.raw();
+        raw[14] = 190;
+        raw[15] = 0x22;
+        raw[17] = 69;
+        raw[18] = 69;
+        raw[19] = 31;
+        smart.data = SmartData::new(raw, 0);
+        assert_eq!(smart.temperature(), Some(Temperature::from_celsius(31.0)));
+    }
+
+    #[test]
+    fn test_command_timeouts() {
+        let smart = smart_with_attribute(188, 0x32, 100, 10, 0);
+        assert_eq!(smart.command_timeouts(), Some(10));
+        assert_eq!(smart.overall(true), SmartOverall::Good);
+
+        let smart = smart_with_attribute(188, 0x32, 100, 11, 0);
+        assert_eq!(smart.overall(true), SmartOverall::BadAttributeInThePast);
+        assert_eq!(smart.overall(false), SmartOverall::BadStatus);
+
+        // Seagate: 总数 1, 超过 5 秒 1, 超过 7.5 秒 1
+        let mut smart = smart_with_attribute(188, 0x32, 100, 0x0001_0001, 0);
+        assert_eq!(
+            with_model(&smart, "ST3500320AS").command_timeouts(),
+            Some(1)
+        );
+        assert_eq!(smart.command_timeouts(), Some(0x0001_0001));
+        let mut raw = *smart.data.raw();
+        raw[11] = 0x01;
+        smart.data = SmartData::new(raw, 0);
+        assert_eq!(
+            with_model(&smart, "ST3500320AS").command_timeouts(),
+            Some(1)
+        );
+        assert_eq!(smart.command_timeouts(), Some(0x0001_0001_0001));
+
+        // Seagate 的超时命令总数不超过 10 次时不影响整体状态
+        let smart = smart_with_attribute(188, 0x32, 100, 0x0020_0001, 0);
+        assert_eq!(smart.overall(true), SmartOverall::BadAttributeInThePast);
+        assert_eq!(
+            with_model(&smart, "ST3500320AS").overall(true),
+            SmartOverall::Good
+        );
+
+        let smart = smart_with_attribute(5, 0x00, 100, 0, 10);
+        assert_eq!(smart.command_timeouts(), None);
+    }
 }
@@ -0,0 +1,127 @@
+//! 磁盘厂商识别
+//!
+//! 部分属性的编码方式因厂商而异,根据 IDENTIFY 中的型号字符串判断厂商
+
+use crate::disk::Disk;
+use crate::error::Result;
+use crate::types::DiskVendor;
+
+/// 型号前缀和对应的厂商 (不区分大小写)
+static VENDOR_PREFIXES: &[(&str, DiskVendor)] = &[
+    ("SEAGATE", DiskVendor::Seagate),
+    ("WDC", DiskVendor::WesternDigital),
+    ("WD", DiskVendor::WesternDigital),
+    ("WESTERN DIGITAL", DiskVendor::WesternDigital),
+    ("TOSHIBA", DiskVendor::Toshiba),
+    ("HITACHI", DiskVendor::Hitachi),
+    ("HGST", DiskVendor::Hitachi),
+    ("SAMSUNG", DiskVendor::Samsung),
+    ("FUJITSU", DiskVendor::Fujitsu),
+    ("INTEL", DiskVendor::Intel),
+    ("SANDISK", DiskVendor::Sandisk),
+    ("CRUCIAL", DiskVendor::Crucial),
+];
+
+/// 根据型号字符串判断厂商
+///
+/// Seagate 的型号通常不带厂商名,以 `ST` 加数字开头 (例如 `ST3500320AS`);
+/// Crucial 的型号以 `CT` 加数字开头 (例如 `CT500MX500SSD1`)。
+/// 其他厂商按型号前缀匹配,不区分大小写。
+///
+/// # 示例
+///
+/// ```
+/// use libatasmart::{detect_vendor, DiskVendor};
+///
+/// assert_eq!(detect_vendor("ST3500320AS"), DiskVendor::Seagate);
+/// assert_eq!(detect_vendor("WDC WD10EZEX-08WN4A0"), DiskVendor::WesternDigital);
+/// assert_eq!(detect_vendor("QEMU HARDDISK"), DiskVendor::Unknown);
+/// ```
+pub fn detect_vendor(model: &str) -> DiskVendor {
+    let model = model.trim();
+    let upper = model.to_ascii_uppercase();
+
+    if let Some(vendor) = VENDOR_PREFIXES
+        .iter()
+        .find(|(prefix, _)| upper.starts_with(prefix))
+        .map(|(_, vendor)| *vendor)
+    {
+        return vendor;
+    }
+
+    let digit_after = |prefix: &str| {
+        upper
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_ascii_digit())
+    };
+    if digit_after("ST") {
+        DiskVendor::Seagate
+    } else if digit_after("CT") {
+        DiskVendor::Crucial
+    } else {
+        DiskVendor::Unknown
+    }
+}
+
+impl Disk {
+    /// 获取磁盘厂商
+    ///
+    /// 根据 IDENTIFY 中的型号判断,参见 [`detect_vendor`]。
+    /// 使用已缓存的 IDENTIFY 数据,没有缓存时从设备读取。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// println!("厂商: {}", disk.vendor()?.as_str());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn vendor(&self) -> Result<DiskVendor> {
+        let model = self.read_identify_cached()?.parse()?.model;
+        Ok(detect_vendor(&model))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_vendor() {
+        let cases = [
+            ("ST3500320AS", DiskVendor::Seagate),
+            (
+                "Seagate BarraCuda 120 SSD ZA250CM10003",
+                DiskVendor::Seagate,
+            ),
+            ("WDC WD10EZEX-08WN4A0", DiskVendor::WesternDigital),
+            ("WD Blue SA510 2.5 1000GB", DiskVendor::WesternDigital),
+            ("TOSHIBA MQ01ABD100", DiskVendor::Toshiba),
+            ("Hitachi HDS721010CLA332", DiskVendor::Hitachi),
+            ("HGST HUS726040ALA610", DiskVendor::Hitachi),
+            ("Samsung SSD 860 EVO 500GB", DiskVendor::Samsung),
+            ("SAMSUNG HD103SJ", DiskVendor::Samsung),
+            ("FUJITSU MHY2120BH", DiskVendor::Fujitsu),
+            ("INTEL SSDSC2KB480G8", DiskVendor::Intel),
+            ("SanDisk SDSSDH3500G", DiskVendor::Sandisk),
+            ("Crucial_CT525MX300SSD1", DiskVendor::Crucial),
+            ("CT500MX500SSD1", DiskVendor::Crucial),
+            ("QEMU HARDDISK", DiskVendor::Unknown),
+            ("STORAGE DEVICE", DiskVendor::Unknown),
+            ("", DiskVendor::Unknown),
+        ];
+
+        for (model, vendor) in cases {
+            assert_eq!(detect_vendor(model), vendor, "{}", model);
+        }
+    }
+
+    #[test]
+    fn test_vendor_without_identify() {
+        let disk = Disk::from_blob().unwrap();
+        assert!(disk.vendor().is_err());
+    }
+}
@@ -0,0 +1,397 @@
+//! 磁盘快照
+//!
+//! 记录某一时刻磁盘的全部已知信息
+
+use crate::disk::{Disk, IdentifyData, SmartInfo};
+use crate::error::{Error, Result};
+use crate::smart::blob::{read_blob_from_file, smart_info_from_blob_data, BlobData};
+use crate::smart::AttributeId;
+use crate::types::{
+    AttributeUnit, Duration, IdentifyParsedData, SmartAttributeParsedData, SmartOverall,
+    SmartParsedData, Temperature,
+};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// 磁盘快照
+///
+/// 一次轮询得到的全部数据,便于序列化、比较和上报。
+/// 通过 [`Disk::snapshot`] 从设备采集,或通过 [`DiskSnapshot::from_blob`] 从 blob 文件创建。
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiskSnapshot {
+    /// 采集时间
+    pub taken_at: SystemTime,
+    /// IDENTIFY 信息
+    pub identify: Option<IdentifyParsedData>,
+    /// SMART 数据
+    pub smart: Option<SmartParsedData>,
+    /// SMART 属性
+    pub attributes: Vec<SmartAttributeParsedData>,
+    /// SMART 自评估结果
+    pub status: Option<bool>,
+    /// 整体健康状态
+    pub overall: Option<SmartOverall>,
+    /// 设备大小 (字节)
+    pub size: u64,
+}
+
+impl DiskSnapshot {
+    /// 从设备采集快照
+    ///
+    /// 优先使用已缓存的 IDENTIFY 数据。SMART 数据与自评估结果一起重新读取,
+    /// 参见 [`Disk::read_smart_with_status`];读取失败时使用缓存的 SMART 数据,
+    /// 此时自评估结果和整体状态为空。
+    /// IDENTIFY 读取失败时返回错误,其余数据读取失败时对应字段为空
+    pub(crate) fn capture(disk: &Disk) -> Result<Self> {
+        let identify = disk.read_identify_cached()?.parse()?;
+
+        let (smart, status) = match disk.read_smart_with_status() {
+            Ok((smart, status)) => (Some(smart), Some(status)),
+            Err(_) => (disk.cached_smart(), None),
+        };
+        let overall = smart.as_ref().zip(status).map(|(smart, status)| {
+            smart.overall_with_crc_threshold(status, disk.crc_error_threshold())
+        });
+
+        Ok(Self::assemble(
+            Some(identify),
+            smart,
+            status,
+            overall,
+            disk.size(),
+        ))
+    }
+
+    /// 从 blob 文件创建快照
+    ///
+    /// Blob 中没有设备大小,使用 IDENTIFY 报告的容量。
+    /// 缺少 SMART 数据或状态时对应字段为空。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::DiskSnapshot;
+    ///
+    /// let snapshot = DiskSnapshot::from_blob("tests/blobs/example.blob")?;
+    /// println!("{:?}: {} 个属性", snapshot.overall, snapshot.attributes.len());
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn from_blob<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_blob_data(&read_blob_from_file(path)?)
+    }
+
+    /// 从已解析的 blob 数据创建快照
+    pub(crate) fn from_blob_data(blob_data: &BlobData) -> Result<Self> {
+        let identify = blob_data
+            .identify
+            .map(IdentifyData::new)
+            .ok_or(Error::InvalidData("Blob 缺少 IDENTIFY 数据".to_string()))?;
+        let size = identify.capacity().unwrap_or(0);
+
+        let smart = smart_info_from_blob_data(blob_data).ok();
+        let status = blob_data.smart_status;
+        let overall = smart
+            .as_ref()
+            .zip(status)
+            .map(|(smart, status)| smart.overall(status));
+
+        Ok(Self::assemble(
+            Some(identify.parse()?),
+            smart,
+            status,
+            overall,
+            size,
+        ))
+    }
+
+    /// 当前温度 (属性 194、190 或 231)
+    pub fn temperature(&self) -> Option<Temperature> {
+        self.attributes
+            .iter()
+            .find(|attr| {
+                matches!(
+                    attr.id_enum(),
+                    AttributeId::TemperatureCelsius2
+                        | AttributeId::AirflowTemperatureCelsius
+                        | AttributeId::TemperatureCelsius
+                ) && attr.name.contains("temperature")
+            })
+            .map(|attr| Temperature::from_millikelvin(attr.pretty_value))
+    }
+
+    /// 累计开机时间 (属性 9)
+    pub fn power_on_duration(&self) -> Option<Duration> {
+        self.attributes
+            .iter()
+            .find(|attr| {
+                attr.id_enum() == AttributeId::PowerOnHours
+                    && attr.pretty_unit == AttributeUnit::Milliseconds
+            })
+            .map(|attr| Duration::from_millis(attr.pretty_value))
+    }
+
+    fn assemble(
+        identify: Option<IdentifyParsedData>,
+        smart: Option<SmartInfo>,
+        status: Option<bool>,
+        overall: Option<SmartOverall>,
+        size: u64,
+    ) -> Self {
+        let attributes = smart
+            .as_ref()
+            .and_then(|smart| smart.parse_attributes().ok())
+            .unwrap_or_default();
+
+        Self {
+            taken_at: SystemTime::now(),
+            identify,
+            smart: smart.and_then(|smart| smart.data.parse().ok()),
+            attributes,
+            status,
+            overall,
+            size,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl DiskSnapshot {
+    /// 导出与 `smartctl --json` 字段名兼容的 JSON
+    ///
+    /// 输出 `model_name`、`serial_number`、`firmware_version`、`user_capacity`、
+    /// `smart_status`、`ata_smart_attributes`、`temperature`、`power_on_time`
+    /// 和 `power_cycle_count`,字段名和单位与 smartctl 相同,便于沿用已有的采集管道。
+    /// 属性名称转换为 smartmontools 的写法 (例如 `Reallocated_Sector_Ct`),
+    /// 快照中没有的数据不输出对应字段。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let json = disk.snapshot()?.to_smartctl_json();
+    /// println!("{}", json["ata_smart_attributes"]["table"]);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn to_smartctl_json(&self) -> serde_json::Value {
+        use crate::smart::smartctl_attribute_name;
+        use serde_json::{json, Map, Value};
+
+        let mut root = Map::new();
+
+        if let Some(identify) = &self.identify {
+            root.insert("model_name".into(), json!(identify.model));
+            root.insert("serial_number".into(), json!(identify.serial));
+            root.insert("firmware_version".into(), json!(identify.firmware));
+        }
+        root.insert("user_capacity".into(), json!({ "bytes": self.size }));
+
+        if let Some(status) = self.status {
+            root.insert("smart_status".into(), json!({ "passed": status }));
+        }
+
+        if !self.attributes.is_empty() {
+            let table: Vec<Value> = self
+                .attributes
+                .iter()
+                .map(|attr| {
+                    let when_failed = if attr.good_now_valid && !attr.good_now {
+                        "now"
+                    } else if attr.good_in_the_past_valid && !attr.good_in_the_past {
+                        "past"
+                    } else {
+                        ""
+                    };
+
+                    json!({
+                        "id": attr.id,
+                        "name": smartctl_attribute_name(attr.name),
+                        "value": attr.current_value,
+                        "worst": attr.worst_value,
+                        "thresh": attr.threshold,
+                        "when_failed": when_failed,
+                        "flags": {
+                            "value": attr.flags,
+                            "prefailure": attr.prefailure,
+                            "updated_online": attr.online,
+                        },
+                        "raw": {
+                            "value": raw_value(&attr.raw),
+                            "string": raw_string(attr),
+                        },
+                    })
+                })
+                .collect();
+            root.insert("ata_smart_attributes".into(), json!({ "table": table }));
+        }
+
+        if let Some(temperature) = self.temperature() {
+            root.insert(
+                "temperature".into(),
+                json!({ "current": temperature.celsius().round() as i64 }),
+            );
+        }
+
+        if let Some(power_on) = self.power_on_duration() {
+            root.insert(
+                "power_on_time".into(),
+                json!({ "hours": power_on.as_hours() }),
+            );
+        }
+
+        if let Some(attr) = self
+            .attributes
+            .iter()
+            .find(|attr| attr.id_enum() == AttributeId::PowerCycleCount)
+        {
+            root.insert("power_cycle_count".into(), json!(attr.pretty_value));
+        }
+
+        Value::Object(root)
+    }
+}
+
+/// 48 位原始值 (小端序)
+#[cfg(any(feature = "json", feature = "metrics"))]
+pub(crate) fn raw_value(raw: &[u8; 6]) -> u64 {
+    raw.iter()
+        .rev()
+        .fold(0u64, |value, &byte| (value << 8) | byte as u64)
+}
+
+/// 按 smartctl 的默认格式输出原始值
+///
+/// 温度属性为 `"35 (Min/Max 20/45)"`,其余属性为十进制原始值
+#[cfg(feature = "json")]
+fn raw_string(attr: &SmartAttributeParsedData) -> String {
+    if attr.pretty_unit == AttributeUnit::MilliKelvin {
+        let current = attr.raw[0];
+        let (a, b) = (attr.raw[2], attr.raw[4]);
+        if a != 0 && b != 0 && attr.raw[1] == 0 && attr.raw[3] == 0 && attr.raw[5] == 0 {
+            return format!("{} (Min/Max {}/{})", current, a.min(b), a.max(b));
+        }
+        return current.to_string();
+    }
+
+    raw_value(&attr.raw).to_string()
+}
+
+impl Disk {
+    /// 采集磁盘快照
+    ///
+    /// 汇总 IDENTIFY 信息、SMART 数据、属性、自评估结果和整体健康状态,
+    /// 已缓存的数据不会重新读取。适合监控程序每次轮询保存一个值。
+    ///
+    /// # 返回
+    ///
+    /// * `Err` - IDENTIFY 读取失败;其余数据读取失败时快照中对应字段为空
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use libatasmart::Disk;
+    ///
+    /// let disk = Disk::open("/dev/sda")?;
+    /// let snapshot = disk.snapshot()?;
+    /// println!("{:?} {:?}", snapshot.overall, snapshot.taken_at);
+    /// # Ok::<(), libatasmart::Error>(())
+    /// ```
+    pub fn snapshot(&self) -> Result<DiskSnapshot> {
+        DiskSnapshot::capture(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blob(smart_status: Option<bool>, smart_data: Option<[u8; 512]>) -> BlobData {
+        BlobData {
+            identify: Some([0u8; 512]),
+            smart_status,
+            smart_data,
+            smart_thresholds: None,
+        }
+    }
+
+    #[test]
+    fn test_from_blob_data_identify_only() {
+        let snapshot = DiskSnapshot::from_blob_data(&blob(None, None)).unwrap();
+        assert!(snapshot.identify.is_some());
+        assert!(snapshot.smart.is_none());
+        assert!(snapshot.attributes.is_empty());
+        assert_eq!(snapshot.status, None);
+        assert_eq!(snapshot.overall, None);
+    }
+
+    #[test]
+    fn test_from_blob_data_with_smart() {
+        let mut smart_data = [0u8; 512];
+        // 属性 5: 重映射扇区数
+        smart_data[2] = 5;
+        smart_data[5] = 100;
+        smart_data[6] = 100;
+        smart_data[511] = 0u8.wrapping_sub(5 + 100 + 100);
+
+        let snapshot = DiskSnapshot::from_blob_data(&blob(Some(true), Some(smart_data))).unwrap();
+        assert!(snapshot.smart.is_some());
+        assert_eq!(snapshot.attributes.len(), 1);
+        assert_eq!(snapshot.status, Some(true));
+        assert_eq!(snapshot.overall, Some(SmartOverall::Good));
+    }
+
+    #[test]
+    fn test_from_blob_data_requires_identify() {
+        let mut data = blob(None, None);
+        data.identify = None;
+        assert!(DiskSnapshot::from_blob_data(&data).is_err());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_to_smartctl_json() {
+        let mut identify = [0u8; 512];
+        // 字 27-46: 型号 (字节对交换)
+        identify[54..58].copy_from_slice(b"SD T");
+
+        let mut smart_data = [0u8; 512];
+        // 属性 9: 开机 1000 小时
+        smart_data[2..7].copy_from_slice(&[9, 0x32, 0, 99, 99]);
+        smart_data[7..9].copy_from_slice(&1000u16.to_le_bytes());
+        // 属性 194: 35°C, 最低 20°C, 最高 45°C
+        smart_data[14..19].copy_from_slice(&[194, 0x22, 0, 65, 55]);
+        smart_data[19..25].copy_from_slice(&[35, 0, 45, 0, 20, 0]);
+        // 属性 5: 重映射扇区数
+        smart_data[26..31].copy_from_slice(&[5, 0x33, 0, 100, 100]);
+        let sum = smart_data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        smart_data[511] = sum.wrapping_neg();
+
+        let data = BlobData {
+            identify: Some(identify),
+            smart_status: Some(true),
+            smart_data: Some(smart_data),
+            smart_thresholds: None,
+        };
+        let json = DiskSnapshot::from_blob_data(&data)
+            .unwrap()
+            .to_smartctl_json();
+
+        assert_eq!(json["model_name"], "DST");
+        assert_eq!(json["smart_status"]["passed"], true);
+        assert_eq!(json["temperature"]["current"], 35);
+        assert_eq!(json["power_on_time"]["hours"], 1000);
+
+        let table = json["ata_smart_attributes"]["table"].as_array().unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(table[0]["name"], "Power_On_Hours");
+        assert_eq!(table[0]["raw"]["value"], 1000);
+        assert_eq!(table[1]["name"], "Temperature_Celsius");
+        assert_eq!(table[1]["raw"]["string"], "35 (Min/Max 20/45)");
+        assert_eq!(table[1]["worst"], 55);
+        assert_eq!(table[2]["name"], "Reallocated_Sector_Ct");
+        assert_eq!(table[2]["flags"]["prefailure"], true);
+        assert!(json.get("power_cycle_count").is_none());
+    }
+}
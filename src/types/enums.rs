@@ -1,7 +1,13 @@
 //! 枚举类型定义
 
+use super::SecurityStatus;
+use crate::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
 /// 磁盘类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DiskType {
     /// ATA Passthrough 16 字节 SCSI 命令
     AtaPassthrough16,
@@ -13,6 +19,12 @@ pub enum DiskType {
     Sunplus,
     /// JMicron USB/ATA 桥接
     Jmicron,
+    /// Initio INIC-1610P USB/ATA 桥接
+    Initio,
+    /// ASMedia ASM225/ASM1153 USB/ATA 桥接
+    ///
+    /// 自动检测只在 USB 厂商 ID 为 ASMedia (0x174c) 时测试该类型,代替 Initio
+    UsbAsmedia,
     /// 从文件读取的数据
     Blob,
     /// 自动检测
@@ -30,6 +42,8 @@ impl DiskType {
             Self::LinuxIde => "原生 Linux IDE",
             Self::Sunplus => "Sunplus SCSI ATA Passthru",
             Self::Jmicron => "JMicron SCSI ATA Passthru",
+            Self::Initio => "Initio SCSI ATA Passthru",
+            Self::UsbAsmedia => "ASMedia SCSI ATA Passthru",
             Self::Blob => "Blob 文件",
             Self::Auto => "自动检测",
             Self::None => "无",
@@ -44,6 +58,8 @@ impl DiskType {
             Self::LinuxIde => Some("linux-ide"),
             Self::Sunplus => Some("sunplus"),
             Self::Jmicron => Some("jmicron"),
+            Self::Initio => Some("initio"),
+            Self::UsbAsmedia => Some("asmedia"),
             Self::Auto => Some("auto"),
             Self::None => Some("none"),
             Self::Blob => None,
@@ -53,6 +69,7 @@ impl DiskType {
 
 /// SMART 自检类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SmartSelfTest {
     /// 短时自检
     Short = 1,
@@ -76,8 +93,603 @@ impl SmartSelfTest {
     }
 }
 
+impl fmt::Display for SmartSelfTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SmartSelfTest {
+    type Err = Error;
+
+    /// 解析 `"short"`、`"extended"`、`"conveyance"`、`"abort"`,不区分大小写
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Self::Short, Self::Extended, Self::Conveyance, Self::Abort]
+            .into_iter()
+            .find(|test| test.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::InvalidData(format!("未知的自检类型: '{}'", s)))
+    }
+}
+
+/// ATA 安全状态
+///
+/// 参见 ACS-3 7.16 节,由 IDENTIFY 字 128 给出。
+/// 冻结 (frozen) 状态下无法设置或修改安全密码,也无法执行安全擦除,
+/// 直到设备下一次断电或硬件复位。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SecurityState {
+    /// 未设置密码
+    Disabled,
+    /// 未设置密码,已冻结
+    Frozen,
+    /// 已设置密码且已锁定,需要解锁才能访问数据
+    Locked,
+    /// 已设置密码,已解锁
+    Enabled,
+    /// 已设置密码,已解锁且已冻结
+    EnabledFrozen,
+}
+
+impl SecurityState {
+    /// 从 IDENTIFY 字 128 解析,不支持安全功能时返回 `None`
+    ///
+    /// 参见 [`SecurityStatus::from_word`] 和 [`SecurityStatus::state`]
+    pub fn from_word(word: u16) -> Option<Self> {
+        SecurityStatus::from_word(word).map(|security| security.state())
+    }
+
+    /// 是否已冻结 (无法修改密码或执行安全擦除)
+    pub fn is_frozen(&self) -> bool {
+        matches!(self, Self::Frozen | Self::EnabledFrozen)
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disabled => "未启用",
+            Self::Frozen => "未启用 (已冻结)",
+            Self::Locked => "已锁定",
+            Self::Enabled => "已启用",
+            Self::EnabledFrozen => "已启用 (已冻结)",
+        }
+    }
+}
+
+/// 介质旋转速率
+///
+/// 由 IDENTIFY 字 217 给出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RotationRate {
+    /// 非旋转介质 (SSD)
+    Ssd,
+    /// 标称转速 (RPM)
+    Rpm(u16),
+    /// 未报告或保留值
+    Unknown,
+}
+
+impl RotationRate {
+    /// 从 IDENTIFY 字 217 解析
+    pub fn from_word(word: u16) -> Self {
+        match word {
+            0x0001 => Self::Ssd,
+            0x0401..=0xFFFE => Self::Rpm(word),
+            _ => Self::Unknown,
+        }
+    }
+
+    /// 是否为非旋转介质
+    pub fn is_ssd(&self) -> bool {
+        matches!(self, Self::Ssd)
+    }
+}
+
+/// 可选功能的支持和启用状态
+///
+/// 由 IDENTIFY 字 82/83 (支持) 和字 85/86 (启用) 的同一位给出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FeatureState {
+    /// 不支持或 IDENTIFY 未报告
+    Unsupported,
+    /// 支持但未启用
+    Disabled,
+    /// 已启用
+    Enabled,
+}
+
+impl FeatureState {
+    /// 从支持字和启用字的同一位解析
+    ///
+    /// 与 smartmontools 相同,字值为 0x0000 或 0xFFFF 时视为未报告
+    pub fn from_words(supported: u16, enabled: u16, bit: u8) -> Self {
+        let valid = |word: u16| word != 0x0000 && word != 0xFFFF;
+        let mask = 1u16 << bit;
+
+        if !valid(supported) || supported & mask == 0 {
+            Self::Unsupported
+        } else if valid(enabled) && enabled & mask != 0 {
+            Self::Enabled
+        } else {
+            Self::Disabled
+        }
+    }
+
+    /// 是否支持该功能
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, Self::Unsupported)
+    }
+
+    /// 是否已启用该功能
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled)
+    }
+}
+
+/// 标称外形尺寸
+///
+/// 由 IDENTIFY 字 168 bit 3:0 给出
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FormFactor {
+    /// 5.25 英寸
+    Inch5_25,
+    /// 3.5 英寸
+    Inch3_5,
+    /// 2.5 英寸
+    Inch2_5,
+    /// 1.8 英寸
+    Inch1_8,
+    /// 小于 1.8 英寸 (mSATA、M.2 等)
+    Smaller,
+    /// 保留值
+    Unknown(u16),
+}
+
+/// 字 168 的代码、外形尺寸和描述
+const FORM_FACTORS: &[(u16, FormFactor, &str)] = &[
+    (1, FormFactor::Inch5_25, "5.25 inches"),
+    (2, FormFactor::Inch3_5, "3.5 inches"),
+    (3, FormFactor::Inch2_5, "2.5 inches"),
+    (4, FormFactor::Inch1_8, "1.8 inches"),
+    (5, FormFactor::Smaller, "< 1.8 inches"),
+];
+
+impl FormFactor {
+    /// 从 IDENTIFY 字 168 解析,未报告 (0) 时返回 `None`
+    pub fn from_word(word: u16) -> Option<Self> {
+        let code = word & 0xF;
+        if code == 0 || word == 0xFFFF {
+            return None;
+        }
+
+        Some(
+            FORM_FACTORS
+                .iter()
+                .find(|(value, _, _)| *value == code)
+                .map_or(Self::Unknown(code), |(_, form_factor, _)| *form_factor),
+        )
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        FORM_FACTORS
+            .iter()
+            .find(|(_, form_factor, _)| form_factor == self)
+            .map_or("未知", |(_, _, name)| name)
+    }
+}
+
+/// 磁盘厂商
+///
+/// 由型号字符串推断,参见 [`crate::detect_vendor`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DiskVendor {
+    /// Seagate
+    Seagate,
+    /// Western Digital
+    WesternDigital,
+    /// Toshiba
+    Toshiba,
+    /// Hitachi / HGST
+    Hitachi,
+    /// Samsung
+    Samsung,
+    /// Fujitsu
+    Fujitsu,
+    /// Intel
+    Intel,
+    /// SanDisk
+    Sandisk,
+    /// Crucial
+    Crucial,
+    /// 未知厂商
+    Unknown,
+}
+
+impl DiskVendor {
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Seagate => "Seagate",
+            Self::WesternDigital => "Western Digital",
+            Self::Toshiba => "Toshiba",
+            Self::Hitachi => "Hitachi",
+            Self::Samsung => "Samsung",
+            Self::Fujitsu => "Fujitsu",
+            Self::Intel => "Intel",
+            Self::Sandisk => "SanDisk",
+            Self::Crucial => "Crucial",
+            Self::Unknown => "未知",
+        }
+    }
+}
+
+/// 传输类型
+///
+/// 由 IDENTIFY 字 222 给出,bit 15:12 为传输类型,bit 11:0 为支持的传输规范版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Transport {
+    /// 并行 ATA
+    Parallel,
+    /// 串行 ATA,`revision` 为支持的最高 SATA 规范版本
+    Serial {
+        /// 最高 SATA 规范版本,未报告时为 `None`
+        revision: Option<&'static str>,
+    },
+    /// PCIe
+    Pcie,
+    /// 未报告或保留值
+    Unknown,
+}
+
+/// 字 222 bit 11:0 中各位对应的 SATA 规范版本
+const SATA_REVISIONS: &[(u8, &str)] = &[
+    (0, "ATA8-AST"),
+    (1, "SATA 1.0a"),
+    (2, "SATA II Ext"),
+    (3, "SATA 2.5"),
+    (4, "SATA 2.6"),
+    (5, "SATA 3.0"),
+    (6, "SATA 3.1"),
+    (7, "SATA 3.2"),
+    (8, "SATA 3.3"),
+    (9, "SATA 3.4"),
+    (10, "SATA 3.5"),
+];
+
+impl Transport {
+    /// 从 IDENTIFY 字 222 解析
+    pub fn from_word(word: u16) -> Self {
+        if word == 0x0000 || word == 0xFFFF {
+            return Self::Unknown;
+        }
+
+        match word >> 12 {
+            0x0 => Self::Parallel,
+            0x1 => Self::Serial {
+                revision: SATA_REVISIONS
+                    .iter()
+                    .rev()
+                    .find(|(bit, _)| word & (1 << bit) != 0)
+                    .map(|(_, name)| *name),
+            },
+            0xE => Self::Pcie,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Parallel => "PATA",
+            Self::Serial { revision } => revision.unwrap_or("SATA"),
+            Self::Pcie => "PCIe",
+            Self::Unknown => "未知",
+        }
+    }
+}
+
+/// 分区 (SMR/ZAC) 能力
+///
+/// 由 IDENTIFY 字 69 bit 1:0 给出。主机管理型设备使用不同的设备签名,
+/// 字 69 报告为 00b,无法从 IDENTIFY 数据中识别,参见 [`ZonedCapability::from_linux_zoned`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ZonedCapability {
+    /// 未报告分区能力 (传统设备或无法识别的设备管理型 SMR)
+    NotZoned,
+    /// 主机感知型 (01b)
+    HostAware,
+    /// 主机管理型
+    HostManaged,
+    /// 设备声明为设备管理型 (10b)
+    DriveManagedHint,
+}
+
+impl ZonedCapability {
+    /// 从 IDENTIFY 字 69 解析,保留值 11b 视为未报告
+    pub fn from_word(word: u16) -> Self {
+        match word & 0x3 {
+            0b01 => Self::HostAware,
+            0b10 => Self::DriveManagedHint,
+            _ => Self::NotZoned,
+        }
+    }
+
+    /// 从 Linux `/sys/block/<name>/queue/zoned` 的内容解析
+    pub fn from_linux_zoned(value: &str) -> Option<Self> {
+        match value.trim() {
+            "none" => Some(Self::NotZoned),
+            "host-aware" => Some(Self::HostAware),
+            "host-managed" => Some(Self::HostManaged),
+            _ => None,
+        }
+    }
+
+    /// 是否为叠瓦式 (SMR) 设备
+    pub fn is_smr(&self) -> bool {
+        !matches!(self, Self::NotZoned)
+    }
+}
+
+/// 自动声学管理 (AAM) 级别
+///
+/// 对应 SET FEATURES 0x42 的 SECTOR COUNT 寄存器值,
+/// 以及 IDENTIFY 字 94 bit 7:0 报告的当前值
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum AcousticLevel {
+    /// 最安静 (0x80)
+    Quiet,
+    /// 介于安静与最高性能之间 (0x81 - 0xFD)
+    Moderate(u8),
+    /// 最高性能 (0xFE)
+    MaxPerformance,
+}
+
+impl AcousticLevel {
+    /// 从寄存器值创建,保留值 (0x00 - 0x7F、0xFF) 返回 `None`
+    pub fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0x80 => Some(Self::Quiet),
+            0x81..=0xFD => Some(Self::Moderate(value)),
+            0xFE => Some(Self::MaxPerformance),
+            _ => None,
+        }
+    }
+
+    /// 转换为寄存器值,`Moderate` 超出 0x81 - 0xFD 时返回 `None`
+    pub fn to_value(&self) -> Option<u8> {
+        match *self {
+            Self::Quiet => Some(0x80),
+            Self::Moderate(value @ 0x81..=0xFD) => Some(value),
+            Self::Moderate(_) => None,
+            Self::MaxPerformance => Some(0xFE),
+        }
+    }
+}
+
+/// SATA 链路速率代
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SataGen {
+    /// 1.5 Gbps
+    Gen1,
+    /// 3.0 Gbps
+    Gen2,
+    /// 6.0 Gbps
+    Gen3,
+}
+
+impl SataGen {
+    /// 从速率代号 (1-3) 创建
+    pub fn from_code(code: u16) -> Option<Self> {
+        match code {
+            1 => Some(Self::Gen1),
+            2 => Some(Self::Gen2),
+            3 => Some(Self::Gen3),
+            _ => None,
+        }
+    }
+
+    /// 链路速率 (Gbps)
+    pub fn speed_gbps(&self) -> f64 {
+        match self {
+            Self::Gen1 => 1.5,
+            Self::Gen2 => 3.0,
+            Self::Gen3 => 6.0,
+        }
+    }
+}
+
+/// 属性 9 (通电时间) 原始值的编码方式
+///
+/// 大多数设备以小时计数,部分 Fujitsu 和 Maxtor 设备以分钟计数,
+/// 部分旧款 Samsung 设备以半分钟计数,少数旧款 Fujitsu 设备以秒计数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PowerOnTimeEncoding {
+    /// 小时
+    #[default]
+    Hours,
+    /// 分钟
+    Minutes,
+    /// 半分钟 (30 秒)
+    HalfMinutes,
+    /// 秒
+    Seconds,
+}
+
+impl PowerOnTimeEncoding {
+    /// 对应的属性名称
+    pub fn attribute_name(&self) -> &'static str {
+        match self {
+            Self::Hours => "power-on-hours",
+            Self::Minutes => "power-on-minutes",
+            Self::HalfMinutes => "power-on-half-minutes",
+            Self::Seconds => "power-on-seconds",
+        }
+    }
+}
+
+/// 离线模式执行的测试类型
+///
+/// SMART EXECUTE OFF-LINE IMMEDIATE 的子命令 0-3,测试在设备后台运行,
+/// 期间设备仍然响应其他命令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OfflineTestType {
+    /// 离线数据收集例程
+    Offline = 0,
+    /// 短时自检
+    Short = 1,
+    /// 扩展自检
+    Extended = 2,
+    /// 传输自检
+    Conveyance = 3,
+}
+
+impl OfflineTestType {
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Offline => "offline",
+            Self::Short => "short",
+            Self::Extended => "extended",
+            Self::Conveyance => "conveyance",
+        }
+    }
+
+    /// 对应的自检类型,离线数据收集没有对应的自检
+    pub fn self_test(&self) -> Option<SmartSelfTest> {
+        match self {
+            Self::Offline => None,
+            Self::Short => Some(SmartSelfTest::Short),
+            Self::Extended => Some(SmartSelfTest::Extended),
+            Self::Conveyance => Some(SmartSelfTest::Conveyance),
+        }
+    }
+}
+
+/// 设备电源模式
+///
+/// 由 CHECK POWER MODE 命令返回的 SECTOR COUNT 寄存器值映射而来
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PowerMode {
+    /// 活动状态 (0xFF)
+    Active,
+    /// 空闲状态 (0x80 - 0x83)
+    Idle,
+    /// 待机状态 (0x00)
+    Standby,
+    /// 睡眠状态 (0x01)
+    Sleep,
+    /// 未知的设备特定值
+    Unknown(u8),
+}
+
+impl PowerMode {
+    /// 从 SECTOR COUNT 寄存器值创建
+    pub fn from_register(value: u8) -> Self {
+        match value {
+            0xFF => Self::Active,
+            0x80..=0x83 => Self::Idle,
+            0x00 => Self::Standby,
+            0x01 => Self::Sleep,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// 设备是否处于唤醒状态 (活动或空闲)
+    pub fn is_awake(&self) -> bool {
+        matches!(self, Self::Active | Self::Idle)
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "活动",
+            Self::Idle => "空闲",
+            Self::Standby => "待机",
+            Self::Sleep => "睡眠",
+            Self::Unknown(_) => "未知",
+        }
+    }
+}
+
+/// 待机计时器
+///
+/// 对应 IDLE 命令 SECTOR COUNT 寄存器中的计时器代码 (ACS-3 Table 44):
+///
+/// | 代码        | 超时时间                        |
+/// |-------------|---------------------------------|
+/// | 0x00        | 禁用计时器                      |
+/// | 0x01 - 0xF0 | 代码 × 5 秒 (5 秒 - 20 分钟)    |
+/// | 0xF1 - 0xFB | (代码 - 240) × 30 分钟 (30 分钟 - 5.5 小时) |
+/// | 0xFC        | 21 分钟                         |
+/// | 0xFD        | 8 - 12 小时之间 (厂商特定)      |
+/// | 0xFE        | 保留                            |
+/// | 0xFF        | 21 分 15 秒                     |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StandbyTimer {
+    /// 禁用待机计时器
+    Disabled,
+    /// 以分钟表示的超时时间
+    ///
+    /// 仅支持 1-21 分钟以及 30-330 分钟中 30 的整数倍
+    Minutes(u16),
+    /// 原始计时器代码 (厂商特定或无法用整分钟表示的值)
+    VendorSpecific(u8),
+}
+
+impl StandbyTimer {
+    /// 转换为 IDLE 命令使用的计时器代码
+    pub fn to_code(&self) -> Option<u8> {
+        match *self {
+            Self::Disabled => Some(0x00),
+            Self::Minutes(m @ 1..=20) => Some((m * 12) as u8),
+            Self::Minutes(21) => Some(0xFC),
+            Self::Minutes(m @ 30..=330) if m.is_multiple_of(30) => Some((240 + m / 30) as u8),
+            Self::Minutes(_) => None,
+            Self::VendorSpecific(code) => Some(code),
+        }
+    }
+
+    /// 从计时器代码创建
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0x00 => Self::Disabled,
+            0x01..=0xF0 if code.is_multiple_of(12) => Self::Minutes(code as u16 / 12),
+            0xF1..=0xFB => Self::Minutes((code as u16 - 240) * 30),
+            0xFC => Self::Minutes(21),
+            other => Self::VendorSpecific(other),
+        }
+    }
+}
+
+/// 设备唤醒策略
+///
+/// 控制读取 SMART 数据前是否允许唤醒处于待机/睡眠状态的设备
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum WakeupPolicy {
+    /// 总是直接读取 (可能唤醒设备)
+    #[default]
+    AlwaysWake,
+    /// 先发送 CHECK POWER MODE,设备处于待机/睡眠时拒绝读取
+    NeverWake,
+}
+
 /// 离线数据收集状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum OfflineDataCollectionStatus {
     /// 从未启动
     Never,
@@ -95,8 +707,50 @@ pub enum OfflineDataCollectionStatus {
     Unknown,
 }
 
+/// 离线数据收集能力
+///
+/// 由 SMART 数据字节 367 (离线数据收集能力位) 推导:
+/// - bit 0: 支持 EXECUTE OFF-LINE IMMEDIATE
+/// - bit 1: 支持自动离线数据收集
+/// - bit 3: 支持离线读扫描
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OfflineDataCollectionCapability {
+    /// 不支持离线数据收集
+    NotSupported,
+    /// 支持立即执行离线数据收集
+    ExecuteImmediate,
+    /// 支持立即执行和自动离线数据收集
+    Automatic,
+    /// 仅支持由厂商自行安排的离线扫描
+    PerVendor,
+}
+
+impl OfflineDataCollectionCapability {
+    /// 从 SMART 数据字节 367 解析
+    pub fn from_capability_byte(value: u8) -> Self {
+        if value & 0x01 == 0 {
+            if value & 0x08 != 0 {
+                Self::PerVendor
+            } else {
+                Self::NotSupported
+            }
+        } else if value & 0x02 != 0 {
+            Self::Automatic
+        } else {
+            Self::ExecuteImmediate
+        }
+    }
+
+    /// 是否支持 EXECUTE OFF-LINE IMMEDIATE
+    pub fn supports_execute_immediate(&self) -> bool {
+        matches!(self, Self::ExecuteImmediate | Self::Automatic)
+    }
+}
+
 /// 自检执行状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SelfTestExecutionStatus {
     /// 成功或从未运行
     SuccessOrNever = 0,
@@ -122,6 +776,7 @@ pub enum SelfTestExecutionStatus {
 
 /// SMART 属性单位
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AttributeUnit {
     /// 未知
     Unknown,
@@ -139,10 +794,15 @@ pub enum AttributeUnit {
     Percent,
     /// 兆字节
     Megabytes,
+    /// 吉字节 (部分企业级 SSD 直接以 GB 报告写入量)
+    GigaBytesWritten,
 }
 
 /// SMART 整体健康状态
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// 变体按严重程度递增排列,`Good` 最小,`BadStatus` 最大
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum SmartOverall {
     /// 良好
     Good,
@@ -158,6 +818,101 @@ pub enum SmartOverall {
     BadStatus,
 }
 
+/// 故障风险等级
+///
+/// 变体按风险递增排列,参见 [`crate::estimate_failure_risk`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FailureRisk {
+    /// 低
+    Low,
+    /// 中等
+    Moderate,
+    /// 高
+    High,
+    /// 严重
+    Critical,
+}
+
+impl FailureRisk {
+    /// 由风险分数映射
+    pub fn from_score(score: u32) -> Self {
+        match score {
+            0 => Self::Low,
+            1..=2 => Self::Moderate,
+            3..=5 => Self::High,
+            _ => Self::Critical,
+        }
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "低",
+            Self::Moderate => "中等",
+            Self::High => "高",
+            Self::Critical => "严重",
+        }
+    }
+}
+
+/// 属性异常类型
+///
+/// 参见 [`crate::SmartAttributeParsedData::failure_kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FailureKind {
+    /// 当前值不高于阈值
+    ThresholdNow,
+    /// 最差值曾经不高于阈值,当前值已恢复
+    ThresholdInThePast,
+    /// 坏扇区计数 (属性 5、197) 非零,与阈值无关
+    BadSectors,
+}
+
+impl FailureKind {
+    /// 是否为当前存在的问题
+    pub fn is_current(&self) -> bool {
+        !matches!(self, Self::ThresholdInThePast)
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ThresholdNow => "当前超过阈值",
+            Self::ThresholdInThePast => "过去超过阈值",
+            Self::BadSectors => "存在坏扇区",
+        }
+    }
+}
+
+impl SmartOverall {
+    /// 返回两者中更严重的状态
+    pub fn worst(a: Self, b: Self) -> Self {
+        a.max(b)
+    }
+
+    /// 是否为严重状态 (当前属性超过阈值、大量坏扇区或自评估为负面)
+    pub fn is_critical(&self) -> bool {
+        matches!(
+            self,
+            Self::BadAttributeNow | Self::BadSectorMany | Self::BadStatus
+        )
+    }
+
+    /// 转换为字符串描述
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Good => "良好",
+            Self::BadAttributeInThePast => "过去有属性超过阈值",
+            Self::BadSector => "存在坏扇区",
+            Self::BadAttributeNow => "当前有属性超过阈值",
+            Self::BadSectorMany => "存在大量坏扇区",
+            Self::BadStatus => "SMART 自评估为负面",
+        }
+    }
+}
+
 impl OfflineDataCollectionStatus {
     /// 转换为字符串描述
     pub fn as_str(&self) -> &'static str {
@@ -174,6 +929,21 @@ impl OfflineDataCollectionStatus {
 }
 
 impl SelfTestExecutionStatus {
+    /// 上次自检是否失败
+    ///
+    /// 被主机中止或被重置中断不算失败
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Fatal
+                | Self::ErrorUnknown
+                | Self::ErrorElectrical
+                | Self::ErrorServo
+                | Self::ErrorRead
+                | Self::ErrorHandling
+        )
+    }
+
     /// 转换为字符串描述
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -203,6 +973,7 @@ impl AttributeUnit {
             Self::SmallPercent => "小百分比",
             Self::Percent => "百分比",
             Self::Megabytes => "MB",
+            Self::GigaBytesWritten => "GB",
         }
     }
 }
@@ -211,6 +982,34 @@ impl AttributeUnit {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_smart_self_test_from_str() {
+        assert_eq!(
+            "short".parse::<SmartSelfTest>().unwrap(),
+            SmartSelfTest::Short
+        );
+        assert_eq!(
+            "Extended".parse::<SmartSelfTest>().unwrap(),
+            SmartSelfTest::Extended
+        );
+        assert_eq!(
+            "CONVEYANCE".parse::<SmartSelfTest>().unwrap(),
+            SmartSelfTest::Conveyance
+        );
+        assert_eq!(
+            "abort".parse::<SmartSelfTest>().unwrap(),
+            SmartSelfTest::Abort
+        );
+        assert!(matches!(
+            "long".parse::<SmartSelfTest>(),
+            Err(Error::InvalidData(_))
+        ));
+
+        for test in [SmartSelfTest::Short, SmartSelfTest::Abort] {
+            assert_eq!(test.to_string().parse::<SmartSelfTest>().unwrap(), test);
+        }
+    }
+
     #[test]
     fn test_smart_self_test_values() {
         assert_eq!(SmartSelfTest::Short as u8, 1);
@@ -218,6 +1017,150 @@ mod tests {
         assert_eq!(SmartSelfTest::Abort as u8, 127);
     }
 
+    #[test]
+    fn test_security_state_from_word() {
+        assert_eq!(SecurityState::from_word(0x0000), None);
+        assert_eq!(
+            SecurityState::from_word(0x0001),
+            Some(SecurityState::Disabled)
+        );
+        assert_eq!(
+            SecurityState::from_word(0x0009),
+            Some(SecurityState::Frozen)
+        );
+        assert_eq!(
+            SecurityState::from_word(0x0007),
+            Some(SecurityState::Locked)
+        );
+        assert_eq!(
+            SecurityState::from_word(0x0003),
+            Some(SecurityState::Enabled)
+        );
+        assert_eq!(
+            SecurityState::from_word(0x000B),
+            Some(SecurityState::EnabledFrozen)
+        );
+        assert!(SecurityState::EnabledFrozen.is_frozen());
+        assert!(!SecurityState::Locked.is_frozen());
+    }
+
+    #[test]
+    fn test_rotation_rate_from_word() {
+        assert_eq!(RotationRate::from_word(0x0000), RotationRate::Unknown);
+        assert_eq!(RotationRate::from_word(0x0001), RotationRate::Ssd);
+        assert_eq!(RotationRate::from_word(0x0400), RotationRate::Unknown);
+        assert_eq!(RotationRate::from_word(7200), RotationRate::Rpm(7200));
+        assert_eq!(RotationRate::from_word(0xFFFF), RotationRate::Unknown);
+        assert!(RotationRate::Ssd.is_ssd());
+        assert!(!RotationRate::Rpm(5400).is_ssd());
+    }
+
+    #[test]
+    fn test_acoustic_level_values() {
+        assert_eq!(AcousticLevel::from_value(0x80), Some(AcousticLevel::Quiet));
+        assert_eq!(
+            AcousticLevel::from_value(0xFE),
+            Some(AcousticLevel::MaxPerformance)
+        );
+        assert_eq!(AcousticLevel::from_value(0x7F), None);
+        assert_eq!(AcousticLevel::from_value(0xFF), None);
+        assert_eq!(AcousticLevel::Moderate(0x80).to_value(), None);
+
+        for value in 0x80..=0xFEu8 {
+            let level = AcousticLevel::from_value(value).unwrap();
+            assert_eq!(level.to_value(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_offline_test_type() {
+        assert_eq!(OfflineTestType::Offline as u8, 0);
+        assert_eq!(OfflineTestType::Conveyance as u8, 3);
+        assert_eq!(OfflineTestType::Offline.self_test(), None);
+        assert_eq!(
+            OfflineTestType::Extended.self_test(),
+            Some(SmartSelfTest::Extended)
+        );
+    }
+
+    #[test]
+    fn test_power_mode_from_register() {
+        assert_eq!(PowerMode::from_register(0xFF), PowerMode::Active);
+        assert_eq!(PowerMode::from_register(0x80), PowerMode::Idle);
+        assert_eq!(PowerMode::from_register(0x00), PowerMode::Standby);
+        assert_eq!(PowerMode::from_register(0x01), PowerMode::Sleep);
+        assert_eq!(PowerMode::from_register(0x40), PowerMode::Unknown(0x40));
+
+        assert!(PowerMode::Active.is_awake());
+        assert!(PowerMode::Idle.is_awake());
+        assert!(!PowerMode::Standby.is_awake());
+        assert!(!PowerMode::Sleep.is_awake());
+    }
+
+    #[test]
+    fn test_standby_timer_codes() {
+        assert_eq!(StandbyTimer::Disabled.to_code(), Some(0x00));
+        assert_eq!(StandbyTimer::Minutes(1).to_code(), Some(12));
+        assert_eq!(StandbyTimer::Minutes(20).to_code(), Some(0xF0));
+        assert_eq!(StandbyTimer::Minutes(21).to_code(), Some(0xFC));
+        assert_eq!(StandbyTimer::Minutes(30).to_code(), Some(0xF1));
+        assert_eq!(StandbyTimer::Minutes(330).to_code(), Some(0xFB));
+        assert_eq!(StandbyTimer::Minutes(25).to_code(), None);
+        assert_eq!(StandbyTimer::VendorSpecific(0xFD).to_code(), Some(0xFD));
+
+        for code in 0..=0xFFu8 {
+            let timer = StandbyTimer::from_code(code);
+            assert_eq!(timer.to_code(), Some(code));
+        }
+    }
+
+    #[test]
+    fn test_wakeup_policy_default() {
+        assert_eq!(WakeupPolicy::default(), WakeupPolicy::AlwaysWake);
+    }
+
+    #[test]
+    fn test_offline_data_collection_capability() {
+        use OfflineDataCollectionCapability as Cap;
+        assert_eq!(Cap::from_capability_byte(0x00), Cap::NotSupported);
+        assert_eq!(Cap::from_capability_byte(0x08), Cap::PerVendor);
+        assert_eq!(Cap::from_capability_byte(0x59), Cap::ExecuteImmediate);
+        assert_eq!(Cap::from_capability_byte(0x5B), Cap::Automatic);
+
+        assert!(Cap::Automatic.supports_execute_immediate());
+        assert!(!Cap::PerVendor.supports_execute_immediate());
+    }
+
+    #[test]
+    fn test_smart_overall_ordering() {
+        use SmartOverall::*;
+        let ordered = [
+            Good,
+            BadAttributeInThePast,
+            BadSector,
+            BadAttributeNow,
+            BadSectorMany,
+            BadStatus,
+        ];
+
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+                assert_eq!(SmartOverall::worst(*a, *b), ordered[i.max(j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_smart_overall_is_critical() {
+        assert!(!SmartOverall::Good.is_critical());
+        assert!(!SmartOverall::BadAttributeInThePast.is_critical());
+        assert!(!SmartOverall::BadSector.is_critical());
+        assert!(SmartOverall::BadAttributeNow.is_critical());
+        assert!(SmartOverall::BadSectorMany.is_critical());
+        assert!(SmartOverall::BadStatus.is_critical());
+    }
+
     #[test]
     fn test_status_strings() {
         assert!(!OfflineDataCollectionStatus::Success.as_str().is_empty());
@@ -3,7 +3,7 @@
 mod constants;
 mod enums;
 mod structs;
-mod units;
+pub mod units;
 
 pub use constants::*;
 pub use enums::*;
@@ -1,9 +1,11 @@
 //! 数据结构定义
 
 use super::*;
+use std::fmt;
 
 /// IDENTIFY 解析数据
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct IdentifyParsedData {
     /// 序列号
     pub serial: String,
@@ -11,10 +13,478 @@ pub struct IdentifyParsedData {
     pub firmware: String,
     /// 型号
     pub model: String,
+    /// 逻辑扇区大小 (字节,字 106、117-118),未报告时为 512
+    pub logical_sector_size: u32,
+    /// 物理扇区大小 (字节,字 106),未报告时等于逻辑扇区大小
+    pub physical_sector_size: u32,
+    /// 逻辑扇区 0 在物理扇区内的偏移 (逻辑扇区数,字 209)
+    pub sector_alignment_offset: u16,
+    /// 介质旋转速率 (字 217),未报告时为 `None`
+    pub rotation_rate: Option<RotationRate>,
+    /// 全球唯一名称 (WWN,字 108-111),设备不支持或 NAA 无效时为 `None`
+    pub wwn: Option<u64>,
+    /// 支持的最高 ATA 规范版本 (字 80/81),未报告时为 `None`
+    pub ata_version: Option<AtaVersion>,
+    /// SATA 能力和当前链路速率 (字 76-77),PATA 设备为 `None`
+    pub sata: Option<SataInfo>,
+    /// NCQ 队列深度 (字 75 bit 4:0 加 1),不支持 NCQ 时为 `None`
+    pub queue_depth: Option<u8>,
+    /// TRIM (DATA SET MANAGEMENT) 支持情况 (字 69、105、169),不支持时为 `None`
+    pub trim: Option<TrimSupport>,
+    /// ATA 安全功能详细状态 (字 128、89、90),不支持安全功能时为 `None`
+    pub security: Option<SecurityStatus>,
+    /// 高级电源管理 (APM,字 83、86、91),不支持时为 `None`
+    pub apm: Option<ApmInfo>,
+    /// 自动声学管理 (AAM,字 83、86、94),不支持或未报告时为 `None`
+    pub aam: Option<AamInfo>,
+    /// 易失性写缓存 (字 82/85 bit 5)
+    pub write_cache: FeatureState,
+    /// 预读 (字 82/85 bit 6)
+    pub read_lookahead: FeatureState,
+    /// 命令集支持/启用标志 (字 82-87)
+    pub features: FeatureFlags,
+    /// SANITIZE 功能支持情况 (字 59 bit 15:10),不支持时为 `None`
+    pub sanitize: Option<SanitizeSupport>,
+    /// 分区 (SMR) 能力 (字 69 bit 1:0),SMR 设备上属性 197/198 可能因后台重写而频繁变化
+    pub zoned: ZonedCapability,
+    /// 标称外形尺寸 (字 168),未报告时为 `None`
+    pub form_factor: Option<FormFactor>,
+    /// 传输类型和 SATA 规范版本 (字 222)
+    pub transport: Transport,
+    /// 附加产品标识 (字 170-173),部分 OEM 设备或 USB 桥接后的设备在这里报告真实型号
+    pub additional_product_id: Option<String>,
+}
+
+/// 自动声学管理 (AAM) 信息
+///
+/// ACS-3 起已废弃,但很多在用的设备仍然报告
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AamInfo {
+    /// 是否已启用 (字 86 bit 9)
+    pub enabled: bool,
+    /// 当前级别 (字 94 bit 7:0),保留值时为 `None`
+    pub current: Option<AcousticLevel>,
+    /// 厂商推荐级别 (字 94 bit 15:8),保留值时为 `None`
+    pub recommended: Option<AcousticLevel>,
+}
+
+/// IDENTIFY 命令集支持/启用标志
+///
+/// 字 82-84 表示支持,字 85-87 表示启用。
+/// 字 83、84、87 需要 bit 15:14 = 01b 才有效,无效时视为不支持
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FeatureFlags {
+    /// SMART (字 82/85 bit 0)
+    pub smart: FeatureState,
+    /// DOWNLOAD MICROCODE (字 83/86 bit 0)
+    pub download_microcode: FeatureState,
+    /// 48 位 LBA (字 83/86 bit 10)
+    pub lba48: FeatureState,
+    /// FLUSH CACHE EXT (字 83/86 bit 13)
+    pub flush_cache_ext: FeatureState,
+    /// SMART 错误日志 (字 84/87 bit 0)
+    pub smart_error_logging: FeatureState,
+    /// SMART 自检 (字 84/87 bit 1)
+    pub smart_self_test: FeatureState,
+    /// 通用日志 (GPL,字 84/87 bit 5)
+    pub gpl: FeatureState,
+    /// WRITE DMA FUA EXT (字 84/87 bit 6)
+    pub write_dma_fua: FeatureState,
+}
+
+impl FeatureFlags {
+    /// 从 IDENTIFY 字 82-87 解析,`words[0]` 为字 82
+    pub fn from_words(words: [u16; 6]) -> Self {
+        // bit 15:14 = 01b 才有效
+        let checked = |word: u16| if word & 0xC000 == 0x4000 { word } else { 0 };
+
+        let [word82, word83, word84, word85, word86, word87] = words;
+        let word83 = checked(word83);
+        // 字 86 没有有效性位,跟随字 83
+        let word86 = if word83 == 0 { 0 } else { word86 };
+        let word84 = checked(word84);
+        let word87 = checked(word87);
+
+        Self {
+            smart: FeatureState::from_words(word82, word85, 0),
+            download_microcode: FeatureState::from_words(word83, word86, 0),
+            lba48: FeatureState::from_words(word83, word86, 10),
+            flush_cache_ext: FeatureState::from_words(word83, word86, 13),
+            smart_error_logging: FeatureState::from_words(word84, word87, 0),
+            smart_self_test: FeatureState::from_words(word84, word87, 1),
+            gpl: FeatureState::from_words(word84, word87, 5),
+            write_dma_fua: FeatureState::from_words(word84, word87, 6),
+        }
+    }
+}
+
+/// SANITIZE 功能支持情况
+///
+/// 由 IDENTIFY 字 59 给出,bit 7:0 为旧的多扇区设置,不属于 SANITIZE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SanitizeSupport {
+    /// 支持 CRYPTO SCRAMBLE EXT (字 59 bit 13)
+    pub crypto_scramble: bool,
+    /// 支持 OVERWRITE EXT (字 59 bit 14)
+    pub overwrite: bool,
+    /// 支持 BLOCK ERASE EXT (字 59 bit 15)
+    pub block_erase: bool,
+    /// 支持 SANITIZE ANTIFREEZE LOCK EXT (ACS-3,字 59 bit 10)
+    pub antifreeze_lock: bool,
+    /// SANITIZE 期间允许执行 ACS-3 规定的命令 (字 59 bit 11)
+    pub commands_allowed: bool,
+}
+
+/// 高级电源管理 (APM) 信息
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ApmInfo {
+    /// 是否已启用 (字 86 bit 3)
+    pub enabled: bool,
+    /// 当前级别 (字 91 bit 7:0,1 - 254),未启用或未报告时为 `None`
+    pub level: Option<u8>,
+}
+
+impl ApmInfo {
+    /// 是否为允许磁头停转的激进级别 (1 - 127),容易导致 load-cycle-count 快速增长
+    pub fn is_aggressive(&self) -> bool {
+        self.level.is_some_and(|level| level <= 127)
+    }
+}
+
+/// ATA 安全功能状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SecurityStatus {
+    /// 已设置用户密码 (字 128 bit 1)
+    pub enabled: bool,
+    /// 已锁定,需要解锁才能访问数据 (字 128 bit 2)
+    pub locked: bool,
+    /// 已冻结,断电或硬件复位前无法修改安全设置 (字 128 bit 3)
+    pub frozen: bool,
+    /// 密码尝试次数已用尽,需要断电重试 (字 128 bit 4)
+    pub count_expired: bool,
+    /// 支持增强安全擦除 (字 128 bit 5)
+    pub enhanced_erase_supported: bool,
+    /// 普通安全擦除所需时间 (分钟,字 89),未报告时为 `None`
+    pub erase_time_minutes: Option<u16>,
+    /// 增强安全擦除所需时间 (分钟,字 90),未报告时为 `None`
+    pub enhanced_erase_time_minutes: Option<u16>,
+}
+
+impl SecurityStatus {
+    /// 从 IDENTIFY 字 128 解析,不支持安全功能时返回 `None`
+    ///
+    /// 字 128 不包含擦除时间,`erase_time_minutes` 和 `enhanced_erase_time_minutes` 为 `None`
+    pub fn from_word(word: u16) -> Option<Self> {
+        // bit 0: 支持
+        if word & 0x0001 == 0 {
+            return None;
+        }
+
+        Some(Self {
+            enabled: word & (1 << 1) != 0,
+            locked: word & (1 << 2) != 0,
+            frozen: word & (1 << 3) != 0,
+            count_expired: word & (1 << 4) != 0,
+            enhanced_erase_supported: word & (1 << 5) != 0,
+            erase_time_minutes: None,
+            enhanced_erase_time_minutes: None,
+        })
+    }
+
+    /// 汇总后的安全状态
+    pub fn state(&self) -> SecurityState {
+        match (self.enabled, self.locked, self.frozen) {
+            (_, true, _) => SecurityState::Locked,
+            (true, false, true) => SecurityState::EnabledFrozen,
+            (true, false, false) => SecurityState::Enabled,
+            (false, false, true) => SecurityState::Frozen,
+            (false, false, false) => SecurityState::Disabled,
+        }
+    }
+}
+
+/// TRIM 支持情况
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrimSupport {
+    /// TRIM 后读取结果是确定的 (字 69 bit 14)
+    pub deterministic: bool,
+    /// TRIM 后读取结果为全零 (字 69 bit 5)
+    pub zeroes_after_trim: bool,
+    /// 单个 DATA SET MANAGEMENT 命令最多可传输的 512 字节块数 (字 105),
+    /// 未报告时为 `None`
+    pub max_dsm_blocks: Option<u16>,
+}
+
+/// SATA 能力信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SataInfo {
+    /// 支持的链路速率代 (字 76 bit 3:1)
+    pub supported_gens: Vec<SataGen>,
+    /// 当前协商的链路速率代 (字 77 bit 3:1),未报告时为 `None`
+    pub current_gen: Option<SataGen>,
+    /// 是否支持 NCQ (字 76 bit 8)
+    pub ncq_supported: bool,
+}
+
+impl SataInfo {
+    /// 从 IDENTIFY 字 76/77 解析,字 76 为 0x0000 或 0xFFFF (PATA 设备) 时返回 `None`
+    pub fn from_words(capabilities: u16, current: u16) -> Option<Self> {
+        if capabilities == 0x0000 || capabilities == 0xFFFF {
+            return None;
+        }
+
+        let supported_gens = (1..=3)
+            .filter(|bit| capabilities & (1 << bit) != 0)
+            .filter_map(SataGen::from_code)
+            .collect();
+
+        // 字 77 同样可能未实现
+        let current_gen = if current == 0xFFFF {
+            None
+        } else {
+            SataGen::from_code((current >> 1) & 0x7)
+        };
+
+        Some(Self {
+            supported_gens,
+            current_gen,
+            ncq_supported: capabilities & (1 << 8) != 0,
+        })
+    }
+
+    /// 支持的最高链路速率代
+    pub fn max_gen(&self) -> Option<SataGen> {
+        self.supported_gens.iter().max().copied()
+    }
+
+    /// 当前链路是否低于设备支持的最高速率 (可能是线缆或接口问题)
+    pub fn is_downshifted(&self) -> bool {
+        matches!((self.current_gen, self.max_gen()), (Some(current), Some(max)) if current < max)
+    }
+}
+
+impl IdentifyParsedData {
+    /// 格式化的 WWN,例如 `naa.5000c500a1b2c3d4`,与 udev 的 by-id 链接一致
+    pub fn wwn_string(&self) -> Option<String> {
+        self.wwn.map(|wwn| format!("naa.{:016x}", wwn))
+    }
+
+    /// 当前自动声学管理级别,不支持或未启用时为 `None`,参见 [`IdentifyParsedData::aam`]
+    pub fn acoustic_level(&self) -> Option<AcousticLevel> {
+        self.aam
+            .filter(|aam| aam.enabled)
+            .and_then(|aam| aam.current)
+    }
+
+    /// ATA 安全状态,设备不支持安全功能时为 `None`,参见 [`SecurityStatus::state`]
+    pub fn security_state(&self) -> Option<SecurityState> {
+        self.security.map(|security| security.state())
+    }
+}
+
+/// ATA 规范版本
+///
+/// 按主版本号排序,主版本相同时再按次版本代码排序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AtaVersion {
+    /// 主版本号 (字 80 中最高的置位 bit),例如 8 为 ATA8-ACS
+    pub major: u8,
+    /// 次版本代码 (字 81),0 表示未报告
+    pub minor: u16,
+}
+
+/// 字 80 各 bit 对应的规范名称,下标为 bit 号
+const ATA_MAJOR_NAMES: [&str; 13] = [
+    "",
+    "ATA-1",
+    "ATA-2",
+    "ATA-3",
+    "ATA/ATAPI-4",
+    "ATA/ATAPI-5",
+    "ATA/ATAPI-6",
+    "ATA/ATAPI-7",
+    "ATA8-ACS",
+    "ACS-2",
+    "ACS-3",
+    "ACS-4",
+    "ACS-5",
+];
+
+/// 字 81 次版本代码表 (ACS-3 Table 47)
+const ATA_MINOR_NAMES: &[(u16, &str)] = &[
+    (0x0001, "ATA-1 X3T9.2/781D prior to revision 4"),
+    (0x0002, "ATA-1 published, ANSI X3.221-1994"),
+    (0x0003, "ATA-1 X3T9.2/781D revision 4"),
+    (0x0004, "ATA-2 published, ANSI X3.279-1996"),
+    (0x0005, "ATA-2 X3T10/948D prior to revision 2k"),
+    (0x0006, "ATA-3 X3T10/2008D revision 1"),
+    (0x0007, "ATA-2 X3T10/948D revision 2k"),
+    (0x0008, "ATA-3 X3T10/2008D revision 0"),
+    (0x0009, "ATA-2 X3T10/948D revision 3"),
+    (0x000A, "ATA-3 published, ANSI X3.298-1997"),
+    (0x000B, "ATA-3 X3T10/2008D revision 6"),
+    (0x000C, "ATA-3 X3T13/2008D revision 7 and 7a"),
+    (0x000D, "ATA/ATAPI-4 X3T13/1153D revision 6"),
+    (0x000E, "ATA/ATAPI-4 T13/1153D revision 13"),
+    (0x000F, "ATA/ATAPI-4 X3T13/1153D revision 7"),
+    (0x0010, "ATA/ATAPI-4 T13/1153D revision 18"),
+    (0x0011, "ATA/ATAPI-4 T13/1153D revision 15"),
+    (0x0012, "ATA/ATAPI-4 published, ANSI NCITS 317-1998"),
+    (0x0013, "ATA/ATAPI-5 T13/1321D revision 3"),
+    (0x0014, "ATA/ATAPI-4 T13/1153D revision 14"),
+    (0x0015, "ATA/ATAPI-5 T13/1321D revision 1"),
+    (0x0016, "ATA/ATAPI-5 published, ANSI NCITS 340-2000"),
+    (0x0017, "ATA/ATAPI-4 T13/1153D revision 17"),
+    (0x0018, "ATA/ATAPI-6 T13/1410D revision 0"),
+    (0x0019, "ATA/ATAPI-6 T13/1410D revision 3a"),
+    (0x001A, "ATA/ATAPI-7 T13/1532D revision 1"),
+    (0x001B, "ATA/ATAPI-6 T13/1410D revision 2"),
+    (0x001C, "ATA/ATAPI-6 T13/1410D revision 1"),
+    (0x001D, "ATA/ATAPI-7 published, ANSI INCITS 397-2005"),
+    (0x001E, "ATA/ATAPI-7 T13/1532D revision 0"),
+    (0x001F, "ACS-3 T13/2161-D revision 3b"),
+    (0x0021, "ATA/ATAPI-7 T13/1532D revision 4a"),
+    (0x0022, "ATA/ATAPI-6 published, ANSI INCITS 361-2002"),
+    (0x0027, "ATA8-ACS T13/1699-D revision 3c"),
+    (0x0028, "ATA8-ACS T13/1699-D revision 6"),
+    (0x0029, "ATA8-ACS T13/1699-D revision 4"),
+    (0x0031, "ACS-2 T13/2015-D revision 2"),
+    (0x0033, "ATA8-ACS T13/1699-D revision 3e"),
+    (0x0039, "ATA8-ACS T13/1699-D revision 4c"),
+    (0x0042, "ATA8-ACS T13/1699-D revision 3f"),
+    (0x0052, "ATA8-ACS T13/1699-D revision 3b"),
+    (0x005E, "ACS-4 T13/BSR INCITS 529 revision 5"),
+    (0x006D, "ACS-3 T13/2161-D revision 5"),
+    (0x0082, "ACS-2 published, ANSI INCITS 482-2012"),
+    (0x0107, "ATA8-ACS T13/1699-D revision 2d"),
+    (0x010A, "ACS-3 published, ANSI INCITS 522-2014"),
+    (0x0110, "ACS-2 T13/2015-D revision 3"),
+    (0x011B, "ACS-3 T13/2161-D revision 4"),
+];
+
+impl AtaVersion {
+    /// 从 IDENTIFY 字 80/81 解析,字 80 为 0x0000 或 0xFFFF 时返回 `None`
+    pub fn from_words(major: u16, minor: u16) -> Option<Self> {
+        if major == 0x0000 || major == 0xFFFF {
+            return None;
+        }
+
+        // bit 15:13 保留
+        let bits = major & 0x1FFE;
+        if bits == 0 {
+            return None;
+        }
+
+        Some(Self {
+            major: bits.ilog2() as u8,
+            minor: if minor == 0xFFFF { 0 } else { minor },
+        })
+    }
+
+    /// 主版本名称,例如 `ACS-3`
+    pub fn name(&self) -> &'static str {
+        ATA_MAJOR_NAMES
+            .get(self.major as usize)
+            .copied()
+            .unwrap_or("未知")
+    }
+
+    /// 次版本描述,未报告或不在代码表中时为 `None`
+    pub fn minor_name(&self) -> Option<&'static str> {
+        ATA_MINOR_NAMES
+            .iter()
+            .find(|(code, _)| *code == self.minor)
+            .map(|(_, name)| *name)
+    }
+
+    /// 是否支持通用日志 (READ LOG EXT),该功能从 ATA/ATAPI-6 开始引入
+    pub fn supports_gpl(&self) -> bool {
+        self.major >= 6
+    }
+}
+
+impl fmt::Display for AtaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.minor_name() {
+            Some(minor) => write!(f, "{} ({})", self.name(), minor),
+            None => f.write_str(self.name()),
+        }
+    }
+}
+
+/// SMART 日志目录 (SMART READ LOG 日志地址 0x00)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SmartLogDirectory {
+    /// 目录版本号,为 1 时表示支持多扇区日志
+    pub version: u16,
+    /// 可用的日志地址及其扇区数,按地址排序,不含扇区数为 0 的日志
+    pub logs: Vec<(u8, u16)>,
+}
+
+impl SmartLogDirectory {
+    /// 指定日志地址的扇区数,不可用时返回 0
+    pub fn sector_count(&self, log_address: u8) -> u16 {
+        self.logs
+            .iter()
+            .find(|(address, _)| *address == log_address)
+            .map_or(0, |(_, count)| *count)
+    }
+}
+
+/// SATA Phy 事件计数器 (GPL 日志 0x11)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PhyEventCounter {
+    /// 计数器 ID (bit 15 为厂商特定)
+    pub id: u16,
+    /// 计数值
+    pub value: u64,
+    /// 计数器已达到最大值 (所有位均为 1)
+    pub saturated: bool,
+}
+
+impl PhyEventCounter {
+    /// 计数器描述,厂商特定或未知的 ID 返回 `None`
+    pub fn description(&self) -> Option<&'static str> {
+        Some(match self.id {
+            0x001 => "Command failed due to ICRC error",
+            0x002 => "R_ERR response for data FIS",
+            0x003 => "R_ERR response for device-to-host data FIS",
+            0x004 => "R_ERR response for host-to-device data FIS",
+            0x005 => "R_ERR response for non-data FIS",
+            0x006 => "R_ERR response for device-to-host non-data FIS",
+            0x007 => "R_ERR response for host-to-device non-data FIS",
+            0x008 => "Device-to-host non-data FIS retries",
+            0x009 => "Transition from drive PhyRdy to drive PhyNRdy",
+            0x00A => "Device-to-host register FISes sent due to a COMRESET",
+            0x00B => "CRC errors within host-to-device FIS",
+            0x00D => "Non-CRC errors within host-to-device FIS",
+            0x00F => "R_ERR response for host-to-device data FIS, CRC",
+            0x010 => "R_ERR response for host-to-device data FIS, non-CRC",
+            0x012 => "R_ERR response for host-to-device non-data FIS, CRC",
+            0x013 => "R_ERR response for host-to-device non-data FIS, non-CRC",
+            _ => return None,
+        })
+    }
+
+    /// 是否为 CRC 相关的计数器,与 UDMA CRC 错误属性 (199) 对应
+    pub fn is_crc_error(&self) -> bool {
+        matches!(self.id, 0x001 | 0x00B | 0x00F | 0x012)
+    }
 }
 
 /// SMART 解析数据
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SmartParsedData {
     // 易失性数据
     /// 离线数据收集状态
@@ -25,6 +495,8 @@ pub struct SmartParsedData {
     pub self_test_execution_status: SelfTestExecutionStatus,
     /// 自检执行剩余百分比
     pub self_test_execution_percent_remaining: u32,
+    /// 自动保存/自动离线数据收集已启用 (字节 362 bit 7)
+    pub auto_save_enabled: bool,
 
     // 固定数据
     /// 短时和扩展自检可用
@@ -35,6 +507,8 @@ pub struct SmartParsedData {
     pub start_test_available: bool,
     /// 中止自检可用
     pub abort_test_available: bool,
+    /// 离线数据收集能力
+    pub offline_data_collection_capability: OfflineDataCollectionCapability,
 
     /// 短时自检轮询分钟数
     pub short_test_polling_minutes: u16,
@@ -44,8 +518,122 @@ pub struct SmartParsedData {
     pub conveyance_test_polling_minutes: u16,
 }
 
+/// SMART 属性解析上下文
+///
+/// 解析属性时需要的设备信息,用于合理性检查和厂商属性表查找
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AttributeContext {
+    /// 设备大小 (字节),未知时为 0,此时跳过扇区数检查
+    pub disk_size: u64,
+    /// 逻辑扇区大小 (字节)
+    pub sector_size: u32,
+    /// 设备型号,用于查找厂商属性表
+    pub model: Option<String>,
+    /// 介质旋转速率,用于跳过 SSD 上无意义的检查
+    pub rotation_rate: Option<RotationRate>,
+    /// 属性 9 的编码方式,参见 [`VendorAttributeTable::for_model`]
+    pub power_on_encoding: PowerOnTimeEncoding,
+}
+
+impl AttributeContext {
+    /// 创建上下文,扇区大小默认为 512
+    pub fn new(disk_size: u64) -> Self {
+        Self {
+            disk_size,
+            sector_size: 512,
+            model: None,
+            rotation_rate: None,
+            power_on_encoding: PowerOnTimeEncoding::Hours,
+        }
+    }
+
+    /// 设备是否为 SSD
+    pub fn is_ssd(&self) -> bool {
+        self.rotation_rate.is_some_and(|rate| rate.is_ssd())
+    }
+
+    /// 设备的逻辑扇区数,设备大小未知时为 0
+    pub fn sector_count(&self) -> u64 {
+        self.disk_size / u64::from(self.sector_size.max(1))
+    }
+}
+
+impl Default for AttributeContext {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// 厂商属性编码
+///
+/// 描述某个型号与通用属性表不同的原始值编码方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VendorAttributeTable {
+    /// 属性 9 (通电时间) 的编码方式
+    pub power_on_encoding: PowerOnTimeEncoding,
+}
+
+/// 属性 9 编码与通用属性表不同的型号 (型号前缀, 编码方式)
+static VENDOR_POWER_ON_ENCODINGS: &[(&str, PowerOnTimeEncoding)] = &[
+    ("FUJITSU MHT2", PowerOnTimeEncoding::Minutes),
+    ("FUJITSU MHV2", PowerOnTimeEncoding::Minutes),
+    ("FUJITSU MHW2", PowerOnTimeEncoding::Minutes),
+    ("FUJITSU MHY2", PowerOnTimeEncoding::Minutes),
+    ("FUJITSU MHR2040AT", PowerOnTimeEncoding::Seconds),
+    ("FUJITSU MHS20", PowerOnTimeEncoding::Seconds),
+    ("Maxtor 6B200", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 6B250", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 6B300", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 2B0", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 4G120J6", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 4G160J", PowerOnTimeEncoding::Minutes),
+    ("Maxtor 4D0", PowerOnTimeEncoding::Minutes),
+    ("SAMSUNG SV4012H", PowerOnTimeEncoding::HalfMinutes),
+    ("SAMSUNG SV0412H", PowerOnTimeEncoding::HalfMinutes),
+    ("SAMSUNG SV1204H", PowerOnTimeEncoding::HalfMinutes),
+    ("SAMSUNG SP40A2H", PowerOnTimeEncoding::HalfMinutes),
+    ("SAMSUNG SP80A4H", PowerOnTimeEncoding::HalfMinutes),
+    ("SAMSUNG SP8004H", PowerOnTimeEncoding::HalfMinutes),
+];
+
+impl VendorAttributeTable {
+    /// 通用编码
+    pub const fn standard() -> Self {
+        Self {
+            power_on_encoding: PowerOnTimeEncoding::Hours,
+        }
+    }
+
+    /// Fujitsu 移动硬盘,属性 9 以分钟计数
+    pub const fn fujitsu() -> Self {
+        Self {
+            power_on_encoding: PowerOnTimeEncoding::Minutes,
+        }
+    }
+
+    /// 旧款 Samsung 硬盘,属性 9 以半分钟计数
+    pub const fn samsung_legacy() -> Self {
+        Self {
+            power_on_encoding: PowerOnTimeEncoding::HalfMinutes,
+        }
+    }
+
+    /// 根据设备型号查找,未知型号使用通用编码
+    pub fn for_model(model: &str) -> Self {
+        VENDOR_POWER_ON_ENCODINGS
+            .iter()
+            .find(|(prefix, _)| model.starts_with(prefix))
+            .map_or(Self::standard(), |(_, power_on_encoding)| Self {
+                power_on_encoding: *power_on_encoding,
+            })
+    }
+}
+
 /// SMART 属性解析数据
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SmartAttributeParsedData {
     // 固定数据
     /// 属性 ID
@@ -54,6 +642,8 @@ pub struct SmartAttributeParsedData {
     pub name: &'static str,
     /// 格式化值的单位
     pub pretty_unit: AttributeUnit,
+    /// 厂商表覆盖的单位,与通用属性表一致时为 `None`
+    pub vendor_override_unit: Option<AttributeUnit>,
     /// 标志位
     pub flags: u16,
     /// 阈值
@@ -122,8 +712,53 @@ impl SmartParsedData {
     }
 }
 
+/// 错误发生前设备收到的命令
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SmartErrorCommand {
+    /// 命令码
+    pub command: u8,
+    /// FEATURES 寄存器
+    pub features: u16,
+    /// COUNT 寄存器
+    pub count: u16,
+    /// LBA
+    pub lba: u64,
+    /// DEVICE 寄存器
+    pub device: u8,
+    /// DEVICE CONTROL 寄存器
+    pub device_control: u8,
+    /// 自上电以来的时间戳 (毫秒)
+    pub timestamp_ms: u32,
+}
+
+/// SMART 错误日志条目
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SmartErrorLogEntry {
+    /// 错误编号,1 为设备记录的第一个错误
+    pub error_number: u32,
+    /// 发生错误时的累计通电小时数
+    pub lifetime_hours: u16,
+    /// 发生错误时的设备状态 (bit 3:0: 0 未知, 1 睡眠, 2 待机, 3 活动/空闲, 4 执行 SMART 离线或自检)
+    pub state: u8,
+    /// ERROR 寄存器
+    pub error: u8,
+    /// STATUS 寄存器
+    pub status: u8,
+    /// COUNT 寄存器
+    pub count: u16,
+    /// 出错的 LBA
+    pub lba: u64,
+    /// DEVICE 寄存器
+    pub device: u8,
+    /// 错误发生前的命令,按时间顺序排列,最后一个为出错的命令
+    pub commands: Vec<SmartErrorCommand>,
+}
+
 /// 磁盘统计信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DiskStatistics {
     /// 坏扇区总数
     pub bad_sectors: Option<u64>,
@@ -135,6 +770,182 @@ pub struct DiskStatistics {
     pub temperature: Option<Temperature>,
 }
 
+/// 异常属性
+///
+/// 由 [`crate::Disk::smart_failing_attributes`] 返回,说明属性异常的原因
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AttributeFailure {
+    /// 异常的属性
+    pub attribute: SmartAttributeParsedData,
+    /// 是否为预失败属性,预失败属性超过阈值说明设备即将故障
+    pub prefailure: bool,
+    /// 异常类型
+    pub kind: FailureKind,
+    /// 被突破的阈值,坏扇区类型为 `None`
+    pub threshold: Option<u8>,
+}
+
+impl AttributeFailure {
+    /// 是否为当前存在的问题,参见 [`FailureKind::is_current`]
+    pub fn is_current(&self) -> bool {
+        self.kind.is_current()
+    }
+}
+
+/// 整体健康状态及判定依据
+///
+/// 由 [`crate::Disk::smart_get_overall_detailed`] 返回。
+/// `Display` 输出一行简短说明,例如 `待处理扇区 2 个; 属性 5 (reallocated-sector-count) 过去超过阈值`,
+/// 可以直接用于告警通知
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OverallAssessment {
+    /// 整体健康状态
+    pub overall: SmartOverall,
+    /// SMART RETURN STATUS 的结果,`false` 表示设备自评估为负面
+    pub smart_status: bool,
+    /// 已重新分配扇区数 (属性 5)
+    pub reallocated_sectors: Option<u64>,
+    /// 待处理扇区数 (属性 197)
+    pub pending_sectors: Option<u64>,
+    /// 当前或过去超过阈值的预失败属性
+    pub attributes: Vec<AttributeFailure>,
+    /// 命令超时次数 (属性 188),只在超过 10 次、影响判定时记录
+    pub command_timeouts: Option<u64>,
+    /// UDMA CRC 错误数 (属性 199),只在超过 [`crate::Disk::set_crc_error_threshold`]
+    /// 设置的阈值时记录
+    pub udma_crc_errors: Option<u64>,
+    /// 上次自检失败时的执行状态,参见 [`SelfTestExecutionStatus::is_failure`]。
+    /// 自检结果不影响 `overall`
+    pub failed_self_test: Option<SelfTestExecutionStatus>,
+}
+
+impl OverallAssessment {
+    /// 坏扇区总数 (已重新分配扇区与待处理扇区之和)
+    pub fn bad_sectors(&self) -> u64 {
+        self.reallocated_sectors.unwrap_or(0) + self.pending_sectors.unwrap_or(0)
+    }
+}
+
+impl fmt::Display for OverallAssessment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut reasons = Vec::new();
+        if !self.smart_status {
+            reasons.push("SMART 自评估为负面".to_string());
+        }
+        if let Some(sectors) = self.reallocated_sectors.filter(|&n| n > 0) {
+            reasons.push(format!("已重新分配扇区 {} 个", sectors));
+        }
+        if let Some(sectors) = self.pending_sectors.filter(|&n| n > 0) {
+            reasons.push(format!("待处理扇区 {} 个", sectors));
+        }
+        for failure in &self.attributes {
+            reasons.push(format!(
+                "属性 {} ({}) {}",
+                failure.attribute.id,
+                failure.attribute.name,
+                failure.kind.as_str()
+            ));
+        }
+        if let Some(timeouts) = self.command_timeouts {
+            reasons.push(format!("命令超时 {} 次", timeouts));
+        }
+        if let Some(errors) = self.udma_crc_errors {
+            reasons.push(format!("UDMA CRC 错误 {} 次", errors));
+        }
+        if let Some(status) = self.failed_self_test {
+            reasons.push(status.as_str().to_string());
+        }
+
+        if reasons.is_empty() {
+            write!(f, "{}", self.overall.as_str())
+        } else {
+            write!(f, "{}", reasons.join("; "))
+        }
+    }
+}
+
+/// 机械磨损统计
+///
+/// 汇总与主轴电机和磁头相关的计数,设备没有报告的属性为 `None`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WearStatistics {
+    /// 主轴电机启停次数 (属性 4)
+    pub start_stop_count: Option<u64>,
+    /// 主轴起转重试次数 (属性 10)
+    pub spin_retry_count: Option<u64>,
+    /// 磁头校准重试次数 (属性 11)
+    pub calibration_retry_count: Option<u64>,
+    /// 电源循环次数 (属性 12)
+    pub power_cycle_count: Option<u64>,
+    /// 磁头加载/卸载次数 (属性 193)
+    pub load_cycle_count: Option<u64>,
+    /// 磁头飞行时间 (属性 240)
+    pub head_flying_duration: Option<Duration>,
+}
+
+/// SMART 属性摘要
+///
+/// 只保留展示所需的字段,`value` 由 [`SmartAttributeParsedData::value`] 得到:
+/// 温度为摄氏度,时间为小时,容量保持设备报告的 MB/GB 数值,单位未知时为 48 位原始值
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AttributeSummary {
+    /// 属性 ID
+    pub id: u8,
+    /// 属性名称
+    pub name: String,
+    /// 换算后的值
+    pub value: f64,
+    /// 格式化值的单位
+    pub unit: AttributeUnit,
+    /// 是否警告
+    pub warn: bool,
+}
+
+impl From<&SmartAttributeParsedData> for AttributeSummary {
+    fn from(attr: &SmartAttributeParsedData) -> Self {
+        let value = match attr.value() {
+            PrettyValue::Temperature(temperature) => temperature.celsius(),
+            PrettyValue::Duration(duration) => duration.as_millis() as f64 / 3_600_000.0,
+            PrettyValue::Percent(percent) => percent,
+            // 显示时带上 MB/GB 单位,保持设备报告的数值
+            PrettyValue::Bytes(_) => attr.pretty_value as f64,
+            PrettyValue::Sectors(n) | PrettyValue::Count(n) | PrettyValue::Raw(n) => n as f64,
+        };
+
+        Self {
+            id: attr.id,
+            name: attr.name.to_string(),
+            value,
+            unit: attr.pretty_unit,
+            warn: attr.warn,
+        }
+    }
+}
+
+impl fmt::Display for AttributeSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>3} {}: ", self.id, self.name)?;
+        match self.unit {
+            AttributeUnit::MilliKelvin => write!(f, "{:.1}°C", self.value)?,
+            AttributeUnit::Milliseconds => write!(f, "{:.1} 小时", self.value)?,
+            AttributeUnit::Sectors => write!(f, "{} 扇区", self.value)?,
+            AttributeUnit::SmallPercent => write!(f, "{:.3}%", self.value)?,
+            AttributeUnit::Percent => write!(f, "{}%", self.value)?,
+            AttributeUnit::Megabytes => write!(f, "{} MB", self.value)?,
+            AttributeUnit::GigaBytesWritten => write!(f, "{} GB", self.value)?,
+            AttributeUnit::None | AttributeUnit::Unknown => write!(f, "{}", self.value)?,
+        }
+        if self.warn {
+            write!(f, " (警告)")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,10 +957,12 @@ mod tests {
             total_offline_data_collection_seconds: 0,
             self_test_execution_status: SelfTestExecutionStatus::SuccessOrNever,
             self_test_execution_percent_remaining: 0,
+            auto_save_enabled: false,
             short_and_extended_test_available: true,
             conveyance_test_available: false,
             start_test_available: true,
             abort_test_available: true,
+            offline_data_collection_capability: OfflineDataCollectionCapability::ExecuteImmediate,
             short_test_polling_minutes: 2,
             extended_test_polling_minutes: 60,
             conveyance_test_polling_minutes: 0,
@@ -159,4 +972,157 @@ mod tests {
         assert!(!data.self_test_available(SmartSelfTest::Conveyance));
         assert_eq!(data.self_test_polling_minutes(SmartSelfTest::Short), 2);
     }
+
+    #[test]
+    fn test_ata_version() {
+        assert_eq!(AtaVersion::from_words(0x0000, 0), None);
+        assert_eq!(AtaVersion::from_words(0xFFFF, 0), None);
+
+        // ATA8-ACS 及以下, ACS-3 revision 5
+        let version = AtaVersion::from_words(0x01FE, 0x006D).unwrap();
+        assert_eq!(version.major, 8);
+        assert_eq!(version.name(), "ATA8-ACS");
+        assert_eq!(version.minor_name(), Some("ACS-3 T13/2161-D revision 5"));
+
+        let acs3 = AtaVersion::from_words(0x07FE, 0xFFFF).unwrap();
+        assert_eq!(acs3.minor, 0);
+        assert_eq!(acs3.to_string(), "ACS-3");
+        assert!(acs3 > version);
+
+        let ata5 = AtaVersion::from_words(0x003E, 0).unwrap();
+        assert_eq!(ata5.name(), "ATA/ATAPI-5");
+        assert!(!ata5.supports_gpl());
+        assert!(acs3.supports_gpl());
+    }
+
+    #[test]
+    fn test_feature_flags() {
+        let flags = FeatureFlags::from_words([0; 6]);
+        assert_eq!(flags.smart, FeatureState::Unsupported);
+        assert_eq!(flags.gpl, FeatureState::Unsupported);
+
+        // SMART 已启用, 48 位 LBA 和 FLUSH CACHE EXT 已启用, 支持 GPL 和自检
+        let flags = FeatureFlags::from_words([0x0001, 0x6401, 0x4023, 0x0001, 0x2400, 0x4022]);
+        assert_eq!(flags.smart, FeatureState::Enabled);
+        assert_eq!(flags.download_microcode, FeatureState::Disabled);
+        assert_eq!(flags.lba48, FeatureState::Enabled);
+        assert_eq!(flags.flush_cache_ext, FeatureState::Enabled);
+        assert_eq!(flags.smart_error_logging, FeatureState::Disabled);
+        assert_eq!(flags.smart_self_test, FeatureState::Enabled);
+        assert_eq!(flags.gpl, FeatureState::Enabled);
+        assert_eq!(flags.write_dma_fua, FeatureState::Unsupported);
+
+        // 字 83、84 的有效性位错误
+        let flags = FeatureFlags::from_words([0x0001, 0x2401, 0x8023, 0x0001, 0x2400, 0x4022]);
+        assert_eq!(flags.smart, FeatureState::Enabled);
+        assert_eq!(flags.lba48, FeatureState::Unsupported);
+        assert_eq!(flags.gpl, FeatureState::Unsupported);
+
+        // 字 87 无效时只能确认支持
+        let flags = FeatureFlags::from_words([0x0001, 0x4000, 0x4020, 0x0001, 0x0000, 0xFFFF]);
+        assert_eq!(flags.gpl, FeatureState::Disabled);
+    }
+
+    #[test]
+    fn test_sata_info() {
+        assert_eq!(SataInfo::from_words(0x0000, 0x0000), None);
+        assert_eq!(SataInfo::from_words(0xFFFF, 0xFFFF), None);
+
+        // 支持 Gen1-3 和 NCQ, 当前 Gen2
+        let info = SataInfo::from_words(0x010E, 0x0004).unwrap();
+        assert_eq!(
+            info.supported_gens,
+            [SataGen::Gen1, SataGen::Gen2, SataGen::Gen3]
+        );
+        assert_eq!(info.current_gen, Some(SataGen::Gen2));
+        assert!(info.ncq_supported);
+        assert_eq!(info.max_gen(), Some(SataGen::Gen3));
+        assert!(info.is_downshifted());
+
+        let info = SataInfo::from_words(0x0006, 0xFFFF).unwrap();
+        assert_eq!(info.current_gen, None);
+        assert!(!info.ncq_supported);
+        assert!(!info.is_downshifted());
+    }
+
+    #[test]
+    fn test_vendor_attribute_table() {
+        assert_eq!(
+            VendorAttributeTable::for_model("FUJITSU MHY2120BH"),
+            VendorAttributeTable::fujitsu()
+        );
+        assert_eq!(
+            VendorAttributeTable::for_model("SAMSUNG SV0412H"),
+            VendorAttributeTable::samsung_legacy()
+        );
+        assert_eq!(
+            VendorAttributeTable::for_model("ST3500320AS"),
+            VendorAttributeTable::standard()
+        );
+    }
+
+    #[test]
+    fn test_attribute_summary() {
+        let attr = SmartAttributeParsedData {
+            id: 194,
+            name: "temperature-celsius-2",
+            pretty_unit: AttributeUnit::MilliKelvin,
+            vendor_override_unit: None,
+            flags: 0,
+            threshold: 0,
+            threshold_valid: false,
+            online: true,
+            prefailure: false,
+            good_now: true,
+            good_now_valid: false,
+            good_in_the_past: true,
+            good_in_the_past_valid: false,
+            current_value_valid: true,
+            worst_value_valid: true,
+            warn: false,
+            current_value: 100,
+            worst_value: 100,
+            pretty_value: 308150,
+            raw: [35, 0, 0, 0, 0, 0],
+        };
+
+        let summary = AttributeSummary::from(&attr);
+        assert_eq!(summary.value, 35.0);
+        assert_eq!(summary.to_string(), "194 temperature-celsius-2: 35.0°C");
+
+        let attr = SmartAttributeParsedData {
+            id: 9,
+            name: "power-on-hours",
+            pretty_unit: AttributeUnit::Milliseconds,
+            pretty_value: 1000 * 3_600_000,
+            warn: true,
+            ..attr
+        };
+        let summary = AttributeSummary::from(&attr);
+        assert_eq!(summary.value, 1000.0);
+        assert_eq!(
+            summary.to_string(),
+            "  9 power-on-hours: 1000.0 小时 (警告)"
+        );
+
+        let attr = SmartAttributeParsedData {
+            id: 241,
+            name: "total-lbas-written",
+            pretty_unit: AttributeUnit::Megabytes,
+            pretty_value: 1234,
+            warn: false,
+            ..attr
+        };
+        let summary = AttributeSummary::from(&attr);
+        assert_eq!(summary.to_string(), "241 total-lbas-written: 1234 MB");
+
+        // 单位未知时为原始值
+        let attr = SmartAttributeParsedData {
+            pretty_unit: AttributeUnit::Unknown,
+            pretty_value: 0,
+            raw: [0x34, 0x12, 0, 0, 0, 0],
+            ..attr
+        };
+        assert_eq!(AttributeSummary::from(&attr).value, 4660.0);
+    }
 }
@@ -2,10 +2,14 @@
 //!
 //! 提供类型安全的单位包装器
 
+use std::collections::VecDeque;
 use std::fmt;
+use std::ops::{Add, Sub};
+use std::time::SystemTime;
 
 /// 温度 (摄氏度)
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Temperature {
     celsius: f64,
 }
@@ -36,6 +40,21 @@ impl Temperature {
     pub fn kelvin(&self) -> f64 {
         self.celsius + 273.15
     }
+
+    /// 获取毫开尔文值,与 [`Temperature::from_millikelvin`] 互逆
+    pub fn millikelvin(&self) -> u64 {
+        (self.celsius * 1000.0 + 273150.0).round().max(0.0) as u64
+    }
+
+    /// 返回两者中较低的温度
+    pub fn min(self, other: Self) -> Self {
+        Self::from_celsius(self.celsius.min(other.celsius))
+    }
+
+    /// 返回两者中较高的温度
+    pub fn max(self, other: Self) -> Self {
+        Self::from_celsius(self.celsius.max(other.celsius))
+    }
 }
 
 impl fmt::Display for Temperature {
@@ -44,8 +63,127 @@ impl fmt::Display for Temperature {
     }
 }
 
+/// 温度范围
+///
+/// 用于长期监控时记录观察到的最低和最高温度
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TemperatureRange {
+    /// 最低温度
+    pub min: Temperature,
+    /// 最高温度
+    pub max: Temperature,
+}
+
+impl TemperatureRange {
+    /// 从单个温度创建,最低和最高温度相同
+    pub fn new(t: Temperature) -> Self {
+        Self { min: t, max: t }
+    }
+
+    /// 加入一个温度,必要时扩大范围
+    pub fn update(&mut self, t: Temperature) {
+        self.min = self.min.min(t);
+        self.max = self.max.max(t);
+    }
+
+    /// 最高与最低温度之差 (摄氏度)
+    pub fn span_celsius(&self) -> f64 {
+        self.max.celsius() - self.min.celsius()
+    }
+}
+
+impl fmt::Display for TemperatureRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.min, self.max)
+    }
+}
+
+/// 温度历史记录
+///
+/// 按时间顺序保存最近的温度采样,超过容量时丢弃最早的采样。
+/// 可以用于定期轮询 SMART 温度属性,或保存 SCT 温度历史等设备端记录。
+///
+/// # 示例
+///
+/// ```
+/// use libatasmart::{Temperature, TemperatureHistory};
+/// use std::time::SystemTime;
+///
+/// let mut history = TemperatureHistory::new(2);
+/// history.push(SystemTime::now(), Temperature::from_celsius(30.0));
+/// history.push(SystemTime::now(), Temperature::from_celsius(42.0));
+/// history.push(SystemTime::now(), Temperature::from_celsius(35.0));
+///
+/// assert_eq!(history.len(), 2);
+/// assert_eq!(history.range().unwrap().max.celsius(), 42.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TemperatureHistory {
+    samples: VecDeque<(SystemTime, Temperature)>,
+    capacity: usize,
+}
+
+impl TemperatureHistory {
+    /// 创建指定容量的历史记录,容量为 0 时不保存任何采样
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// 最多保存的采样数
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 添加一个采样,超过容量时丢弃最早的采样
+    pub fn push(&mut self, time: SystemTime, t: Temperature) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((time, t));
+    }
+
+    /// 按时间顺序 (从旧到新) 遍历采样
+    pub fn samples(&self) -> impl Iterator<Item = &(SystemTime, Temperature)> {
+        self.samples.iter()
+    }
+
+    /// 最近一次采样
+    pub fn latest(&self) -> Option<&(SystemTime, Temperature)> {
+        self.samples.back()
+    }
+
+    /// 采样数
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// 是否没有任何采样
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// 已保存采样的温度范围,没有采样时返回 `None`
+    pub fn range(&self) -> Option<TemperatureRange> {
+        let mut samples = self.samples.iter().map(|(_, t)| *t);
+        let mut range = TemperatureRange::new(samples.next()?);
+        for t in samples {
+            range.update(t);
+        }
+        Some(range)
+    }
+}
+
 /// 时长
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Duration {
     milliseconds: u64,
 }
@@ -101,6 +239,23 @@ impl Duration {
     }
 }
 
+impl Add for Duration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_millis(self.milliseconds.saturating_add(rhs.milliseconds))
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    /// 结果不会小于 0
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_millis(self.milliseconds.saturating_sub(rhs.milliseconds))
+    }
+}
+
 impl fmt::Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let hours = self.as_hours();
@@ -117,10 +272,152 @@ impl fmt::Display for Duration {
     }
 }
 
+/// 容量 (字节)
+///
+/// `Display` 按 1000 进制选择单位,与硬盘厂商标称的容量一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Capacity {
+    bytes: u64,
+}
+
+impl Capacity {
+    /// 从字节数创建
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self { bytes }
+    }
+
+    /// 获取字节数
+    pub fn as_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// 获取 GB 值 (10^9 字节)
+    pub fn as_gb(&self) -> f64 {
+        self.bytes as f64 / 1e9
+    }
+
+    /// 获取 GiB 值 (2^30 字节)
+    pub fn as_gib(&self) -> f64 {
+        self.bytes as f64 / (1u64 << 30) as f64
+    }
+}
+
+impl fmt::Display for Capacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+        let mut value = self.bytes as f64;
+        let mut unit = 0;
+        while value >= 1000.0 && unit < UNITS.len() - 1 {
+            value /= 1000.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "{} B", self.bytes)
+        } else {
+            write!(f, "{:.1} {}", value, UNITS[unit])
+        }
+    }
+}
+
+/// 百分比,限制在 0-100
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Percent {
+    value: f64,
+}
+
+impl Percent {
+    /// 创建百分比,超出 0-100 的值被截断,NaN 视为 0
+    pub fn new(value: f64) -> Self {
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 100.0)
+        };
+        Self { value }
+    }
+
+    /// 获取百分比数值 (0-100)
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// 获取比例 (0-1)
+    pub fn ratio(&self) -> f64 {
+        self.value / 100.0
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}%", self.value)
+    }
+}
+
+/// 带单位的属性值
+///
+/// 由 [`crate::SmartAttributeParsedData::value`] 根据属性单位转换得到,
+/// `Display` 输出带单位的可读字符串。
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum PrettyValue {
+    /// 时长
+    Duration(Duration),
+    /// 温度
+    Temperature(Temperature),
+    /// 扇区数
+    Sectors(u64),
+    /// 百分比
+    Percent(f64),
+    /// 字节数 (按 1000 进制换算)
+    Bytes(u64),
+    /// 计数
+    Count(u64),
+    /// 单位未知的 48 位原始值
+    Raw(u64),
+}
+
+impl fmt::Display for PrettyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duration(duration) => write!(f, "{}", duration),
+            Self::Temperature(temperature) => write!(f, "{}", temperature),
+            Self::Sectors(sectors) => write!(f, "{} 扇区", sectors),
+            Self::Percent(percent) if percent.fract() == 0.0 => write!(f, "{}%", percent),
+            Self::Percent(percent) => write!(f, "{:.3}%", percent),
+            Self::Bytes(bytes) => write!(f, "{}", Capacity::from_bytes(*bytes)),
+            Self::Count(count) | Self::Raw(count) => write!(f, "{}", count),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pretty_value_display() {
+        let cases = [
+            (PrettyValue::Duration(Duration::from_hours(5)), "5 小时"),
+            (
+                PrettyValue::Temperature(Temperature::from_celsius(35.0)),
+                "35.0°C",
+            ),
+            (PrettyValue::Sectors(8), "8 扇区"),
+            (PrettyValue::Percent(97.0), "97%"),
+            (PrettyValue::Percent(0.125), "0.125%"),
+            (PrettyValue::Bytes(512), "512 B"),
+            (PrettyValue::Bytes(1_500_000_000), "1.5 GB"),
+            (PrettyValue::Bytes(u64::MAX), "18446744.1 TB"),
+            (PrettyValue::Count(42), "42"),
+            (PrettyValue::Raw(7), "7"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.to_string(), expected);
+        }
+    }
+
     #[test]
     fn test_temperature() {
         let temp = Temperature::from_celsius(25.0);
@@ -130,6 +427,48 @@ mod tests {
 
         let temp2 = Temperature::from_millikelvin(298150);
         assert!((temp2.celsius() - 25.0).abs() < 0.01);
+        assert_eq!(temp2.millikelvin(), 298150);
+        assert_eq!(Temperature::from_millikelvin(308150).millikelvin(), 308150);
+    }
+
+    #[test]
+    fn test_temperature_range() {
+        let mut range = TemperatureRange::new(Temperature::from_celsius(35.0));
+        assert_eq!(range.span_celsius(), 0.0);
+
+        range.update(Temperature::from_celsius(28.5));
+        range.update(Temperature::from_celsius(41.0));
+        range.update(Temperature::from_celsius(30.0));
+        assert_eq!(range.min.celsius(), 28.5);
+        assert_eq!(range.max.celsius(), 41.0);
+        assert_eq!(range.span_celsius(), 12.5);
+        assert_eq!(range.to_string(), "28.5°C - 41.0°C");
+    }
+
+    #[test]
+    fn test_temperature_history() {
+        let start = SystemTime::UNIX_EPOCH;
+        let mut history = TemperatureHistory::new(3);
+        assert!(history.is_empty());
+        assert!(history.range().is_none());
+
+        for (i, celsius) in [30.0, 45.0, 33.0, 36.0].into_iter().enumerate() {
+            let time = start + std::time::Duration::from_secs(i as u64 * 60);
+            history.push(time, Temperature::from_celsius(celsius));
+        }
+
+        assert_eq!(history.len(), 3);
+        let temps: Vec<f64> = history.samples().map(|(_, t)| t.celsius()).collect();
+        assert_eq!(temps, [45.0, 33.0, 36.0]);
+        assert_eq!(history.latest().unwrap().1.celsius(), 36.0);
+
+        let range = history.range().unwrap();
+        assert_eq!(range.min.celsius(), 33.0);
+        assert_eq!(range.max.celsius(), 45.0);
+
+        let mut empty = TemperatureHistory::new(0);
+        empty.push(start, Temperature::from_celsius(30.0));
+        assert!(empty.is_empty());
     }
 
     #[test]
@@ -140,5 +479,52 @@ mod tests {
 
         let dur2 = Duration::from_millis(3600000);
         assert_eq!(dur2.as_hours(), 1);
+
+        assert_eq!(dur + dur2, Duration::from_hours(25));
+        assert_eq!(dur - dur2, Duration::from_hours(23));
+        assert_eq!(dur2 - dur, Duration::from_millis(0));
+    }
+
+    #[test]
+    fn test_duration_display() {
+        assert_eq!(Duration::from_hours(5).to_string(), "5 小时");
+        assert_eq!(Duration::from_hours(36).to_string(), "1.5 天");
+        assert_eq!(Duration::from_hours(24 * 365 * 2).to_string(), "2.0 年");
+    }
+
+    #[test]
+    fn test_temperature_ordering() {
+        let cool = Temperature::from_celsius(30.0);
+        let hot = Temperature::from_celsius(55.0);
+        assert!(cool < hot);
+        assert_eq!(cool.to_string(), "30.0°C");
+    }
+
+    #[test]
+    fn test_capacity() {
+        let capacity = Capacity::from_bytes(500_107_862_016);
+        assert_eq!(capacity.as_bytes(), 500_107_862_016);
+        assert!((capacity.as_gb() - 500.107862016).abs() < 1e-9);
+        assert!((capacity.as_gib() - 465.76).abs() < 0.01);
+
+        assert_eq!(Capacity::from_bytes(0).to_string(), "0 B");
+        assert_eq!(Capacity::from_bytes(999).to_string(), "999 B");
+        assert_eq!(Capacity::from_bytes(4096).to_string(), "4.1 KB");
+        assert_eq!(capacity.to_string(), "500.1 GB");
+        assert_eq!(
+            Capacity::from_bytes(4_000_787_030_016).to_string(),
+            "4.0 TB"
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(Percent::new(97.0).to_string(), "97.0%");
+        assert_eq!(Percent::new(12.345).to_string(), "12.3%");
+        assert_eq!(Percent::new(150.0).value(), 100.0);
+        assert_eq!(Percent::new(-3.0).value(), 0.0);
+        assert_eq!(Percent::new(f64::NAN).value(), 0.0);
+        assert_eq!(Percent::new(25.0).ratio(), 0.25);
+        assert!(Percent::new(10.0) < Percent::new(20.0));
     }
 }
@@ -33,6 +33,31 @@ pub(crate) fn read_ata_string(raw: &[u8]) -> String {
     trim_spaces(&s)
 }
 
+/// 读取 IDENTIFY 字 (小端序)
+pub(crate) fn read_ata_word(raw: &[u8; 512], index: usize) -> u16 {
+    u16::from_le_bytes([raw[index * 2], raw[index * 2 + 1]])
+}
+
+/// 校验 ATA 数据扇区的校验和
+///
+/// SMART 数据、阈值等扇区的最后一个字节是校验和,
+/// 全部 512 字节相加 (模 256) 应等于 0。
+///
+/// # 示例
+///
+/// ```
+/// use libatasmart::verify_ata_checksum;
+///
+/// let mut sector = [0u8; 512];
+/// sector[0] = 0x10;
+/// assert!(!verify_ata_checksum(&sector));
+/// sector[511] = 0xF0;
+/// assert!(verify_ata_checksum(&sector));
+/// ```
+pub fn verify_ata_checksum(sector: &[u8; 512]) -> bool {
+    sector.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +80,18 @@ mod tests {
     fn test_trim_spaces() {
         assert_eq!(trim_spaces("  hello   world  "), "hello world");
     }
+
+    #[test]
+    fn test_verify_ata_checksum() {
+        let mut sector = [0u8; 512];
+        sector[0] = 0x01;
+        sector[2] = 0x05;
+        sector[362] = 0x82;
+        sector[511] = 0u8.wrapping_sub(0x01 + 0x05 + 0x82);
+        assert!(verify_ata_checksum(&sector));
+
+        // 单个字节翻转
+        sector[2] ^= 0x01;
+        assert!(!verify_ata_checksum(&sector));
+    }
 }
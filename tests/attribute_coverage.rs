@@ -0,0 +1,142 @@
+//! 属性表覆盖测试
+//!
+//! 预期列表按 smartmontools `drivedb.h` 中常见型号的条目整理,
+//! 注释标出报告该属性的主要系列;名称和单位沿用 libatasmart 的命名。
+//! 列表中的属性缺失或名称、单位被修改时测试失败并给出属性 ID。
+
+use libatasmart::{attribute_info, AttributeUnit};
+
+/// (ID, 名称, 单位)
+const EXPECTED: &[(u8, &str, AttributeUnit)] = &[
+    // 所有机械硬盘 (Seagate、WD、HGST、Toshiba) 的通用属性
+    (1, "raw-read-error-rate", AttributeUnit::None),
+    (2, "throughput-performance", AttributeUnit::Unknown),
+    (3, "spin-up-time", AttributeUnit::Milliseconds),
+    (4, "start-stop-count", AttributeUnit::None),
+    (5, "reallocated-sector-count", AttributeUnit::Sectors),
+    (7, "seek-error-rate", AttributeUnit::None),
+    (8, "seek-time-performance", AttributeUnit::Unknown),
+    (9, "power-on-hours", AttributeUnit::Milliseconds),
+    (10, "spin-retry-count", AttributeUnit::None),
+    (11, "calibration-retry-count", AttributeUnit::None),
+    (12, "power-cycle-count", AttributeUnit::None),
+    (13, "read-soft-error-rate", AttributeUnit::None),
+    // HGST Ultrastar He、WD Red/Gold 充氦型号
+    (22, "helium-level", AttributeUnit::Unknown),
+    // Silicon Motion、Phison 主控 SSD (Transcend、ADATA、Kingston A400 等)
+    (160, "uncorrectable-error-count", AttributeUnit::None),
+    (161, "valid-spare-block-count", AttributeUnit::None),
+    (162, "bad-block-count", AttributeUnit::None),
+    (163, "initial-bad-block-count", AttributeUnit::None),
+    (164, "total-erase-count", AttributeUnit::None),
+    (165, "max-erase-count", AttributeUnit::None),
+    (166, "min-erase-count", AttributeUnit::None),
+    (167, "average-erase-count-2", AttributeUnit::None),
+    (168, "max-erase-count-of-spec", AttributeUnit::None),
+    (169, "remaining-lifetime-percent", AttributeUnit::Percent),
+    // Samsung 8xx、Crucial MX、Intel 5xx、SanDisk SSD
+    (170, "available-reserved-space", AttributeUnit::Percent),
+    (171, "program-fail-count", AttributeUnit::None),
+    (172, "erase-fail-count", AttributeUnit::None),
+    (173, "average-erase-count", AttributeUnit::None),
+    (174, "unexpected-power-loss-count", AttributeUnit::None),
+    (175, "program-fail-count-chip", AttributeUnit::None),
+    (176, "erase-fail-count-chip", AttributeUnit::None),
+    (177, "wear-leveling-count", AttributeUnit::None),
+    (178, "used-reserved-blocks-chip", AttributeUnit::None),
+    (179, "used-reserved-blocks-total", AttributeUnit::None),
+    (180, "unused-reserved-blocks", AttributeUnit::None),
+    (181, "program-fail-count-total", AttributeUnit::None),
+    (182, "erase-fail-count-total", AttributeUnit::None),
+    (183, "runtime-bad-block-total", AttributeUnit::None),
+    (184, "end-to-end-error", AttributeUnit::None),
+    // WD 机械硬盘
+    (185, "head-stability", AttributeUnit::Unknown),
+    (
+        186,
+        "induced-op-vibration-detection",
+        AttributeUnit::Unknown,
+    ),
+    // Seagate Barracuda/IronWolf 及大多数机械硬盘和 SSD
+    (187, "reported-uncorrect", AttributeUnit::Sectors),
+    (188, "command-timeout", AttributeUnit::None),
+    (189, "high-fly-writes", AttributeUnit::None),
+    (
+        190,
+        "airflow-temperature-celsius",
+        AttributeUnit::MilliKelvin,
+    ),
+    (191, "g-sense-error-rate", AttributeUnit::None),
+    (192, "power-off-retract-count", AttributeUnit::None),
+    (193, "load-cycle-count", AttributeUnit::None),
+    (194, "temperature-celsius-2", AttributeUnit::MilliKelvin),
+    (195, "hardware-ecc-recovered", AttributeUnit::None),
+    (196, "reallocated-event-count", AttributeUnit::None),
+    (197, "current-pending-sector", AttributeUnit::Sectors),
+    (198, "offline-uncorrectable", AttributeUnit::Sectors),
+    (199, "udma-crc-error-count", AttributeUnit::None),
+    (200, "multi-zone-error-rate", AttributeUnit::None),
+    // Crucial/Micron SSD
+    (202, "ta-increase-count", AttributeUnit::None),
+    (210, "success-rain-recovery-count", AttributeUnit::None),
+    // Seagate SSD、Samsung 旧型号
+    (218, "crc-error-count", AttributeUnit::None),
+    // Toshiba DT/MG、HGST Deskstar
+    (220, "disk-shift", AttributeUnit::Unknown),
+    (222, "loaded-hours", AttributeUnit::Milliseconds),
+    (223, "load-retry-count", AttributeUnit::None),
+    (225, "load-cycle-count-2", AttributeUnit::None),
+    (226, "load-in-time", AttributeUnit::Milliseconds),
+    // Intel、Kingston SSD
+    (231, "temperature-celsius", AttributeUnit::MilliKelvin),
+    (232, "endurance-remaining", AttributeUnit::Percent),
+    (233, "power-on-seconds-2", AttributeUnit::Unknown),
+    // Seagate 机械硬盘和 SSD
+    (240, "head-flying-hours", AttributeUnit::Milliseconds),
+    (241, "total-lbas-written", AttributeUnit::Megabytes),
+    (242, "total-lbas-read", AttributeUnit::Megabytes),
+    // Crucial MX/BX SSD
+    (246, "total-host-sector-writes", AttributeUnit::None),
+    (247, "host-program-page-count", AttributeUnit::None),
+    (248, "ftl-program-page-count", AttributeUnit::None),
+    // WD/HGST 笔记本硬盘
+    (254, "free-fall-sensor", AttributeUnit::None),
+];
+
+#[test]
+fn test_expected_attributes_present() {
+    let mut errors = Vec::new();
+
+    for &(id, name, unit) in EXPECTED {
+        match attribute_info(id) {
+            None => errors.push(format!("属性 {} ({}) 不在属性表中", id, name)),
+            Some((actual_name, actual_unit)) => {
+                if actual_name != name {
+                    errors.push(format!(
+                        "属性 {} 名称错误: 期望 {}, 实际 {}",
+                        id, name, actual_name
+                    ));
+                }
+                if actual_unit != unit {
+                    errors.push(format!(
+                        "属性 {} ({}) 单位错误: 期望 {:?}, 实际 {:?}",
+                        id, name, unit, actual_unit
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(errors.is_empty(), "属性表不完整:\n{}", errors.join("\n"));
+}
+
+#[test]
+fn test_expected_list_sorted_and_unique() {
+    assert!(EXPECTED.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn test_reserved_ids_absent() {
+    assert_eq!(attribute_info(0), None);
+    assert_eq!(attribute_info(255), None);
+}
@@ -0,0 +1,133 @@
+//! Blob 样本测试
+//!
+//! 逐个载入 tests/blobs/ 下的 blob 文件,走完解析到整体健康状态的完整流程。
+//! 新增样本时需要在 `EXPECTED` 中记录其整体状态,防止判定规则被意外修改。
+//!
+//! 现有的 `synthetic-*.blob` 是人工构造的样本 (1 TB 机械硬盘,带校验和的 SMART 数据和阈值),
+//! 分别覆盖各个整体状态;真实磁盘的 blob 可以直接放入该目录。
+
+use libatasmart::{read_blob_from_file, Disk, DiskSnapshot, Error, SmartOverall};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// (文件名, 整体状态),没有 SMART 数据的样本为 `None`
+const EXPECTED: &[(&str, Option<SmartOverall>)] = &[
+    (
+        "synthetic-bad-attribute-now.blob",
+        Some(SmartOverall::BadAttributeNow),
+    ),
+    (
+        "synthetic-bad-attribute-past.blob",
+        Some(SmartOverall::BadAttributeInThePast),
+    ),
+    (
+        "synthetic-bad-sector-many.blob",
+        Some(SmartOverall::BadSectorMany),
+    ),
+    ("synthetic-bad-sector.blob", Some(SmartOverall::BadSector)),
+    ("synthetic-bad-status.blob", Some(SmartOverall::BadStatus)),
+    ("synthetic-good.blob", Some(SmartOverall::Good)),
+    ("synthetic-identify-only.blob", None),
+];
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/blobs");
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "blob"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn disk_from_blob(path: &Path) -> Disk {
+    read_blob_from_file(path).unwrap().to_disk().unwrap()
+}
+
+#[test]
+fn test_fixtures_listed() {
+    let names: Vec<String> = fixtures()
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    let expected: Vec<&str> = EXPECTED.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, expected, "tests/blobs/ 与 EXPECTED 不一致");
+}
+
+#[test]
+fn test_blob_capture_round_trip() {
+    let dir = std::env::temp_dir().join(format!("libatasmart-blob-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    for path in fixtures() {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let blob = read_blob_from_file(&path).unwrap();
+        let disk = blob.clone().to_disk().unwrap();
+        assert_eq!(disk.capture_blob(), blob, "{}", name);
+
+        let saved = dir.join(&name);
+        disk.save_blob(&saved).unwrap();
+        assert_eq!(read_blob_from_file(&saved).unwrap(), blob, "{}", name);
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(matches!(
+        Disk::default().save_blob(dir.join("empty.blob")),
+        Err(Error::NoData)
+    ));
+}
+
+#[test]
+fn test_blob_fixtures() {
+    for path in fixtures() {
+        let name = path.file_name().unwrap().to_string_lossy().into_owned();
+        let Some((_, expected)) = EXPECTED.iter().find(|(n, _)| *n == name) else {
+            panic!("{}: 没有记录整体状态", name);
+        };
+
+        let disk = disk_from_blob(&path);
+        let identify = disk.read_identify_cached().unwrap().parse().unwrap();
+        assert!(!identify.model.is_empty(), "{}", name);
+
+        let snapshot = DiskSnapshot::from_blob(&path).unwrap();
+        assert_eq!(snapshot.overall, *expected, "{}", name);
+
+        match expected {
+            Some(overall) => {
+                let smart = disk.smart_info().unwrap();
+                let attributes = smart.parse_attributes().unwrap();
+                assert!(!attributes.is_empty(), "{}", name);
+
+                assert!(smart.bad_sectors().is_some(), "{}", name);
+                assert!(smart.temperature().is_some(), "{}", name);
+                assert!(smart.power_on_duration().is_some(), "{}", name);
+                assert_eq!(
+                    disk.smart_get_temperature().ok(),
+                    smart.temperature(),
+                    "{}",
+                    name
+                );
+                assert_eq!(
+                    disk.smart_get_power_on().ok(),
+                    smart.power_on_duration(),
+                    "{}",
+                    name
+                );
+                assert_eq!(disk.smart_get_overall().unwrap(), *overall, "{}", name);
+                let assessment = disk.smart_get_overall_detailed().unwrap();
+                assert_eq!(assessment.overall, *overall, "{}", name);
+                assert!(!assessment.to_string().is_empty(), "{}", name);
+            }
+            None => {
+                assert!(
+                    matches!(disk.smart_info(), Err(Error::NotSupported(_))),
+                    "{}",
+                    name
+                );
+                assert!(disk.smart_get_overall().is_err(), "{}", name);
+                assert!(disk.smart_get_temperature().is_err(), "{}", name);
+            }
+        }
+    }
+}
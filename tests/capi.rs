@@ -0,0 +1,106 @@
+//! C 接口测试
+//!
+//! 从 Rust 调用导出的 `sk_disk_*` 函数;tests/capi/test_capi.c 从 C 端链接动态库测试同样的接口,
+//! 由 tests/capi_c.rs 编译运行
+
+#![cfg(feature = "capi")]
+
+use libatasmart::capi::*;
+use std::ffi::{c_int, c_void, CStr};
+use std::ptr;
+
+/// 构造包含 IDENTIFY、SMART 状态和 SMART 数据的 blob
+fn sample_blob() -> Vec<u8> {
+    let mut identify = [0u8; 512];
+    // 型号 "AB" (字节对交换),支持 SMART
+    identify[54..56].copy_from_slice(b"BA");
+    identify[164] = 0x01;
+    identify[167] = 0x40;
+
+    let mut data = [0u8; 512];
+    // 属性 5: 3 个重映射扇区
+    data[2..8].copy_from_slice(&[5, 0x03, 0, 100, 99, 3]);
+    let sum = data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+    data[511] = sum.wrapping_neg();
+
+    let mut blob = Vec::new();
+    for (tag, block) in [
+        (b"IDFY", &identify[..]),
+        (b"SMST", &[0, 0, 0, 1][..]),
+        (b"SMDT", &data[..]),
+    ] {
+        blob.extend_from_slice(tag);
+        blob.extend_from_slice(&(block.len() as u32).to_be_bytes());
+        blob.extend_from_slice(block);
+    }
+    blob
+}
+
+unsafe extern "C" fn collect_ids(
+    _d: *mut SkDisk,
+    a: *const SkSmartAttributeParsedData,
+    userdata: *mut c_void,
+) {
+    let ids = &mut *(userdata as *mut Vec<(u8, String)>);
+    let a = &*a;
+    ids.push((a.id, CStr::from_ptr(a.name).to_string_lossy().into_owned()));
+}
+
+#[test]
+fn test_capi_blob_disk() {
+    unsafe {
+        let mut d = ptr::null_mut();
+        assert_eq!(sk_disk_open(ptr::null(), &mut d), 0);
+
+        let blob = sample_blob();
+        assert_eq!(
+            sk_disk_set_blob(d, blob.as_ptr() as *const c_void, blob.len()),
+            0
+        );
+
+        let mut identify = ptr::null();
+        assert_eq!(sk_disk_identify_parse(d, &mut identify), 0);
+        assert_eq!(
+            CStr::from_ptr((*identify).model.as_ptr()).to_str().unwrap(),
+            "AB"
+        );
+
+        let mut good = 0;
+        assert_eq!(sk_disk_smart_status(d, &mut good), 0);
+        assert_eq!(good, 1);
+
+        assert_eq!(sk_disk_smart_read_data(d), 0);
+        let mut ids: Vec<(u8, String)> = Vec::new();
+        assert_eq!(
+            sk_disk_smart_parse_attributes(d, Some(collect_ids), &mut ids as *mut _ as *mut c_void),
+            0
+        );
+        assert_eq!(ids, [(5, "reallocated-sector-count".to_string())]);
+
+        let mut overall: c_int = -1;
+        assert_eq!(sk_disk_smart_get_overall(d, &mut overall), 0);
+        assert_eq!(overall, 2); // SK_SMART_OVERALL_BAD_SECTOR
+
+        let mut out = ptr::null();
+        let mut size = 0;
+        assert_eq!(sk_disk_get_blob(d, &mut out, &mut size), 0);
+        assert_eq!(std::slice::from_raw_parts(out as *const u8, size), blob);
+
+        sk_disk_free(d);
+    }
+}
+
+#[test]
+fn test_capi_errors() {
+    unsafe {
+        let mut d = ptr::null_mut();
+        let name = c"/dev/libatasmart-does-not-exist";
+        assert_eq!(sk_disk_open(name.as_ptr(), &mut d), -libc::ENOENT);
+
+        assert_eq!(sk_disk_open(ptr::null(), &mut d), 0);
+        assert!(sk_disk_smart_read_data(d) < 0);
+        assert!(sk_disk_set_blob(d, [0u8; 4].as_ptr() as *const c_void, 4) < 0);
+        sk_disk_free(d);
+        sk_disk_free(ptr::null_mut());
+    }
+}
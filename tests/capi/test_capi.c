@@ -0,0 +1,117 @@
+/*
+ * C 接口测试
+ *
+ * 由 tests/capi_c.rs 构建并运行 (cargo test --features capi),也可以手动运行:
+ *
+ * cargo rustc --lib --features capi --crate-type cdylib
+ * cc -Wall -Iinclude tests/capi/test_capi.c -Ltarget/debug -llibatasmart -o target/test_capi
+ * LD_LIBRARY_PATH=target/debug ./target/test_capi
+ */
+
+#include <assert.h>
+#include <errno.h>
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "atasmart.h"
+
+static void put_block(uint8_t **p, const char *tag, const void *data, uint32_t size) {
+        memcpy(*p, tag, 4);
+        (*p)[4] = size >> 24;
+        (*p)[5] = size >> 16;
+        (*p)[6] = size >> 8;
+        (*p)[7] = size;
+        memcpy(*p + 8, data, size);
+        *p += 8 + size;
+}
+
+static void on_attribute(SkDisk *d, const SkSmartAttributeParsedData *a, void *userdata) {
+        unsigned *count = userdata;
+        (void) d;
+
+        if (a->id == 5) {
+                assert(strcmp(a->name, "reallocated-sector-count") == 0);
+                assert(a->pretty_unit == SK_SMART_ATTRIBUTE_UNIT_SECTORS);
+                assert(a->prefailure && a->online);
+                assert(a->threshold_valid && a->threshold == 10);
+                assert(a->good_now && a->good_now_valid);
+                assert(a->current_value_valid && a->worst_value_valid);
+                assert(!a->warn);
+                assert(a->current_value == 100 && a->worst_value == 99);
+                assert(a->pretty_value == 3);
+        } else {
+                assert(a->id == 194);
+                assert(a->pretty_unit == SK_SMART_ATTRIBUTE_UNIT_MKELVIN);
+                assert(a->pretty_value == 308150);
+        }
+
+        (*count)++;
+}
+
+int main(void) {
+        uint8_t identify[512] = {0}, data[512] = {0}, thresholds[512] = {0};
+        uint8_t status[4] = {0, 0, 0, 1};
+        uint8_t blob[4 * 8 + 3 * 512 + 4], *p = blob;
+        uint8_t sum = 0;
+        SkDisk *d = NULL;
+        const SkIdentifyParsedData *id = NULL;
+        const void *out = NULL;
+        size_t out_size = 0;
+        SkSmartOverall overall;
+        uint64_t value;
+        unsigned count = 0;
+        int i;
+
+        /* 型号 "AB" (字节对交换),支持 SMART */
+        identify[54] = 'B';
+        identify[55] = 'A';
+        identify[164] = 0x01;
+        identify[167] = 0x40;
+
+        /* 属性 5: 预失败/在线, 3 个重映射扇区; 属性 194: 35°C */
+        memcpy(data + 2, (uint8_t[]) {5, 0x03, 0, 100, 99, 3}, 6);
+        memcpy(data + 14, (uint8_t[]) {194, 0x22, 0, 65, 55, 35}, 6);
+        for (i = 0; i < 511; i++)
+                sum += data[i];
+        data[511] = -sum;
+
+        memcpy(thresholds + 2, (uint8_t[]) {5, 10}, 2);
+        memcpy(thresholds + 14, (uint8_t[]) {194, 0}, 2);
+        for (sum = 0, i = 0; i < 511; i++)
+                sum += thresholds[i];
+        thresholds[511] = -sum;
+
+        put_block(&p, "IDFY", identify, 512);
+        put_block(&p, "SMST", status, 4);
+        put_block(&p, "SMDT", data, 512);
+        put_block(&p, "SMTH", thresholds, 512);
+
+        assert(sk_disk_open(NULL, &d) == 0);
+        assert(sk_disk_smart_parse_attributes(d, on_attribute, &count) == -ENOENT);
+        assert(errno == ENOENT);
+        assert(sk_disk_set_blob(d, blob, (size_t) (p - blob)) == 0);
+
+        assert(sk_disk_identify_parse(d, &id) == 0);
+        assert(strcmp(id->model, "AB") == 0);
+
+        assert(sk_disk_smart_read_data(d) == 0);
+        assert(sk_disk_smart_parse_attributes(d, on_attribute, &count) == 0);
+        assert(count == 2);
+
+        assert(sk_disk_smart_get_overall(d, &overall) == 0);
+        assert(overall == SK_SMART_OVERALL_BAD_SECTOR);
+        assert(sk_disk_smart_get_temperature(d, &value) == 0 && value == 308150);
+        assert(sk_disk_smart_get_bad(d, &value) == 0 && value == 3);
+        assert(sk_disk_smart_get_power_on(d, &value) == -ENOENT);
+
+        assert(sk_disk_get_blob(d, &out, &out_size) == 0);
+        assert(out_size == (size_t) (p - blob));
+        assert(memcmp(out, blob, out_size) == 0);
+
+        sk_disk_free(d);
+        assert(sk_disk_open("/dev/libatasmart-does-not-exist", &d) == -ENOENT);
+
+        printf("ok\n");
+        return 0;
+}
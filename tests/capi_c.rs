@@ -0,0 +1,61 @@
+//! C 程序链接测试
+//!
+//! 把库构建为动态库,用 `cc` 编译 tests/capi/test_capi.c 并运行,
+//! 确认 include/atasmart.h 与导出的 `sk_disk_*` 符号一致。
+//! 编译器可以通过 `CC` 环境变量指定。
+
+#![cfg(feature = "capi")]
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// 运行命令,失败时给出命令行和输出
+fn run(command: &mut Command) {
+    let output = command
+        .output()
+        .unwrap_or_else(|e| panic!("无法运行 {:?}: {}", command, e));
+
+    assert!(
+        output.status.success(),
+        "{:?} 失败 ({}):\n{}{}",
+        command,
+        output.status,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_capi_c_program() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // 使用单独的目录,不与正在运行测试的 cargo 争用 target/ 的锁
+    let target_dir = root.join("target").join("capi-test");
+    let lib_dir = target_dir.join("debug");
+    let program = target_dir.join("test_capi");
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    run(Command::new(cargo)
+        .current_dir(root)
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "capi",
+            "--crate-type",
+            "cdylib",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir));
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    run(Command::new(cc)
+        .current_dir(root)
+        .args(["-Wall", "-Werror", "-Iinclude", "tests/capi/test_capi.c"])
+        .arg("-L")
+        .arg(&lib_dir)
+        .args(["-llibatasmart", "-o"])
+        .arg(&program));
+
+    run(Command::new(&program).env("LD_LIBRARY_PATH", &lib_dir));
+}
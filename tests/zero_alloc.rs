@@ -0,0 +1,60 @@
+//! 属性解析的堆分配测试
+//!
+//! 使用计数分配器确认 [`SmartData::attributes`] 迭代全部属性时不分配堆内存。
+//! 该文件只包含一个测试,避免其他线程的分配干扰计数。
+
+use libatasmart::Disk;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_attribute_iteration_does_not_allocate() {
+    let mut data = [0u8; 512];
+    let mut thresholds = [0u8; 512];
+    // 已知属性和未定义属性 (251) 各若干个
+    for (slot, id) in [1u8, 5, 9, 194, 197, 251].into_iter().enumerate() {
+        data[2 + slot * 12] = id;
+        data[2 + slot * 12 + 3] = 100;
+        data[2 + slot * 12 + 5] = slot as u8;
+        thresholds[2 + slot * 12] = id;
+        thresholds[2 + slot * 12 + 1] = 10;
+    }
+    for sector in [&mut data, &mut thresholds] {
+        let sum = sector.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        sector[511] = sum.wrapping_neg();
+    }
+
+    let disk = Disk::from_raw_buffers(None, Some(data), Some(thresholds), Some(true)).unwrap();
+    let smart = disk.smart_info().unwrap();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let mut count = 0;
+    let mut names = 0;
+    for attr in smart.attributes() {
+        count += 1;
+        names += attr.name.len();
+    }
+    let after = ALLOCATIONS.load(Ordering::Relaxed);
+
+    assert_eq!(count, 6);
+    assert!(names > 0);
+    assert_eq!(after - before, 0, "迭代属性时分配了堆内存");
+}